@@ -347,6 +347,24 @@ impl SpannedConfig {
         self.vertical_chars.remove(&pos);
     }
 
+    /// Changes all overridden chars of horizontal and vertical borders by a given function.
+    ///
+    /// See [`SpannedConfig::set_horizontal_char`] and [`SpannedConfig::set_vertical_char`].
+    pub fn map_overridden_chars<F>(&mut self, f: F)
+    where
+        F: Fn(char) -> char,
+    {
+        let chars = self
+            .horizontal_chars
+            .values_mut()
+            .chain(self.vertical_chars.values_mut())
+            .flat_map(|chars| chars.values_mut());
+
+        for c in chars {
+            *c = f(*c);
+        }
+    }
+
     /// Override a character color on a horizontal line.
     pub fn set_horizontal_color(&mut self, pos: Position, c: ANSIBuf, offset: Offset) {
         let chars = self
@@ -159,7 +159,7 @@ fn adjust_row_range(
         return;
     }
 
-    inc_range(heights, max_span_height - range_height, start, end);
+    inc_range_evenly(heights, max_span_height - range_height, start, end);
 }
 
 fn range_height(
@@ -202,6 +202,26 @@ fn inc_range(list: &mut [usize], size: usize, start: usize, end: usize) {
     }
 }
 
+/// Increases a range of values by `size` in total,
+/// so they differ at most by 1 and the leftover goes to the first ones.
+fn inc_range_evenly(list: &mut [usize], size: usize, start: usize, end: usize) {
+    if list.is_empty() {
+        return;
+    }
+
+    let span = end - start;
+    let one = size / span;
+    let rest = size - span * one;
+
+    for (i, value) in list[start..end].iter_mut().enumerate() {
+        *value += one;
+
+        if i < rest {
+            *value += 1;
+        }
+    }
+}
+
 fn adjust_vspans(
     cfg: &SpannedConfig,
     len: usize,
@@ -15,11 +15,25 @@ pub struct CellInfo<S> {
 
 impl<S> CellInfo<S> {
     /// Creates a new instance of the structure.
+    ///
+    /// A single trailing `\n` character doesn't start a new line,
+    /// so `"a\n"` has 1 line and `"\n\n"` has 2 lines.
     pub fn new(text: S) -> Self
     where
         S: AsRef<str>,
     {
-        create_cell_info(text)
+        create_cell_info(text, false)
+    }
+
+    /// Creates a new instance of the structure,
+    /// where a trailing `\n` character starts a new empty line.
+    ///
+    /// So `"a\n"` has 2 lines and `"\n\n"` has 3 lines.
+    pub fn with_trailing_newline(text: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        create_cell_info(text, true)
     }
 
     /// Creates a new instance of the structure with a single line.
@@ -128,17 +142,25 @@ impl<'a> StrWithWidth<'a> {
     }
 }
 
-fn create_cell_info<S: AsRef<str>>(text: S) -> CellInfo<S> {
+fn create_cell_info<S: AsRef<str>>(text: S, keep_trailing_newline: bool) -> CellInfo<S> {
     let mut info = CellInfo {
         text,
         lines: vec![],
         width: 0,
     };
 
+    let content = info.text.as_ref();
+    let content = match content.strip_suffix('\n') {
+        Some(content) if !keep_trailing_newline => content,
+        _ => content,
+    };
+
+    let is_trimmed = content.len() != info.text.as_ref().len();
+
     // Here we do a small optimization.
     // We check if there's only 1 line in which case we don't allocate lines Vec
-    let count_lines = count_lines(info.text.as_ref());
-    if count_lines < 2 {
+    let count_lines = count_lines(content);
+    if count_lines < 2 && !is_trimmed {
         info.width = string::string_width_multiline(info.text.as_ref());
         return info;
     }
@@ -155,7 +177,7 @@ fn create_cell_info<S: AsRef<str>>(text: S) -> CellInfo<S> {
     let text = unsafe {
         std::str::from_utf8_unchecked(std::slice::from_raw_parts(
             info.text.as_ref().as_ptr(),
-            info.text.as_ref().len(),
+            content.len(),
         ))
     };
 
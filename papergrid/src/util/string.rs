@@ -9,16 +9,9 @@
 pub fn string_dimension(text: &str) -> (usize, usize) {
     #[cfg(not(feature = "ansi"))]
     {
-        let (lines, acc, max) = text.chars().fold((1, 0, 0), |(lines, acc, max), c| {
-            if c == '\n' {
-                (lines + 1, 0, acc.max(max))
-            } else {
-                let w = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
-                (lines, acc + w, max)
-            }
-        });
-
-        (lines, acc.max(max))
+        text.split('\n')
+            .map(str_width)
+            .fold((0, 0), |(i, acc), width| (i + 1, acc.max(width)))
     }
 
     #[cfg(feature = "ansi")]
@@ -33,30 +26,40 @@ pub fn string_dimension(text: &str) -> (usize, usize) {
 pub fn string_width(text: &str) -> usize {
     #[cfg(not(feature = "ansi"))]
     {
-        unicode_width::UnicodeWidthStr::width(text)
+        str_width(text)
     }
 
     #[cfg(feature = "ansi")]
     {
-        // we need to strip ansi because of terminal links
-        // and they're can't be stripped by ansi_str.
-
-        ansitok::parse_ansi(text)
-            .filter(|e| e.kind() == ansitok::ElementKind::Text)
-            .map(|e| &text[e.start()..e.end()])
-            .map(unicode_width::UnicodeWidthStr::width)
-            .sum()
+        ansi_text_parts(text).map(str_width).sum()
     }
 }
 
+/// Removes ansi sequences from a string.
+///
+/// Sequences are recognized the same way [`string_width`] does it,
+/// so the width of a stripped string is the same as the width of the original one.
+#[cfg(all(feature = "std", feature = "ansi"))]
+pub fn strip_ansi(text: &str) -> String {
+    ansi_text_parts(text).collect()
+}
+
+/// Returns parts of a string which are not ansi sequences.
+#[cfg(feature = "ansi")]
+fn ansi_text_parts(text: &str) -> impl Iterator<Item = &str> {
+    // we need to strip ansi because of terminal links
+    // and they're can't be stripped by ansi_str.
+
+    ansitok::parse_ansi(text)
+        .filter(|e| e.kind() == ansitok::ElementKind::Text)
+        .map(move |e| &text[e.start()..e.end()])
+}
+
 /// Returns a max string width of a line.
 pub fn string_width_multiline(text: &str) -> usize {
     #[cfg(not(feature = "ansi"))]
     {
-        text.lines()
-            .map(unicode_width::UnicodeWidthStr::width)
-            .max()
-            .unwrap_or(0)
+        text.lines().map(str_width).max().unwrap_or(0)
     }
 
     #[cfg(feature = "ansi")]
@@ -65,6 +68,59 @@ pub fn string_width_multiline(text: &str) -> usize {
     }
 }
 
+/// Returns a width of a string (without ansi sequences).
+fn str_width(text: &str) -> usize {
+    let mut width = CharWidth::new();
+    text.chars().map(|c| width.measure(c)).sum()
+}
+
+/// Measures a width of chars of a text one by one,
+/// so a text can be cut the same way [`string_width`] measures it.
+///
+/// Invisible characters (zero width spaces, word joiners, BOMs, soft hyphens) are not counted.
+/// An emoji joined by a zero width joiner is rendered as a single glyph,
+/// so it's not counted either.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CharWidth {
+    last_width: usize,
+    is_joined: bool,
+}
+
+impl CharWidth {
+    /// Creates a measurement of a text start.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a width of a next char of a text.
+    pub fn measure(&mut self, c: char) -> usize {
+        const ZWJ: char = '\u{200D}';
+
+        let width = char_width(c);
+
+        let is_joined_emoji = self.is_joined && self.last_width == 2 && width == 2;
+        self.is_joined = c == ZWJ;
+
+        if is_joined_emoji {
+            return 0;
+        }
+
+        if width > 0 {
+            self.last_width = width;
+        }
+
+        width
+    }
+}
+
+fn char_width(c: char) -> usize {
+    match c {
+        // soft hyphen is not rendered unless a word is broken
+        '\u{00AD}' => 0,
+        c => unicode_width::UnicodeWidthChar::width(c).unwrap_or(0),
+    }
+}
+
 /// Calculates a number of lines.
 pub fn count_lines(s: &str) -> usize {
     if s.is_empty() {
@@ -74,6 +130,13 @@ pub fn count_lines(s: &str) -> usize {
     bytecount::count(s.as_bytes(), b'\n') + 1
 }
 
+/// Calculates a number of rendered lines.
+///
+/// Unlike [`count_lines`] a single trailing `\n` character doesn't start a new line.
+pub fn count_rendered_lines(s: &str) -> usize {
+    count_lines(s.strip_suffix('\n').unwrap_or(s))
+}
+
 /// Returns a list of tabs (`\t`) in a string..
 pub fn count_tabs(s: &str) -> usize {
     bytecount::count(s.as_bytes(), b'\t')
@@ -186,6 +249,17 @@ mod tests {
         assert_eq!(string_width_multiline("Go 👍\nC 😎"), 5);
     }
 
+    #[test]
+    fn string_width_invisible_test() {
+        assert_eq!(string_width("\u{FEFF}abc"), 3);
+        assert_eq!(string_width("a\u{200B}b\u{2060}c"), 3);
+        assert_eq!(string_width("soft\u{00AD}hyphen"), 10);
+        assert_eq!(string_width("👨\u{200D}👩\u{200D}👧"), 2);
+        assert_eq!(string_width("👍\u{200D}"), 2);
+        assert_eq!(string_width("a\u{200D}👍"), 3);
+        assert_eq!(string_width_multiline("\u{FEFF}ab\n👨\u{200D}👩"), 2);
+    }
+
     #[cfg(feature = "ansi")]
     #[test]
     fn colored_string_width_test() {
@@ -208,6 +282,16 @@ mod tests {
         assert_eq!(count_lines("now is the time for all good men\n"), 2);
     }
 
+    #[test]
+    fn count_rendered_lines_test() {
+        assert_eq!(count_rendered_lines(""), 1);
+        assert_eq!(count_rendered_lines("a"), 1);
+        assert_eq!(count_rendered_lines("a\n"), 1);
+        assert_eq!(count_rendered_lines("\n"), 1);
+        assert_eq!(count_rendered_lines("\n\n"), 2);
+        assert_eq!(count_rendered_lines("a\nb\n"), 2);
+    }
+
     #[cfg(feature = "ansi")]
     #[test]
     fn string_width_multinline_for_link() {
@@ -228,6 +312,15 @@ mod tests {
         );
     }
 
+    #[cfg(all(feature = "std", feature = "ansi"))]
+    #[test]
+    fn strip_ansi_test() {
+        let link = "\u{1b}]8;;file:///home/nushell/asd.zip\u{1b}\\asd.zip\u{1b}]8;;\u{1b}\\";
+        assert_eq!(strip_ansi(link), "asd.zip");
+        assert_eq!(strip_ansi("\u{1b}[31;1mred\u{1b}[0m\nline"), "red\nline");
+        assert_eq!(strip_ansi("plain"), "plain");
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn string_dimension_test() {
@@ -0,0 +1,66 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use tabled::{
+    builder::Builder,
+    grid::{
+        colors::NoColors,
+        dimension::{Estimate, PeekableDimension},
+        PeekableGrid,
+    },
+    Table,
+};
+
+fn cell(row: usize, col: usize) -> String {
+    (row * col).to_string()
+}
+
+pub fn build(c: &mut Criterion) {
+    let mut group = c.benchmark_group("build");
+    for size in [8, 64, 512, 1024] {
+        group.bench_with_input(BenchmarkId::new("from_fn", size), &size, |b, &size| {
+            b.iter(|| black_box(Table::from_fn(size, 16, cell)));
+        });
+
+        group.bench_with_input(BenchmarkId::new("collect", size), &size, |b, &size| {
+            b.iter(|| {
+                let data = (0..size)
+                    .map(|row| (0..16).map(|col| cell(row, col)).collect::<Vec<_>>())
+                    .collect::<Vec<_>>();
+
+                black_box(Builder::from(data).build())
+            });
+        });
+    }
+    group.finish();
+}
+
+pub fn render_builder(c: &mut Criterion) {
+    let mut group = c.benchmark_group("render_builder");
+    for size in [8, 64, 512, 1024] {
+        let data = (0..size)
+            .map(|row| (0..16).map(|col| cell(row, col)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let builder = Builder::from(data);
+
+        group.bench_with_input(BenchmarkId::new("build", size), &builder, |b, builder| {
+            b.iter(|| black_box(builder.clone().build().to_string()));
+        });
+
+        group.bench_with_input(BenchmarkId::new("records_ref", size), &builder, |b, builder| {
+            let table = Table::default();
+            let cfg = table.get_config().as_ref();
+
+            b.iter(|| {
+                let records = builder.to_records_ref();
+                let mut dims = PeekableDimension::default();
+                dims.estimate(records, cfg);
+
+                black_box(PeekableGrid::new(records, cfg, &dims, NoColors).to_string())
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, build, render_builder);
+criterion_main!(benches);
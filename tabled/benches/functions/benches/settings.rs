@@ -0,0 +1,77 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use tabled::{
+    settings::{object::Segment, Format, Modify},
+    Table,
+};
+
+fn cell(row: usize, col: usize) -> String {
+    format!(" {}-{} ", row, col)
+}
+
+fn trim(text: &str) -> String {
+    text.trim().to_owned()
+}
+
+fn upper(text: &str) -> String {
+    text.to_uppercase()
+}
+
+fn wrap(text: &str) -> String {
+    format!("<{}>", text)
+}
+
+fn slash(text: &str) -> String {
+    text.replace('-', "/")
+}
+
+fn lower(text: &str) -> String {
+    text.to_lowercase()
+}
+
+pub fn format(c: &mut Criterion) {
+    let mut group = c.benchmark_group("format");
+    for size in [1024, 16384] {
+        let table = Table::from_fn(size, 16, cell);
+
+        group.bench_with_input(BenchmarkId::new("sequential", size), &table, |b, table| {
+            b.iter(|| {
+                let mut table = table.clone();
+                for _ in 0..2 {
+                    table
+                        .with(Modify::new(Segment::all()).with(Format::content(trim)))
+                        .with(Modify::new(Segment::all()).with(Format::content(upper)))
+                        .with(Modify::new(Segment::all()).with(Format::content(wrap)))
+                        .with(Modify::new(Segment::all()).with(Format::content(slash)))
+                        .with(Modify::new(Segment::all()).with(Format::content(lower)));
+                }
+
+                black_box(table)
+            });
+        });
+
+        group.bench_with_input(BenchmarkId::new("then", size), &table, |b, table| {
+            b.iter(|| {
+                let format = Format::content(trim)
+                    .then(upper)
+                    .then(wrap)
+                    .then(slash)
+                    .then(lower)
+                    .then(trim)
+                    .then(upper)
+                    .then(wrap)
+                    .then(slash)
+                    .then(lower);
+
+                let mut table = table.clone();
+                table.with(Modify::new(Segment::all()).with(format));
+
+                black_box(table)
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, format);
+criterion_main!(benches);
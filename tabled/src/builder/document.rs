@@ -0,0 +1,256 @@
+use std::fmt;
+
+/// Options which are used by [`Builder::from_toml`] and [`Builder::from_yaml`].
+///
+/// [`Builder::from_toml`]: crate::builder::Builder::from_toml
+/// [`Builder::from_yaml`]: crate::builder::Builder::from_yaml
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DocumentOptions {
+    arrays: ArrayStyle,
+}
+
+impl DocumentOptions {
+    /// Creates default options.
+    ///
+    /// Arrays are rendered inline by default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a way arrays are rendered.
+    pub fn arrays(mut self, style: ArrayStyle) -> Self {
+        self.arrays = style;
+        self
+    }
+}
+
+/// A way arrays of a document are rendered.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArrayStyle {
+    /// An array is a single value like `[a, b, c]`.
+    #[default]
+    Inline,
+    /// Each element has its own key like `servers[0].host`.
+    Indexed,
+}
+
+/// An error which is returned when a document can't be rendered as a key/value table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentError {
+    /// A top level value is not a table (a mapping in YAML), the found kind is kept.
+    UnsupportedTopLevel(&'static str),
+}
+
+impl fmt::Display for DocumentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedTopLevel(kind) => write!(
+                f,
+                "expected a table at the top level of a document but found {}",
+                kind
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DocumentError {}
+
+/// Flattens a TOML table into a list of dotted keys and values.
+#[cfg(feature = "toml")]
+pub(super) fn flatten_toml(
+    value: &toml::Value,
+    opts: &DocumentOptions,
+) -> Result<Vec<(String, String)>, DocumentError> {
+    let table = match value {
+        toml::Value::Table(table) => table,
+        value => return Err(DocumentError::UnsupportedTopLevel(toml_kind(value))),
+    };
+
+    let mut pairs = Vec::new();
+    for (key, value) in table {
+        flatten_toml_value(key.clone(), value, opts, &mut pairs);
+    }
+
+    Ok(pairs)
+}
+
+#[cfg(feature = "toml")]
+fn flatten_toml_value(
+    key: String,
+    value: &toml::Value,
+    opts: &DocumentOptions,
+    pairs: &mut Vec<(String, String)>,
+) {
+    match value {
+        toml::Value::Table(table) if !table.is_empty() => {
+            for (name, value) in table {
+                flatten_toml_value(format!("{}.{}", key, name), value, opts, pairs);
+            }
+        }
+        toml::Value::Array(list) if !list.is_empty() && opts.arrays == ArrayStyle::Indexed => {
+            for (i, value) in list.iter().enumerate() {
+                flatten_toml_value(format!("{}[{}]", key, i), value, opts, pairs);
+            }
+        }
+        value => {
+            let mut text = String::new();
+            inline_toml(value, &mut text);
+            pairs.push((key, text));
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+fn inline_toml(value: &toml::Value, buf: &mut String) {
+    match value {
+        toml::Value::String(text) => buf.push_str(text),
+        toml::Value::Array(list) => {
+            buf.push('[');
+            for (i, value) in list.iter().enumerate() {
+                if i > 0 {
+                    buf.push_str(", ");
+                }
+
+                inline_toml(value, buf);
+            }
+            buf.push(']');
+        }
+        toml::Value::Table(table) => {
+            buf.push('{');
+            for (i, (key, value)) in table.iter().enumerate() {
+                if i > 0 {
+                    buf.push_str(", ");
+                }
+
+                buf.push_str(key);
+                buf.push_str(" = ");
+                inline_toml(value, buf);
+            }
+            buf.push('}');
+        }
+        value => buf.push_str(&value.to_string()),
+    }
+}
+
+#[cfg(feature = "toml")]
+fn toml_kind(value: &toml::Value) -> &'static str {
+    match value {
+        toml::Value::String(_) => "a string",
+        toml::Value::Integer(_) => "an integer",
+        toml::Value::Float(_) => "a float",
+        toml::Value::Boolean(_) => "a boolean",
+        toml::Value::Datetime(_) => "a datetime",
+        toml::Value::Array(_) => "an array",
+        toml::Value::Table(_) => "a table",
+    }
+}
+
+/// Flattens a YAML mapping into a list of dotted keys and values.
+#[cfg(feature = "serde_yaml")]
+pub(super) fn flatten_yaml(
+    value: &serde_yaml::Value,
+    opts: &DocumentOptions,
+) -> Result<Vec<(String, String)>, DocumentError> {
+    let mapping = match value {
+        serde_yaml::Value::Mapping(mapping) => mapping,
+        value => return Err(DocumentError::UnsupportedTopLevel(yaml_kind(value))),
+    };
+
+    let mut pairs = Vec::new();
+    for (key, value) in mapping {
+        flatten_yaml_value(yaml_key(key), value, opts, &mut pairs);
+    }
+
+    Ok(pairs)
+}
+
+#[cfg(feature = "serde_yaml")]
+fn flatten_yaml_value(
+    key: String,
+    value: &serde_yaml::Value,
+    opts: &DocumentOptions,
+    pairs: &mut Vec<(String, String)>,
+) {
+    use serde_yaml::Value;
+
+    match value {
+        Value::Mapping(mapping) if !mapping.is_empty() => {
+            for (name, value) in mapping {
+                flatten_yaml_value(format!("{}.{}", key, yaml_key(name)), value, opts, pairs);
+            }
+        }
+        Value::Sequence(list) if !list.is_empty() && opts.arrays == ArrayStyle::Indexed => {
+            for (i, value) in list.iter().enumerate() {
+                flatten_yaml_value(format!("{}[{}]", key, i), value, opts, pairs);
+            }
+        }
+        value => {
+            let mut text = String::new();
+            inline_yaml(value, &mut text);
+            pairs.push((key, text));
+        }
+    }
+}
+
+#[cfg(feature = "serde_yaml")]
+fn yaml_key(key: &serde_yaml::Value) -> String {
+    let mut text = String::new();
+    inline_yaml(key, &mut text);
+    text
+}
+
+#[cfg(feature = "serde_yaml")]
+fn inline_yaml(value: &serde_yaml::Value, buf: &mut String) {
+    use serde_yaml::Value;
+
+    match value {
+        Value::Null => buf.push_str("null"),
+        Value::Bool(value) => buf.push_str(&value.to_string()),
+        Value::Number(value) => buf.push_str(&value.to_string()),
+        Value::String(text) => buf.push_str(text),
+        Value::Sequence(list) => {
+            buf.push('[');
+            for (i, value) in list.iter().enumerate() {
+                if i > 0 {
+                    buf.push_str(", ");
+                }
+
+                inline_yaml(value, buf);
+            }
+            buf.push(']');
+        }
+        Value::Mapping(mapping) => {
+            buf.push('{');
+            for (i, (key, value)) in mapping.iter().enumerate() {
+                if i > 0 {
+                    buf.push_str(", ");
+                }
+
+                inline_yaml(key, buf);
+                buf.push_str(": ");
+                inline_yaml(value, buf);
+            }
+            buf.push('}');
+        }
+        Value::Tagged(tagged) => {
+            buf.push_str(&tagged.tag.to_string());
+            buf.push(' ');
+            inline_yaml(&tagged.value, buf);
+        }
+    }
+}
+
+#[cfg(feature = "serde_yaml")]
+fn yaml_kind(value: &serde_yaml::Value) -> &'static str {
+    use serde_yaml::Value;
+
+    match value {
+        Value::Null => "a null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Sequence(_) => "a sequence",
+        Value::Mapping(_) => "a mapping",
+        Value::Tagged(_) => "a tagged value",
+    }
+}
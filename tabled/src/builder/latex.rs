@@ -0,0 +1,84 @@
+use crate::grid::records::vec_records::CellInfo;
+
+/// Options which are used by [`Builder::build_latex`].
+///
+/// [`Builder::build_latex`]: crate::builder::Builder::build_latex
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct LatexOptions {
+    column_spec: Option<String>,
+}
+
+impl LatexOptions {
+    /// Creates default options.
+    ///
+    /// All columns are left aligned by default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a column specification of a `tabular` environment,
+    /// like `lcr` (left, center, right).
+    ///
+    /// The string is used as is.
+    pub fn column_spec<S>(mut self, spec: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.column_spec = Some(spec.into());
+        self
+    }
+}
+
+pub(crate) fn build_latex(
+    data: &[Vec<CellInfo<String>>],
+    count_columns: usize,
+    opts: &LatexOptions,
+) -> String {
+    let spec = match &opts.column_spec {
+        Some(spec) => spec.clone(),
+        None => "l".repeat(count_columns),
+    };
+
+    let mut buf = String::new();
+    buf.push_str("\\begin{tabular}{");
+    buf.push_str(&spec);
+    buf.push_str("}\n");
+
+    if !data.is_empty() {
+        buf.push_str("\\hline\n");
+    }
+
+    for (i, row) in data.iter().enumerate() {
+        for (col, cell) in row.iter().enumerate() {
+            if col > 0 {
+                buf.push_str(" & ");
+            }
+
+            escape_latex(&mut buf, cell.as_ref());
+        }
+
+        buf.push_str(" \\\\\n");
+
+        if i == 0 || i + 1 == data.len() {
+            buf.push_str("\\hline\n");
+        }
+    }
+
+    buf.push_str("\\end{tabular}");
+
+    buf
+}
+
+fn escape_latex(buf: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' | '%' | '_' | '#' | '$' | '{' | '}' => {
+                buf.push('\\');
+                buf.push(c);
+            }
+            '\\' => buf.push_str("\\textbackslash{}"),
+            '\n' => buf.push(' '),
+            c => buf.push(c),
+        }
+    }
+}
@@ -108,8 +108,25 @@
 //!
 //! [`Table`]: crate::Table
 
+#[cfg(any(feature = "toml", feature = "serde_yaml"))]
+mod document;
 mod index_builder;
+mod latex;
+#[cfg(feature = "serde_json")]
+mod ndjson;
+mod records_ref;
 mod table_builder;
+mod table_def;
+mod tsv;
 
+#[cfg(any(feature = "toml", feature = "serde_yaml"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "toml", feature = "serde_yaml"))))]
+pub use document::{ArrayStyle, DocumentError, DocumentOptions};
 pub use index_builder::IndexBuilder;
-pub use table_builder::Builder;
+pub use latex::LatexOptions;
+pub use records_ref::BuilderRecords;
+pub use table_builder::{
+    Builder, CellDiff, ColumnCountMismatch, ColumnGroupError, JoinType, RaggedRecord, WidthHazard,
+};
+pub use table_def::{Schema, SchemaError, TableDef};
+pub use tsv::{ParseError, TsvPolicy};
@@ -0,0 +1,88 @@
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+use serde_json::Value;
+
+use super::Builder;
+
+/// Parses newline-delimited JSON objects into a [`Builder`].
+///
+/// The header is a union of keys of all objects in order they were first seen.
+pub(super) fn parse_ndjson(text: &str) -> Result<Builder, serde_json::Error> {
+    let mut header: Vec<String> = Vec::new();
+    let mut objects = Vec::new();
+
+    for line in text.lines().filter(|line| !line.trim().is_empty()) {
+        let object: Object = serde_json::from_str(line)?;
+
+        for (key, _) in &object.0 {
+            if !header.contains(key) {
+                header.push(key.clone());
+            }
+        }
+
+        objects.push(object);
+    }
+
+    if objects.is_empty() {
+        return Ok(Builder::default());
+    }
+
+    let mut builder = Builder::with_capacity(objects.len() + 1, header.len());
+    builder.push_record(header.clone());
+
+    for object in objects {
+        let mut record = vec![String::new(); header.len()];
+        for (key, value) in object.0 {
+            if let Some(col) = header.iter().position(|k| *k == key) {
+                record[col] = value_to_string(value);
+            }
+        }
+
+        builder.push_record(record);
+    }
+
+    Ok(builder)
+}
+
+fn value_to_string(value: Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text,
+        value => value.to_string(),
+    }
+}
+
+/// A JSON object which keeps an order of its keys.
+struct Object(Vec<(String, Value)>);
+
+impl<'de> Deserialize<'de> for Object {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(ObjectVisitor)
+    }
+}
+
+struct ObjectVisitor;
+
+impl<'de> Visitor<'de> for ObjectVisitor {
+    type Value = Object;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a JSON object")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = Vec::new();
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+
+        Ok(Object(entries))
+    }
+}
@@ -0,0 +1,86 @@
+use crate::grid::{
+    config::Position,
+    records::{
+        vec_records::{Cell, CellInfo},
+        ExactRecords, IntoRecords, PeekableRecords, Records,
+    },
+};
+
+/// [`BuilderRecords`] is a borrowed view of [`Builder`] records,
+/// which can be used by a grid directly, without building a [`Table`].
+///
+/// The first record is a header, as it is in the [`Builder`].
+/// Missing cells of ragged records are considered to be empty.
+///
+/// It's supposed to be created by [`Builder::to_records_ref`].
+///
+/// [`Builder`]: crate::builder::Builder
+/// [`Builder::to_records_ref`]: crate::builder::Builder::to_records_ref
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone, Copy)]
+pub struct BuilderRecords<'a> {
+    data: &'a [Vec<CellInfo<String>>],
+    count_columns: usize,
+}
+
+impl<'a> BuilderRecords<'a> {
+    pub(crate) fn new(data: &'a [Vec<CellInfo<String>>], count_columns: usize) -> Self {
+        Self {
+            data,
+            count_columns,
+        }
+    }
+
+    /// Returns the records.
+    pub fn rows(&self) -> &'a [Vec<CellInfo<String>>] {
+        self.data
+    }
+
+    fn get_cell(&self, (row, col): Position) -> Option<&'a CellInfo<String>> {
+        self.data.get(row).and_then(|record| record.get(col))
+    }
+}
+
+impl<'a> Records for BuilderRecords<'a> {
+    type Iter = &'a [Vec<CellInfo<String>>];
+
+    fn iter_rows(self) -> <Self::Iter as IntoRecords>::IterRows {
+        self.data.iter_rows()
+    }
+
+    fn count_columns(&self) -> usize {
+        self.count_columns
+    }
+
+    fn hint_count_rows(&self) -> Option<usize> {
+        Some(self.data.len())
+    }
+}
+
+impl ExactRecords for BuilderRecords<'_> {
+    fn count_rows(&self) -> usize {
+        self.data.len()
+    }
+}
+
+impl PeekableRecords for BuilderRecords<'_> {
+    fn get_text(&self, pos: Position) -> &str {
+        self.get_cell(pos).map_or("", Cell::text)
+    }
+
+    fn count_lines(&self, pos: Position) -> usize {
+        self.get_cell(pos).map_or(1, Cell::count_lines)
+    }
+
+    fn get_line(&self, pos: Position, line: usize) -> &str {
+        self.get_cell(pos).map_or("", |cell| cell.line(line))
+    }
+
+    fn get_line_width(&self, pos: Position, line: usize) -> usize {
+        self.get_cell(pos).map_or(0, |cell| cell.line_width(line))
+    }
+
+    fn get_width(&self, pos: Position) -> usize {
+        self.get_cell(pos).map_or(0, Cell::width)
+    }
+}
@@ -1,8 +1,14 @@
-use std::iter::FromIterator;
+use std::{cmp::Ordering, collections::HashSet, fmt, iter::FromIterator, ops::Range};
 
-use crate::{grid::records::vec_records::CellInfo, Table};
+use crate::{
+    builder::latex::{build_latex, LatexOptions},
+    grid::{config::Entity, records::vec_records::CellInfo, util::string::CharWidth},
+    settings::{object::Columns, panel::check_column_group, summary::Summary, Alignment, Width},
+    util::string::is_number,
+    Table, Tabled,
+};
 
-use super::IndexBuilder;
+use super::{BuilderRecords, IndexBuilder, ParseError, TsvPolicy};
 
 /// Builder creates a [`Table`] from dynamic data set.
 ///
@@ -44,6 +50,18 @@ pub struct Builder {
     count_columns: usize,
     /// A content of cells which are created in case rows has different length.
     empty_text: CellInfo<String>,
+    /// A content of empty cells which is set for particular columns.
+    column_empty_texts: Vec<(usize, CellInfo<String>)>,
+    /// A list of values which are considered to be empty.
+    null_values: Vec<String>,
+    /// A list of labels which span a range of columns above the header.
+    column_groups: Vec<(Range<usize>, String)>,
+    /// A list of default alignments of columns.
+    alignment_hints: Vec<(usize, Alignment)>,
+    /// A list of max widths of columns with an ellipsis used on truncation.
+    column_max_widths: Vec<(usize, usize, String)>,
+    /// A max number of records kept by [`Builder::push_record`].
+    max_records: Option<usize>,
 }
 
 impl Builder {
@@ -94,11 +112,299 @@ impl Builder {
             data,
             count_columns,
             empty_text: CellInfo::default(),
+            column_empty_texts: Vec::new(),
+            null_values: Vec::new(),
+            column_groups: Vec::new(),
+            alignment_hints: Vec::new(),
+            column_max_widths: Vec::new(),
+            max_records: None,
         }
     }
 
+    /// Creates a [`Builder`] with environment variables of the current process.
+    ///
+    /// The first record is a header `Variable`, `Value`.
+    /// Variables are sorted by a name.
+    /// Names and values which are not valid unicode are converted lossy.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let table = Builder::from_env().build();
+    ///
+    /// println!("{}", table);
+    /// ```
+    pub fn from_env() -> Self {
+        let mut vars = std::env::vars_os()
+            .map(|(key, value)| {
+                let key = key.to_string_lossy().into_owned();
+                let value = value.to_string_lossy().into_owned();
+                (key, value)
+            })
+            .collect::<Vec<_>>();
+
+        vars.sort();
+
+        Self::from_key_value_pairs(vars, "Variable", "Value")
+    }
+
+    /// Creates a [`Builder`] with 2 columns from a list of key-value pairs.
+    ///
+    /// The first record is a header made of `key_header` and `value_header`.
+    /// Pairs are kept in the given order, including ones with the same key.
+    ///
+    /// Pairs can be sorted by [`Builder::sort_records_by_column`],
+    /// and the header can be removed by [`Builder::take_header`].
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut langs = BTreeMap::new();
+    /// langs.insert("Rust", "2015");
+    /// langs.insert("Go", "2012");
+    ///
+    /// let table = Builder::from_key_value_pairs(langs, "language", "since").build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+----------+-------+\n\
+    ///      | language | since |\n\
+    ///      +----------+-------+\n\
+    ///      | Go       | 2012  |\n\
+    ///      +----------+-------+\n\
+    ///      | Rust     | 2015  |\n\
+    ///      +----------+-------+"
+    /// );
+    ///
+    /// let pairs = [("port", "8080"), ("host", "localhost"), ("port", "8081")];
+    ///
+    /// let mut builder = Builder::from_key_value_pairs(pairs, "key", "value");
+    /// builder.sort_records_by_column(0);
+    /// builder.take_header();
+    ///
+    /// assert_eq!(
+    ///     builder.build().to_string(),
+    ///     "+------+-----------+\n\
+    ///      | host | localhost |\n\
+    ///      +------+-----------+\n\
+    ///      | port | 8080      |\n\
+    ///      +------+-----------+\n\
+    ///      | port | 8081      |\n\
+    ///      +------+-----------+"
+    /// );
+    /// ```
+    pub fn from_key_value_pairs<I, K, V>(
+        pairs: I,
+        key_header: impl Into<String>,
+        value_header: impl Into<String>,
+    ) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let pairs = pairs.into_iter();
+
+        let mut builder = Self::with_capacity(pairs.size_hint().0 + 1, 2);
+        builder.push_record([key_header.into(), value_header.into()]);
+
+        for (key, value) in pairs {
+            builder.push_record([key.into(), value.into()]);
+        }
+
+        builder
+    }
+
+    /// Creates a [`Builder`] from newline-delimited JSON objects (NDJSON).
+    ///
+    /// The first record is a header made of keys of all objects,
+    /// in order they are first seen.
+    /// Keys which an object is missing are left empty.
+    ///
+    /// Strings are used as they are, `null`s are empty,
+    /// and the rest values are rendered as JSON.
+    /// Blank lines are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a line is not a valid JSON object.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let logs = r#"
+    /// {"level": "info", "msg": "started"}
+    /// {"level": "warn", "msg": "slow response", "ms": 1200}
+    /// "#;
+    ///
+    /// let table = Builder::from_ndjson(logs).unwrap().build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+---------------+------+\n\
+    ///      | level | msg           | ms   |\n\
+    ///      +-------+---------------+------+\n\
+    ///      | info  | started       |      |\n\
+    ///      +-------+---------------+------+\n\
+    ///      | warn  | slow response | 1200 |\n\
+    ///      +-------+---------------+------+"
+    /// );
+    /// ```
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+    pub fn from_ndjson(text: &str) -> Result<Self, serde_json::Error> {
+        super::ndjson::parse_ndjson(text)
+    }
+
+    /// Creates a [`Builder`] from tab-separated values (TSV).
+    ///
+    /// Each tab is a delimiter, there's no quoting or escaping,
+    /// so a text of a cell is taken as it is.
+    /// The first line is a header, each following line is a record (a blank line included).
+    ///
+    /// TSV has no way to tell whether the first line is a header,
+    /// so for an input without one a header can be added by [`Builder::insert_record`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input can't be read,
+    /// or if a line has a different amount of cells than the first one.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let tsv = "gene\tchr\tstart\nBRCA1\t17\t43044295\nTP53\t17\t7668402\n";
+    ///
+    /// let table = Builder::from_tsv(tsv.as_bytes()).unwrap().build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+-----+----------+\n\
+    ///      | gene  | chr | start    |\n\
+    ///      +-------+-----+----------+\n\
+    ///      | BRCA1 | 17  | 43044295 |\n\
+    ///      +-------+-----+----------+\n\
+    ///      | TP53  | 17  | 7668402  |\n\
+    ///      +-------+-----+----------+"
+    /// );
+    /// ```
+    pub fn from_tsv<R: std::io::Read>(reader: R) -> Result<Self, ParseError> {
+        super::tsv::parse_tsv(reader)
+    }
+
+    /// Creates a [`Builder`] with 2 columns `key` and `value` from a TOML document.
+    ///
+    /// Nested tables are flattened using dotted keys, like `server.port`.
+    /// Arrays are rendered according to [`DocumentOptions::arrays`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document is not a table.
+    ///
+    /// ```
+    /// use tabled::builder::{ArrayStyle, Builder, DocumentOptions};
+    ///
+    /// let config: toml::Value = toml::from_str(
+    ///     r#"
+    ///     name = "app"
+    ///     tags = ["web", "api"]
+    ///
+    ///     [server]
+    ///     port = 8080
+    ///     "#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let opts = DocumentOptions::new().arrays(ArrayStyle::Indexed);
+    /// let table = Builder::from_toml(&config, opts).unwrap().build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------------+-------+\n\
+    ///      | key         | value |\n\
+    ///      +-------------+-------+\n\
+    ///      | name        | app   |\n\
+    ///      +-------------+-------+\n\
+    ///      | server.port | 8080  |\n\
+    ///      +-------------+-------+\n\
+    ///      | tags[0]     | web   |\n\
+    ///      +-------------+-------+\n\
+    ///      | tags[1]     | api   |\n\
+    ///      +-------------+-------+"
+    /// );
+    /// ```
+    ///
+    /// [`DocumentOptions::arrays`]: crate::builder::DocumentOptions::arrays
+    #[cfg(feature = "toml")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "toml")))]
+    pub fn from_toml(
+        value: &toml::Value,
+        opts: super::DocumentOptions,
+    ) -> Result<Self, super::DocumentError> {
+        let pairs = super::document::flatten_toml(value, &opts)?;
+        Ok(Self::from_key_value_pairs(pairs, "key", "value"))
+    }
+
+    /// Creates a [`Builder`] with 2 columns `key` and `value` from a YAML document.
+    ///
+    /// The same as [`Builder::from_toml`] but for a YAML mapping.
+    /// Nested mappings are flattened into dotted keys,
+    /// and arrays are rendered according to [`DocumentOptions::arrays`].
+    /// Keys are kept in the order of the document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document is not a mapping.
+    ///
+    /// ```
+    /// use tabled::builder::{ArrayStyle, Builder, DocumentOptions};
+    ///
+    /// let config: serde_yaml::Value = serde_yaml::from_str(
+    ///     r#"
+    ///     name: app
+    ///     server:
+    ///       port: 8080
+    ///     tags: [web, api]
+    ///     "#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let opts = DocumentOptions::new().arrays(ArrayStyle::Indexed);
+    /// let table = Builder::from_yaml(&config, opts).unwrap().build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------------+-------+\n\
+    ///      | key         | value |\n\
+    ///      +-------------+-------+\n\
+    ///      | name        | app   |\n\
+    ///      +-------------+-------+\n\
+    ///      | server.port | 8080  |\n\
+    ///      +-------------+-------+\n\
+    ///      | tags[0]     | web   |\n\
+    ///      +-------------+-------+\n\
+    ///      | tags[1]     | api   |\n\
+    ///      +-------------+-------+"
+    /// );
+    /// ```
+    ///
+    /// [`DocumentOptions::arrays`]: crate::builder::DocumentOptions::arrays
+    #[cfg(feature = "serde_yaml")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_yaml")))]
+    pub fn from_yaml(
+        value: &serde_yaml::Value,
+        opts: super::DocumentOptions,
+    ) -> Result<Self, super::DocumentError> {
+        let pairs = super::document::flatten_yaml(value, &opts)?;
+        Ok(Self::from_key_value_pairs(pairs, "key", "value"))
+    }
+
     /// Sets a content of cells which are created in case rows has different length.
     ///
+    /// It affects only cells which are created afterwards,
+    /// rows which were already padded are left as is.
+    /// To change them as well use [`Builder::repad_with`].
     ///
     /// ```rust
     /// use tabled::builder::Builder;
@@ -115,230 +421,2174 @@ impl Builder {
         self.empty_text = CellInfo::new(text.into());
     }
 
-    /// Build creates a [`Table`] instance.
+    /// Sets a content of cells which are created in case rows has different length,
+    /// replacing cells which were already created this way.
+    ///
+    /// Trailing cells of a row which are equal to the previous text
+    /// are considered to be created by padding.
+    /// Columns with a text set by [`Builder::set_column_empty`] are left as is.
     ///
     /// ```rust
     /// use tabled::builder::Builder;
     ///
     /// let mut builder = Builder::default();
-    /// builder.push_record(["i", "column1", "column2"]);
-    /// builder.push_record(["0", "value1", "value2"]);
+    /// builder.set_empty("?");
+    /// builder.push_record(["name"]);
+    /// builder.push_record(["Alex", "23", "UK"]);
+    /// builder.repad_with("-");
+    /// builder.push_record(["Max"]);
+    ///
+    /// let data: Vec<Vec<String>> = builder.into();
+    /// assert_eq!(data[0], ["name", "-", "-"]);
+    /// assert_eq!(data[2], ["Max", "-", "-"]);
     /// ```
-    pub fn build(self) -> Table {
-        Table::from(self)
+    pub fn repad_with<T>(&mut self, text: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        let text = CellInfo::new(text.into());
+
+        for row in &mut self.data {
+            for col in (0..row.len()).rev() {
+                let previous = get_empty_text(&self.empty_text, &self.column_empty_texts, col);
+                if row[col].as_ref() != previous.as_ref() {
+                    break;
+                }
+
+                let has_own_text = self.column_empty_texts.iter().any(|(c, _)| *c == col);
+                if !has_own_text {
+                    row[col] = text.clone();
+                }
+            }
+        }
+
+        self.empty_text = text;
+
+        self
     }
 
-    /// Add an index to the [`Table`].
+    /// Sets a content of cells of a given column
+    /// which are created in case rows has different length.
     ///
-    /// Default index is a range 0-N where N is amount of records.
+    /// It takes precedence over a text set by [`Builder::set_empty`].
+    /// To apply it to cells which are already empty use [`Builder::fill_empty`].
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_empty("-");
+    /// builder.set_column_empty(1, "0");
+    /// builder.push_record(["name", "count", "comment"]);
+    /// builder.push_record(["Alex"]);
+    ///
+    /// let table = builder.build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+-------+---------+\n\
+    ///      | name | count | comment |\n\
+    ///      +------+-------+---------+\n\
+    ///      | Alex | 0     | -       |\n\
+    ///      +------+-------+---------+"
+    /// )
+    /// ```
+    pub fn set_column_empty<T>(&mut self, col: usize, text: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.column_empty_texts.retain(|(c, _)| *c != col);
+        self.column_empty_texts
+            .push((col, CellInfo::new(text.into())));
+        self
+    }
+
+    /// Replaces all cells with an empty text by a text set
+    /// via [`Builder::set_empty`] or [`Builder::set_column_empty`].
+    ///
+    /// Notice that filled cells are no longer considered empty,
+    /// so [`Builder::clean`] will not remove them.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "count"]);
+    /// builder.push_record(["Alex", ""]);
+    /// builder.set_column_empty(1, "0");
+    /// builder.fill_empty();
+    ///
+    /// let data: Vec<Vec<String>> = builder.into();
+    /// assert_eq!(data[1], ["Alex", "0"]);
+    /// ```
+    pub fn fill_empty(&mut self) -> &mut Self {
+        for col in 0..self.count_columns {
+            let text = get_empty_text(&self.empty_text, &self.column_empty_texts, col);
+            if text.as_ref().is_empty() {
+                continue;
+            }
+
+            for row in &mut self.data {
+                if row[col].as_ref().is_empty() {
+                    row[col] = text.clone();
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Sets a list of values which are considered to be empty, like `NULL` or `NA`.
+    ///
+    /// Such cells are treated as empty ones by [`Builder::clean`] and [`Builder::count_empty_cells`].
+    /// They can be replaced by an empty text via [`Builder::replace_null_values`].
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_null_values(["NULL", "NA"]);
+    /// builder.push_record(["name", "last name"]);
+    /// builder.push_record(["Alex", "NA"]);
+    ///
+    /// assert_eq!(builder.count_empty_cells(), 1);
+    /// ```
+    pub fn set_null_values<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.null_values = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Replaces all cells which match a null value with an empty text.
+    ///
+    /// See [`Builder::set_null_values`] and [`Builder::set_empty`].
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_empty("-");
+    /// builder.set_null_values(["\\N"]);
+    /// builder.push_record(["name", "last name"]);
+    /// builder.push_record(["Alex", "\\N"]);
+    /// builder.replace_null_values();
+    ///
+    /// let table = builder.build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+-----------+\n\
+    ///      | name | last name |\n\
+    ///      +------+-----------+\n\
+    ///      | Alex | -         |\n\
+    ///      +------+-----------+"
+    /// )
+    /// ```
+    pub fn replace_null_values(&mut self) -> &mut Self {
+        for row in &mut self.data {
+            for (col, cell) in row.iter_mut().enumerate() {
+                if is_null(cell.as_ref(), &self.null_values) {
+                    *cell = get_empty_text(&self.empty_text, &self.column_empty_texts, col).clone();
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Returns an amount of empty cells.
+    ///
+    /// Cells which match a null value are considered to be empty.
+    /// See [`Builder::set_null_values`].
+    pub fn count_empty_cells(&self) -> usize {
+        self.data
+            .iter()
+            .flatten()
+            .filter(|cell| is_empty_cell(cell.as_ref(), &self.null_values))
+            .count()
+    }
+
+    /// Sets labels which span a range of columns,
+    /// so a [`Table`] is built with an additional first row above the header.
+    ///
+    /// Ranges must not be empty, overlap, or exceed the amount of columns.
+    /// Groups which exceed the amount of columns by the time of [`Builder::build`]
+    /// (e.g. after a column was removed) are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ColumnGroupError`] if any of the ranges is invalid.
+    /// Groups are not changed in such case.
     ///
     /// # Example
     ///
     /// ```
-    /// use tabled::Table;
+    /// use tabled::builder::Builder;
     ///
-    /// let table = Table::builder(&["Hello", "World", "!"]).index().build();
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["Jan", "Feb", "Mar", "Apr"]);
+    /// builder.push_record(["1", "2", "3", "4"]);
+    ///
+    /// builder
+    ///     .set_column_groups(vec![(0..3, String::from("Q1")), (3..4, String::from("Q2"))])
+    ///     .unwrap();
+    ///
+    /// let table = builder.build();
     ///
     /// assert_eq!(
     ///     table.to_string(),
-    ///     "+---+-------+\n\
-    ///      |   | &str  |\n\
-    ///      +---+-------+\n\
-    ///      | 0 | Hello |\n\
-    ///      +---+-------+\n\
-    ///      | 1 | World |\n\
-    ///      +---+-------+\n\
-    ///      | 2 | !     |\n\
-    ///      +---+-------+"
+    ///     "+-----+-----+-----+-----+\n\
+    ///      |       Q1        | Q2  |\n\
+    ///      +-----+-----+-----+-----+\n\
+    ///      | Jan | Feb | Mar | Apr |\n\
+    ///      +-----+-----+-----+-----+\n\
+    ///      | 1   | 2   | 3   | 4   |\n\
+    ///      +-----+-----+-----+-----+"
     /// )
     /// ```
-    pub fn index(self) -> IndexBuilder {
-        IndexBuilder::from(self)
+    pub fn set_column_groups(
+        &mut self,
+        groups: Vec<(Range<usize>, String)>,
+    ) -> Result<(), ColumnGroupError> {
+        for (i, (range, _)) in groups.iter().enumerate() {
+            check_column_group(&groups[..i], range, self.count_columns)?;
+        }
+
+        self.column_groups = groups;
+
+        Ok(())
+    }
+
+    /// Returns column groups and a builder without them.
+    pub(crate) fn take_column_groups(mut self) -> (Self, Vec<(Range<usize>, String)>) {
+        let groups = std::mem::take(&mut self.column_groups);
+        (self, groups)
+    }
+
+    /// Sets a default alignment of a column, which is used by a built [`Table`].
+    ///
+    /// The alignment is applied to the whole column, including its header.
+    /// Calling it again for the same column overrides the previous hint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{builder::Builder, settings::Alignment};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["item", "price"]);
+    /// builder.push_record(["Apple", "1.5"]);
+    /// builder.push_record(["Pear", "12"]);
+    /// builder.set_column_alignment_hint(1, Alignment::right());
+    ///
+    /// let table = builder.build().to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+-------+-------+\n\
+    ///      | item  | price |\n\
+    ///      +-------+-------+\n\
+    ///      | Apple |   1.5 |\n\
+    ///      +-------+-------+\n\
+    ///      | Pear  |    12 |\n\
+    ///      +-------+-------+"
+    /// )
+    /// ```
+    pub fn set_column_alignment_hint(&mut self, col: usize, alignment: Alignment) -> &mut Self {
+        self.alignment_hints.retain(|(c, _)| *c != col);
+        self.alignment_hints.push((col, alignment));
+        self
+    }
+
+    /// Returns a default alignment of a column if it was set.
+    ///
+    /// See [`Builder::set_column_alignment_hint`].
+    pub fn get_column_alignment_hint(&self, col: usize) -> Option<Alignment> {
+        self.alignment_hints
+            .iter()
+            .find(|(c, _)| *c == col)
+            .map(|(_, alignment)| *alignment)
+    }
+
+    /// Sets a right alignment hint for numeric columns.
+    ///
+    /// A column is considered numeric if all its non empty cells,
+    /// except the header (the first record), are numbers.
+    /// Numbers are recognized the same way as by [`AutoAlign::numbers`].
+    /// Columns with no values are left as is.
+    ///
+    /// See [`Builder::set_column_alignment_hint`].
+    ///
+    /// [`AutoAlign::numbers`]: crate::settings::AutoAlign::numbers
+    pub fn infer_numeric_columns(&mut self) -> &mut Self {
+        for col in 0..self.count_columns {
+            let mut values = self
+                .data
+                .iter()
+                .skip(1)
+                .map(|row| row[col].as_ref().trim())
+                .filter(|text| !is_empty_cell(text, &self.null_values))
+                .peekable();
+
+            let has_values = values.peek().is_some();
+            let is_numeric = values.all(is_number);
+
+            if has_values && is_numeric {
+                let _ = self.set_column_alignment_hint(col, Alignment::right());
+            }
+        }
+
+        self
+    }
+
+    /// Sets a max width of a column, which is used by a built [`Table`].
+    ///
+    /// Cells wider than `width` are truncated, ending with an `ellipsis`.
+    /// The header (the first record) is truncated as well.
+    ///
+    /// The data of the builder is kept as it is,
+    /// the truncation is applied only to a built [`Table`].
+    /// Calling it again for the same column overrides the previous rule.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "description"]);
+    /// builder.push_record(["tabled", "An easy to use library for pretty print tables"]);
+    /// builder.set_column_max_width(1, 16, "...");
+    ///
+    /// let table = builder.clone().build().to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+--------+------------------+\n\
+    ///      | name   | description      |\n\
+    ///      +--------+------------------+\n\
+    ///      | tabled | An easy to us... |\n\
+    ///      +--------+------------------+"
+    /// );
+    ///
+    /// let descriptions: Vec<String> = builder.collect_column(1);
+    /// assert_eq!(descriptions, ["An easy to use library for pretty print tables"]);
+    /// ```
+    pub fn set_column_max_width(&mut self, col: usize, width: usize, ellipsis: &str) -> &mut Self {
+        self.column_max_widths.retain(|(c, _, _)| *c != col);
+        self.column_max_widths
+            .push((col, width, ellipsis.to_owned()));
+        self
+    }
+
+    /// Sets a max number of records the builder keeps.
+    ///
+    /// Each [`Builder::push_record`] beyond the limit drops the oldest record,
+    /// so the builder holds a rolling window of the last `n` records,
+    /// which is handy for tailing logs.
+    /// Records above the limit are dropped right away.
+    ///
+    /// The header is a record as well, so it's dropped too.
+    /// Add it after the accumulation is done, e.g. with [`Builder::insert_record`],
+    /// which is not limited.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_max_records(2);
+    ///
+    /// for i in 0..5 {
+    ///     builder.push_record([format!("line {}", i)]);
+    /// }
+    ///
+    /// builder.insert_record(0, ["log"]);
+    ///
+    /// let data: Vec<Vec<String>> = builder.into();
+    /// assert_eq!(data, [["log"], ["line 3"], ["line 4"]]);
+    /// ```
+    pub fn set_max_records(&mut self, n: usize) -> &mut Self {
+        self.max_records = Some(n);
+        self.drop_oldest_records(n);
+        self
+    }
+
+    fn drop_oldest_records(&mut self, limit: usize) {
+        if self.data.len() > limit {
+            let count = self.data.len() - limit;
+            let _ = self.data.drain(..count);
+        }
+    }
+
+    /// Returns column max widths and a builder without them.
+    pub(crate) fn take_column_max_widths(mut self) -> (Self, Vec<(usize, usize, String)>) {
+        let widths = std::mem::take(&mut self.column_max_widths);
+        (self, widths)
+    }
+
+    fn get_empty_text(&self, col: usize) -> &CellInfo<String> {
+        get_empty_text(&self.empty_text, &self.column_empty_texts, col)
+    }
+
+    /// Moves settings of particular columns to new positions of the columns,
+    /// settings of removed columns (for which `f` returns `None`) are dropped.
+    ///
+    /// `count_columns` is a number of columns before the change.
+    fn remap_columns<F>(&mut self, count_columns: usize, f: F)
+    where
+        F: Fn(usize) -> Option<usize>,
+    {
+        self.column_empty_texts
+            .retain_mut(|(col, _)| remap_column(col, &f));
+        self.alignment_hints
+            .retain_mut(|(col, _)| remap_column(col, &f));
+        self.column_max_widths
+            .retain_mut(|(col, _, _)| remap_column(col, &f));
+
+        let groups = std::mem::take(&mut self.column_groups);
+        self.column_groups = groups
+            .into_iter()
+            .filter_map(|(range, name)| {
+                remap_column_group(range, count_columns, &f).map(|range| (range, name))
+            })
+            .collect();
+        self.column_groups.sort_by_key(|(range, _)| range.start);
+    }
+
+    fn create_empty_cells(&self, columns: Range<usize>) -> Vec<CellInfo<String>> {
+        columns
+            .map(|col| self.get_empty_text(col).clone())
+            .collect()
+    }
+
+    /// Returns a borrowed view of the records,
+    /// which implements the [`Records`], [`ExactRecords`] and [`PeekableRecords`] traits.
+    ///
+    /// It lets a grid render the records directly, without moving them into a [`Table`].
+    /// The header is the first record of the view.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use tabled::{
+    ///     builder::Builder,
+    ///     grid::{colors::NoColors, dimension::{Estimate, PeekableDimension}, PeekableGrid},
+    ///     Table,
+    /// };
+    ///
+    /// let builder = Builder::from_iter([["name", "age"], ["Alex", "20"]]);
+    ///
+    /// let table = Table::default();
+    /// let cfg = table.get_config().as_ref();
+    ///
+    /// let records = builder.to_records_ref();
+    /// let mut dims = PeekableDimension::default();
+    /// dims.estimate(records, cfg);
+    ///
+    /// let output = PeekableGrid::new(records, cfg, &dims, NoColors).to_string();
+    ///
+    /// assert_eq!(output, builder.build().to_string());
+    /// ```
+    ///
+    /// [`Records`]: crate::grid::records::Records
+    /// [`ExactRecords`]: crate::grid::records::ExactRecords
+    /// [`PeekableRecords`]: crate::grid::records::PeekableRecords
+    /// [`Table`]: crate::Table
+    pub fn to_records_ref(&self) -> BuilderRecords<'_> {
+        BuilderRecords::new(&self.data, self.count_columns)
+    }
+
+    /// Returns column alignment hints and a builder without them.
+    pub(crate) fn take_alignment_hints(mut self) -> (Self, Vec<(usize, Alignment)>) {
+        let hints = std::mem::take(&mut self.alignment_hints);
+        (self, hints)
+    }
+
+    /// Build creates a [`Table`] instance.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["i", "column1", "column2"]);
+    /// builder.push_record(["0", "value1", "value2"]);
+    /// ```
+    pub fn build(self) -> Table {
+        Table::from(self)
+    }
+
+    /// Renders a [`Table`] with the given columns widths.
+    ///
+    /// A width includes a cell padding.
+    /// Content is truncated or padded to fit the width.
+    /// Columns which don't have a width set are kept as they are.
+    ///
+    /// It's handy when a few tables must be aligned to each other.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "value"]);
+    /// builder.push_record(["a very long name", "1"]);
+    ///
+    /// let table = builder.to_fixed_width_string(&[8, 9]);
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+--------+---------+\n\
+    ///      | name   | value   |\n\
+    ///      +--------+---------+\n\
+    ///      | a very | 1       |\n\
+    ///      +--------+---------+"
+    /// )
+    /// ```
+    pub fn to_fixed_width_string(&self, widths: &[usize]) -> String {
+        let mut table = self.clone().build();
+
+        for (col, &width) in widths.iter().enumerate().take(self.count_columns) {
+            let pad = table.get_config().get_padding(Entity::Column(col));
+            let width = width.saturating_sub(pad.left.size + pad.right.size);
+
+            let _ = table.modify(Columns::single(col), Width::truncate(width));
+            let _ = table.modify(Columns::single(col), Width::increase(width));
+        }
+
+        table.to_string()
+    }
+
+    /// Builds a LaTeX `tabular` environment.
+    ///
+    /// The first record is considered to be a header, and it's followed by a `\hline` rule.
+    /// Special characters are escaped.
+    ///
+    /// ```rust
+    /// use tabled::builder::{Builder, LatexOptions};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "share"]);
+    /// builder.push_record(["R&D", "50%"]);
+    ///
+    /// let latex = builder.build_latex(LatexOptions::new().column_spec("lr"));
+    ///
+    /// assert_eq!(
+    ///     latex,
+    ///     concat!(
+    ///         r"\begin{tabular}{lr}", "\n",
+    ///         r"\hline", "\n",
+    ///         r"name & share \\", "\n",
+    ///         r"\hline", "\n",
+    ///         r"R\&D & 50\% \\", "\n",
+    ///         r"\hline", "\n",
+    ///         r"\end{tabular}",
+    ///     )
+    /// )
+    /// ```
+    pub fn build_latex(self, opts: LatexOptions) -> String {
+        build_latex(&self.data, self.count_columns, &opts)
+    }
+
+    /// Writes records as tab-separated values (TSV), starting from a header.
+    ///
+    /// As there's no quoting, a tab or a line break inside a cell is handled according to the [`TsvPolicy`].
+    /// The rest of a text is written as it is, so the output can be read back by [`Builder::from_tsv`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the writer fails,
+    /// or if a cell contains a tab or a line break while [`TsvPolicy::Reject`] is used.
+    ///
+    /// ```rust
+    /// use tabled::builder::{Builder, TsvPolicy};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "note"]);
+    /// builder.push_record(["Alex", "tab\there"]);
+    ///
+    /// let mut buf = Vec::new();
+    /// builder.into_tsv(&mut buf, TsvPolicy::ReplaceWithSpace).unwrap();
+    ///
+    /// assert_eq!(buf, b"name\tnote\nAlex\ttab here\n");
+    /// ```
+    pub fn into_tsv<W: std::io::Write>(self, writer: W, policy: TsvPolicy) -> std::io::Result<()> {
+        super::tsv::write_tsv(&self.data, self.count_columns, writer, policy)
+    }
+
+    /// Add an index to the [`Table`].
+    ///
+    /// Default index is a range 0-N where N is amount of records.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::builder(&["Hello", "World", "!"]).index().build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+---+-------+\n\
+    ///      |   | &str  |\n\
+    ///      +---+-------+\n\
+    ///      | 0 | Hello |\n\
+    ///      +---+-------+\n\
+    ///      | 1 | World |\n\
+    ///      +---+-------+\n\
+    ///      | 2 | !     |\n\
+    ///      +---+-------+"
+    /// )
+    /// ```
+    pub fn index(self) -> IndexBuilder {
+        IndexBuilder::from(self)
+    }
+
+    /// Adds a row to a [`Table`].
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record((0..3).map(|i| i.to_string()));
+    /// builder.push_record(["i", "surname", "lastname"]);
+    /// ```
+    pub fn push_record<R>(&mut self, record: R)
+    where
+        R: IntoIterator,
+        R::Item: Into<String>,
+    {
+        let list = create_row(record, self.count_columns, |col| self.get_empty_text(col));
+        let list_length = list.len();
+
+        if !is_size_eq(self.count_columns, list_length) {
+            let cells = self.create_empty_cells(self.count_columns..list_length);
+            resize_rows(&mut self.data, &cells)
+        }
+
+        self.count_columns = list_length;
+        self.data.push(list);
+
+        if let Some(limit) = self.max_records {
+            self.drop_oldest_records(limit);
+        }
+    }
+
+    /// Adds a row to a [`Table`], creating each cell by a function which gets a column index.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record_with(3, |col| format!("column {}", col));
+    /// builder.push_record_with(3, |col| (col * col).to_string());
+    ///
+    /// let table = builder.build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+----------+----------+----------+\n\
+    ///      | column 0 | column 1 | column 2 |\n\
+    ///      +----------+----------+----------+\n\
+    ///      | 0        | 1        | 4        |\n\
+    ///      +----------+----------+----------+"
+    /// )
+    /// ```
+    pub fn push_record_with<F>(&mut self, count_columns: usize, f: F)
+    where
+        F: FnMut(usize) -> String,
+    {
+        self.push_record((0..count_columns).map(f));
+    }
+
+    /// Adds a row to a [`Table`], where each cell is given as a list of lines.
+    ///
+    /// Lines of a cell are joined by `\n`.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "address"]);
+    /// builder.push_record_multiline([vec!["Tom"], vec!["12 Main St", "Springfield"]]);
+    ///
+    /// let table = builder.build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+-------------+\n\
+    ///      | name | address     |\n\
+    ///      +------+-------------+\n\
+    ///      | Tom  | 12 Main St  |\n\
+    ///      |      | Springfield |\n\
+    ///      +------+-------------+"
+    /// )
+    /// ```
+    pub fn push_record_multiline<R, C>(&mut self, record: R)
+    where
+        R: IntoIterator<Item = C>,
+        C: IntoIterator,
+        C::Item: Into<String>,
+    {
+        let record = record.into_iter().map(|lines| {
+            lines
+                .into_iter()
+                .map(Into::into)
+                .collect::<Vec<String>>()
+                .join("\n")
+        });
+
+        self.push_record(record);
+    }
+
+    /// Adds rows from a list of [`Tabled`] values.
+    ///
+    /// Headers are not added.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ColumnCountMismatch`] if the builder already has records
+    /// and their amount of columns is different from [`Tabled::LENGTH`].
+    /// Nothing is added in such case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "year"]);
+    /// builder.push_record(["Rust", "2015"]);
+    ///
+    /// let result = builder.append_from_tabled_iter([("Go", 2012), ("Zig", 2016)]);
+    /// assert!(result.is_ok());
+    ///
+    /// let result = builder.append_from_tabled_iter(["C"]);
+    /// assert!(result.is_err());
+    ///
+    /// assert_eq!(builder.count_records(), 4);
+    /// ```
+    pub fn append_from_tabled_iter<I, T>(&mut self, iter: I) -> Result<(), ColumnCountMismatch>
+    where
+        I: IntoIterator<Item = T>,
+        T: Tabled,
+    {
+        if !self.data.is_empty() && self.count_columns != T::LENGTH {
+            return Err(ColumnCountMismatch::new(self.count_columns, T::LENGTH));
+        }
+
+        for row in iter {
+            self.push_record(row.fields());
+        }
+
+        Ok(())
+    }
+
+    /// Adds a [`Tabled`] value as a record.
+    ///
+    /// If the builder has no records yet, a header is added first from [`Tabled::headers`].
+    /// So values can be added one by one as they come,
+    /// with the same result as building a [`Table`] from a whole list.
+    ///
+    /// Values of different types can be mixed
+    /// as long as they have the same [`Tabled::LENGTH`];
+    /// the header is taken from the first one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ColumnCountMismatch`] if the builder already has records
+    /// and their amount of columns is different from [`Tabled::LENGTH`].
+    /// Nothing is added in such case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_tabled(&("Rust", 2015)).unwrap();
+    /// builder.push_tabled(&("Go", 2012)).unwrap();
+    ///
+    /// assert!(builder.push_tabled(&"C").is_err());
+    ///
+    /// assert_eq!(
+    ///     builder.build().to_string(),
+    ///     "+------+------+\n\
+    ///      | &str | i32  |\n\
+    ///      +------+------+\n\
+    ///      | Rust | 2015 |\n\
+    ///      +------+------+\n\
+    ///      | Go   | 2012 |\n\
+    ///      +------+------+"
+    /// );
+    /// ```
+    pub fn push_tabled<T>(&mut self, item: &T) -> Result<(), ColumnCountMismatch>
+    where
+        T: Tabled,
+    {
+        if self.data.is_empty() {
+            self.push_record(T::headers());
+        } else if self.count_columns != T::LENGTH {
+            return Err(ColumnCountMismatch::new(self.count_columns, T::LENGTH));
+        }
+
+        self.push_record(item.fields());
+
+        Ok(())
+    }
+
+    /// Creates a [`Builder`] from a list of [`Tabled`] values.
+    ///
+    /// The first record is a header from [`Tabled::headers`].
+    /// It's the same as [`Table::new`] but the result can be changed further.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::from_tabled_iter([("Rust", 2015), ("Go", 2012)]);
+    /// builder.remove_column(1);
+    ///
+    /// assert_eq!(
+    ///     builder.build().to_string(),
+    ///     "+------+\n\
+    ///      | &str |\n\
+    ///      +------+\n\
+    ///      | Rust |\n\
+    ///      +------+\n\
+    ///      | Go   |\n\
+    ///      +------+"
+    /// );
+    /// ```
+    pub fn from_tabled_iter<I, T>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Tabled,
+    {
+        let iter = iter.into_iter();
+
+        let mut builder = Self::with_capacity(iter.size_hint().0 + 1, T::LENGTH);
+        builder.push_record(T::headers());
+
+        for row in iter {
+            builder.push_record(row.fields());
+        }
+
+        builder
+    }
+
+    /// Adds a row with values aggregated from columns.
+    ///
+    /// See [`Summary`].
+    pub fn push_summary(&mut self, summary: &Summary) {
+        let count_rows = self.data.len();
+        let record = summary.create_record(count_rows, self.count_columns, |(row, col)| {
+            self.data[row][col].as_ref()
+        });
+
+        self.push_record(record);
+    }
+
+    /// Insert a row into a specific position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > count_rows`.
+    pub fn insert_record<R>(&mut self, index: usize, record: R)
+    where
+        R: IntoIterator,
+        R::Item: Into<String>,
+    {
+        let list = create_row(record, self.count_columns, |col| self.get_empty_text(col));
+        let list_length = list.len();
+
+        if !is_size_eq(self.count_columns, list_length) {
+            let cells = self.create_empty_cells(self.count_columns..list_length);
+            resize_rows(&mut self.data, &cells)
+        }
+
+        self.count_columns = list_length;
+        self.data.insert(index, list);
+    }
+
+    /// Replaces a row at a specific position.
+    ///
+    /// A shorter record is filled with empty cells,
+    /// while a longer one adds empty cells to all other records.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["lang", "year"]);
+    /// builder.push_record(["Rust", "2010"]);
+    ///
+    /// builder.update_record(1, ["Rust", "2015"]);
+    ///
+    /// let data: Vec<Vec<String>> = builder.into();
+    /// assert_eq!(data, [["lang", "year"], ["Rust", "2015"]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= count_records`.
+    pub fn update_record<R>(&mut self, index: usize, record: R) -> &mut Self
+    where
+        R: IntoIterator,
+        R::Item: Into<String>,
+    {
+        let list = create_row(record, self.count_columns, |col| self.get_empty_text(col));
+        let list_length = list.len();
+
+        if !is_size_eq(self.count_columns, list_length) {
+            let cells = self.create_empty_cells(self.count_columns..list_length);
+            resize_rows(&mut self.data, &cells)
+        }
+
+        self.count_columns = list_length;
+        self.data[index] = list;
+
+        self
+    }
+
+    /// Clean removes empty columns and rows.
+    ///
+    /// Cells which match a null value are considered to be empty.
+    /// See [`Builder::set_null_values`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let mut builder = Table::builder(&["Hello", "World", ""]);
+    /// builder.clean();
+    ///
+    /// let table = builder.build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+\n\
+    ///      | &str  |\n\
+    ///      +-------+\n\
+    ///      | Hello |\n\
+    ///      +-------+\n\
+    ///      | World |\n\
+    ///      +-------+"
+    /// )
+    /// ```
+    pub fn clean(&mut self) {
+        let removed = self.collapse_empty_columns();
+        let count_columns = self.count_columns;
+
+        let nulls = &self.null_values;
+        self.count_columns -= remove_empty_columns(&mut self.data, self.count_columns, nulls);
+        remove_empty_rows(&mut self.data, self.count_columns, nulls);
+
+        self.remap_columns(count_columns, |col| match removed.binary_search(&col) {
+            Ok(_) => None,
+            Err(shift) => Some(col - shift),
+        });
+    }
+
+    /// Returns indexes of columns which have only empty cells, including a header.
+    ///
+    /// Unlike [`Builder::clean`] the data is not changed,
+    /// so the columns can be hidden afterwards (for example by a style) while kept in the builder.
+    ///
+    /// Cells which match a null value are considered to be empty.
+    /// See [`Builder::set_null_values`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["", "name", ""]);
+    /// builder.push_record(["", "Alex", ""]);
+    ///
+    /// assert_eq!(builder.collapse_empty_columns(), [0, 2]);
+    /// assert_eq!(builder.count_columns(), 3);
+    /// ```
+    pub fn collapse_empty_columns(&self) -> Vec<usize> {
+        (0..self.count_columns)
+            .filter(|&col| is_empty_column(&self.data, col, &self.null_values))
+            .collect()
+    }
+
+    /// Trims leading and trailing whitespace of each cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["  name  ", "\tvalue"]);
+    /// builder.push_record([" hello ", "world\n"]);
+    /// builder.trim_cells();
+    ///
+    /// let table = builder.build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+-------+\n\
+    ///      | name  | value |\n\
+    ///      +-------+-------+\n\
+    ///      | hello | world |\n\
+    ///      +-------+-------+"
+    /// )
+    /// ```
+    pub fn trim_cells(&mut self) -> &mut Self {
+        for row in &mut self.data {
+            for cell in row {
+                let text = cell.as_ref();
+                let trimmed = text.trim();
+                if trimmed.len() != text.len() {
+                    *cell = CellInfo::new(trimmed.to_owned());
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Sets a content of a cell, indexing the data directly.
+    ///
+    /// It's meant for bulk updates of a builder which is known to be consistent,
+    /// so it does no checks and doesn't extend a record which is too short.
+    ///
+    /// Row `0` is a header.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row >= count_records` or `col` is out of the record length,
+    /// as the data is accessed through [`Vec`] indexing.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "price"]);
+    /// builder.push_record(["apple", "1.00"]);
+    /// builder.push_record(["pear", "2.5"]);
+    ///
+    /// for row in 1..builder.count_records() {
+    ///     builder.set_cell_unchecked(row, 1, "$");
+    /// }
+    ///
+    /// let table = builder.build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+-------+\n\
+    ///      | name  | price |\n\
+    ///      +-------+-------+\n\
+    ///      | apple | $     |\n\
+    ///      +-------+-------+\n\
+    ///      | pear  | $     |\n\
+    ///      +-------+-------+"
+    /// )
+    /// ```
+    #[inline]
+    pub fn set_cell_unchecked<T>(&mut self, row: usize, col: usize, value: T)
+    where
+        T: Into<String>,
+    {
+        self.data[row][col] = CellInfo::new(value.into());
+    }
+
+    /// Removes a row with a specific position.
+    ///
+    /// Index expected to be in range.
+    /// `Builder::count_records() < x >= 0`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_index > count_rows`.
+    pub fn remove_record(&mut self, index: usize) {
+        let _ = self.data.remove(index);
+    }
+
+    /// Removes duplicate records, keeping the first occurrence of each one.
+    ///
+    /// Records are compared cell by cell, an order of the rest of them is kept.
+    /// The header (the first record) is never removed.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["city", "country"]);
+    /// builder.push_record(["Paris", "France"]);
+    /// builder.push_record(["Berlin", "Germany"]);
+    /// builder.push_record(["Paris", "France"]);
+    ///
+    /// builder.distinct_records();
+    ///
+    /// let data: Vec<Vec<String>> = builder.into();
+    /// assert_eq!(data, [["city", "country"], ["Paris", "France"], ["Berlin", "Germany"]]);
+    /// ```
+    pub fn distinct_records(&mut self) -> &mut Self {
+        let mut seen = HashSet::with_capacity(self.data.len());
+        let keep = self
+            .data
+            .iter()
+            .enumerate()
+            .map(|(row, record)| {
+                let record = record.iter().map(AsRef::as_ref).collect::<Vec<&str>>();
+                row == 0 || seen.insert(record)
+            })
+            .collect::<Vec<_>>();
+
+        let mut keep = keep.into_iter();
+        self.data.retain(|_| keep.next().unwrap_or(true));
+
+        self
+    }
+
+    /// Sorts records by a text of a given column, the header (the first record) is kept in place.
+    ///
+    /// The sort is stable, so records with equal cells are kept in the given order.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "n"]);
+    /// builder.push_record(["b", "1"]);
+    /// builder.push_record(["a", "2"]);
+    /// builder.push_record(["b", "3"]);
+    ///
+    /// builder.sort_records_by_column(0);
+    ///
+    /// let data: Vec<Vec<String>> = builder.into();
+    /// assert_eq!(data, [["name", "n"], ["a", "2"], ["b", "1"], ["b", "3"]]);
+    /// ```
+    pub fn sort_records_by_column(&mut self, col: usize) -> &mut Self {
+        if let Some(records) = self.data.get_mut(1..) {
+            records.sort_by(|a, b| {
+                let a = a.get(col).map(AsRef::as_ref);
+                let b = b.get(col).map(AsRef::as_ref);
+                a.cmp(&b)
+            });
+        }
+
+        self
+    }
+
+    /// Sets a header (the first record) if the builder has no records yet.
+    ///
+    /// Returns `true` if the header was set,
+    /// an existing header is never overwritten.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// assert!(builder.set_header_if_absent(["name", "year"]));
+    /// assert!(!builder.set_header_if_absent(["lang", "released"]));
+    ///
+    /// builder.push_record(["Rust", "2015"]);
+    ///
+    /// let data: Vec<Vec<String>> = builder.into();
+    /// assert_eq!(data, [["name", "year"], ["Rust", "2015"]]);
+    /// ```
+    pub fn set_header_if_absent<R>(&mut self, header: R) -> bool
+    where
+        R: IntoIterator,
+        R::Item: Into<String>,
+    {
+        if !self.data.is_empty() {
+            return false;
+        }
+
+        self.push_record(header);
+
+        true
+    }
+
+    /// Replaces a header (the first record), or sets it if there's no records yet.
+    ///
+    /// The header is reconciled the same way as [`Builder::update_record`] does,
+    /// a shorter one is filled with empty cells,
+    /// while a longer one adds empty cells to all other records.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "year"]);
+    /// builder.push_record(["Rust", "2015"]);
+    ///
+    /// builder.update_header(["lang", "released", "by"]);
+    ///
+    /// let data: Vec<Vec<String>> = builder.into();
+    /// assert_eq!(data, [["lang", "released", "by"], ["Rust", "2015", ""]]);
+    /// ```
+    pub fn update_header<R>(&mut self, header: R) -> &mut Self
+    where
+        R: IntoIterator,
+        R::Item: Into<String>,
+    {
+        if self.data.is_empty() {
+            self.push_record(header);
+            return self;
+        }
+
+        self.update_record(0, header)
+    }
+
+    /// Appends a unit to a header (the first record) of a column,
+    /// like `"latency"` becomes `"latency (ms)"`.
+    ///
+    /// Nothing is done if there's no header.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["host", "latency"]);
+    /// builder.push_record(["localhost", "12"]);
+    ///
+    /// builder.append_unit_to_header(1, "ms");
+    ///
+    /// let data: Vec<Vec<String>> = builder.into();
+    /// assert_eq!(data, [["host", "latency (ms)"], ["localhost", "12"]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col >= count_columns` while there's a header.
+    pub fn append_unit_to_header(&mut self, col: usize, unit: &str) -> &mut Self {
+        let header = match self.data.first_mut() {
+            Some(header) => header,
+            None => return self,
+        };
+
+        let text = format!("{} ({})", header[col].as_ref(), unit);
+        header[col] = CellInfo::new(text);
+
+        self
+    }
+
+    /// Removes a header (the first record) and returns it.
+    ///
+    /// If it was the only record, the builder is left with no columns.
+    /// Returns [`None`] if there's no records.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "year"]);
+    /// builder.push_record(["Rust", "2015"]);
+    ///
+    /// let header = builder.take_header().unwrap();
+    /// assert_eq!(header.len(), 2);
+    /// assert_eq!(header[0].as_ref(), "name");
+    ///
+    /// let mut other = Builder::default();
+    /// other.push_record(header.into_iter().map(|cell| cell.into_inner()));
+    ///
+    /// assert_eq!(builder.count_records(), 1);
+    /// assert_eq!(other.count_records(), 1);
+    /// ```
+    pub fn take_header(&mut self) -> Option<Vec<CellInfo<String>>> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let header = self.data.remove(0);
+        if self.data.is_empty() {
+            self.count_columns = 0;
+        }
+
+        Some(header)
+    }
+
+    /// Removes the first record after a header and returns it.
+    ///
+    /// The header (the first record) is kept,
+    /// so it can be used to consume records in the order they were added.
+    /// Returns [`None`] if there's no records besides the header.
+    ///
+    /// It shifts the rest records, so it takes `O(n)` time.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["event"]);
+    /// builder.push_record(["start"]);
+    /// builder.push_record(["stop"]);
+    ///
+    /// let record = builder.pop_front_record().unwrap();
+    /// assert_eq!(record[0].as_ref(), "start");
+    /// assert_eq!(builder.count_records(), 2);
+    /// ```
+    pub fn pop_front_record(&mut self) -> Option<Vec<CellInfo<String>>> {
+        if self.data.len() < 2 {
+            return None;
+        }
+
+        Some(self.data.remove(1))
+    }
+
+    /// Removes a column with a specific position.
+    ///
+    /// Index expected to be in range.
+    /// `Builder::count_columns() < x >= 0`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > count_columns`.
+    pub fn remove_column(&mut self, index: usize) {
+        for row in &mut self.data {
+            let _ = row.remove(index);
+        }
+
+        self.count_columns -= 1;
+
+        self.remap_columns(self.count_columns + 1, |col| match col.cmp(&index) {
+            Ordering::Less => Some(col),
+            Ordering::Equal => None,
+            Ordering::Greater => Some(col - 1),
+        });
+    }
+
+    /// Moves a row to a new position, shifting the rows in between.
+    ///
+    /// Returns `false` if any of the indexes is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["a"]);
+    /// builder.push_record(["b"]);
+    /// builder.push_record(["c"]);
+    ///
+    /// assert!(builder.move_record(2, 0));
+    /// assert!(!builder.move_record(0, 3));
+    ///
+    /// let data: Vec<Vec<String>> = builder.into();
+    /// assert_eq!(data, vec![vec!["c"], vec!["a"], vec!["b"]]);
+    /// ```
+    pub fn move_record(&mut self, from: usize, to: usize) -> bool {
+        let count_rows = self.data.len();
+        if from >= count_rows || to >= count_rows {
+            return false;
+        }
+
+        move_element(&mut self.data, from, to);
+
+        true
+    }
+
+    /// Rotates records cyclically by `n` positions.
+    ///
+    /// A positive `n` rotates records up (to the left),
+    /// a negative one rotates them down (to the right).
+    /// The header (the first record) is kept in place.
+    /// A `n` bigger than an amount of records wraps around.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["id"]);
+    /// builder.push_record(["1"]);
+    /// builder.push_record(["2"]);
+    /// builder.push_record(["3"]);
+    ///
+    /// builder.rotate_records(1);
+    ///
+    /// let data: Vec<Vec<String>> = builder.clone().into();
+    /// assert_eq!(data, vec![vec!["id"], vec!["2"], vec!["3"], vec!["1"]]);
+    ///
+    /// builder.rotate_records(-2);
+    ///
+    /// let data: Vec<Vec<String>> = builder.into();
+    /// assert_eq!(data, vec![vec!["id"], vec!["3"], vec!["1"], vec!["2"]]);
+    /// ```
+    pub fn rotate_records(&mut self, n: isize) -> &mut Self {
+        if self.data.len() < 2 {
+            return self;
+        }
+
+        let records = &mut self.data[1..];
+        let shift = n.unsigned_abs() % records.len();
+        if n > 0 {
+            records.rotate_left(shift);
+        } else {
+            records.rotate_right(shift);
+        }
+
+        self
+    }
+
+    /// Moves a column to a new position, shifting the columns in between.
+    ///
+    /// Returns `false` if any of the indexes is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["a", "b", "c"]);
+    ///
+    /// assert!(builder.move_column(0, 2));
+    /// assert!(!builder.move_column(3, 0));
+    ///
+    /// let data: Vec<Vec<String>> = builder.into();
+    /// assert_eq!(data, vec![vec!["b", "c", "a"]]);
+    /// ```
+    pub fn move_column(&mut self, from: usize, to: usize) -> bool {
+        if from >= self.count_columns || to >= self.count_columns {
+            return false;
+        }
+
+        for row in &mut self.data {
+            move_element(row, from, to);
+        }
+
+        self.remap_columns(self.count_columns, |col| {
+            let col = if col == from {
+                to
+            } else if from < to && col > from && col <= to {
+                col - 1
+            } else if from > to && col >= to && col < from {
+                col + 1
+            } else {
+                col
+            };
+
+            Some(col)
+        });
+
+        true
+    }
+
+    /// Reverses an order of columns, including the header,
+    /// so a table can be mirrored for right-to-left languages.
+    ///
+    /// Settings of particular columns, like [`Builder::set_column_alignment_hint`],
+    /// are moved together with their columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["a", "b", "c"]);
+    /// builder.push_record(["1", "2", "3"]);
+    ///
+    /// builder.reverse_columns();
+    ///
+    /// let data: Vec<Vec<String>> = builder.into();
+    /// assert_eq!(data, [["c", "b", "a"], ["3", "2", "1"]]);
+    /// ```
+    pub fn reverse_columns(&mut self) -> &mut Self {
+        let count_columns = self.count_columns;
+        let mirror = |col: usize| count_columns.saturating_sub(col + 1);
+
+        for row in &mut self.data {
+            row.reverse();
+        }
+
+        self.remap_columns(count_columns, |col| Some(mirror(col)));
+
+        self
+    }
+
+    /// Push a column.
+    pub fn push_column<I>(&mut self, column: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let mut iter = column.into_iter();
+
+        let empty_text = self.get_empty_text(self.count_columns).clone();
+        for row in self.data.iter_mut() {
+            let text = iter
+                .next()
+                .map(Into::into)
+                .map(CellInfo::new)
+                .unwrap_or_else(|| empty_text.clone());
+
+            row.push(text);
+        }
+
+        for text in iter {
+            let text = CellInfo::new(text.into());
+
+            let mut row = self.create_empty_cells(0..self.count_columns);
+            row.push(text);
+            self.data.push(row);
+        }
+
+        self.count_columns += 1;
+    }
+
+    /// Insert a column with a specific position.
+    ///
+    /// In case a column is bigger then the total amount of rows it will be truncated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > count_columns`.
+    pub fn insert_column<I>(&mut self, index: usize, column: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let mut iter = column.into_iter();
+
+        self.remap_columns(self.count_columns, |col| {
+            Some(if col >= index { col + 1 } else { col })
+        });
+
+        let empty_text = self.get_empty_text(index).clone();
+        for row in self.data.iter_mut() {
+            let text = iter
+                .next()
+                .map(Into::into)
+                .map(CellInfo::new)
+                .unwrap_or_else(|| empty_text.clone());
+
+            row.insert(index, text);
+        }
+
+        for text in iter {
+            let text = CellInfo::new(text.into());
+
+            let mut row = Vec::with_capacity(self.count_columns + 1);
+            row.extend(self.create_empty_cells(0..index));
+            row.push(text);
+            row.extend(self.create_empty_cells(index + 1..self.count_columns + 1));
+        }
+
+        self.count_columns += 1;
+    }
+
+    /// Inserts a first column with sequential row numbers.
+    ///
+    /// The first record is considered to be a header,
+    /// so it gets a given `header` and the rest get numbers starting from `start`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name"]);
+    /// builder.push_record(["Alex"]);
+    /// builder.push_record(["Bob"]);
+    /// builder.add_row_numbers("No.", 1);
+    ///
+    /// let table = builder.build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-----+------+\n\
+    ///      | No. | name |\n\
+    ///      +-----+------+\n\
+    ///      | 1   | Alex |\n\
+    ///      +-----+------+\n\
+    ///      | 2   | Bob  |\n\
+    ///      +-----+------+"
+    /// )
+    /// ```
+    pub fn add_row_numbers<S>(&mut self, header: S, start: usize) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        if self.data.is_empty() {
+            self.push_record([header]);
+            return self;
+        }
+
+        let count_records = self.data.len() - 1;
+        let numbers = (start..start + count_records).map(|i| i.to_string());
+        let column = std::iter::once(header.into()).chain(numbers);
+
+        self.insert_column(0, column);
+
+        self
+    }
+
+    /// Checks whether any cell of a given record contains a `needle`.
+    ///
+    /// It returns `false` in case the record does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "city"]);
+    /// builder.push_record(["Alex", "Berlin"]);
+    ///
+    /// assert!(builder.record_contains(1, "erl"));
+    /// assert!(!builder.record_contains(1, "Paris"));
+    /// assert!(!builder.record_contains(2, "Alex"));
+    /// ```
+    pub fn record_contains(&self, row: usize, needle: &str) -> bool {
+        match self.data.get(row) {
+            Some(record) => record.iter().any(|cell| cell.as_ref().contains(needle)),
+            None => false,
+        }
+    }
+
+    /// Returns indexes of all records which contain a `needle` in any of their cells.
+    ///
+    /// See [`Builder::record_contains`].
+    pub fn find_records_containing(&self, needle: &str) -> Vec<usize> {
+        (0..self.data.len())
+            .filter(|&row| self.record_contains(row, needle))
+            .collect()
+    }
+
+    /// Returns positions (row, column) of all cells which satisfy a predicate.
+    ///
+    /// The header (the first record) is not checked,
+    /// yet the positions are counted including it,
+    /// so they can be used as is to modify a built [`Table`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["a", "b"]);
+    /// builder.push_record(["1", "10"]);
+    /// builder.push_record(["20", "2"]);
+    ///
+    /// let cells = builder.find_cells(|text| text.parse::<u32>().map_or(false, |n| n > 5));
+    ///
+    /// assert_eq!(cells, [(1, 1), (2, 0)]);
+    /// ```
+    pub fn find_cells<F>(&self, pred: F) -> Vec<(usize, usize)>
+    where
+        F: Fn(&str) -> bool,
+    {
+        let mut cells = Vec::new();
+        for (row, record) in self.data.iter().enumerate().skip(1) {
+            for (col, cell) in record.iter().enumerate() {
+                if pred(cell.as_ref()) {
+                    cells.push((row, col));
+                }
+            }
+        }
+
+        cells
+    }
+
+    /// Returns an index of the first column which header satisfies a predicate.
+    ///
+    /// The header is the first record.
+    /// It returns `None` if there's no header or no column matches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["Id", "Created Date", "Updated Date"]);
+    /// builder.push_record(["1", "2023-01-01", "2023-02-01"]);
+    ///
+    /// let col = builder.find_column(|name| name.to_lowercase().contains("date"));
+    ///
+    /// assert_eq!(col, Some(1));
+    /// ```
+    pub fn find_column<F>(&self, pred: F) -> Option<usize>
+    where
+        F: Fn(&str) -> bool,
+    {
+        self.data
+            .first()?
+            .iter()
+            .position(|cell| pred(cell.as_ref()))
+    }
+
+    /// Returns cells which contain characters which might break a table layout.
+    ///
+    /// Each hazard of a cell is reported once, in order of appearance,
+    /// as a row index, a column index and a [`WidthHazard`] kind.
+    /// The header (the first record) is scanned as well.
+    ///
+    /// A `\n` character is not considered to be a hazard.
+    /// The same goes for an escape character when `ansi` feature is on,
+    /// as it starts an ANSI sequence.
+    ///
+    /// It may be used to sanitize an untrusted text before rendering.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::{Builder, WidthHazard};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "comment"]);
+    /// builder.push_record(["Jose\u{301}", "ok\u{7}"]);
+    ///
+    /// assert_eq!(
+    ///     builder.scan_width_hazards(),
+    ///     [(1, 0, WidthHazard::ZeroWidth), (1, 1, WidthHazard::ControlChar)],
+    /// );
+    /// ```
+    pub fn scan_width_hazards(&self) -> Vec<(usize, usize, WidthHazard)> {
+        let mut hazards = Vec::new();
+        for (row, record) in self.data.iter().enumerate() {
+            for (col, cell) in record.iter().enumerate() {
+                let mut found = Vec::new();
+                let mut char_width = CharWidth::new();
+                for c in cell.as_ref().chars() {
+                    let width = char_width.measure(c);
+                    if let Some(hazard) = WidthHazard::of(c, width) {
+                        if !found.contains(&hazard) {
+                            found.push(hazard);
+                        }
+                    }
+                }
+
+                hazards.extend(found.into_iter().map(|hazard| (row, col, hazard)));
+            }
+        }
+
+        hazards
     }
 
-    /// Adds a row to a [`Table`].
+    /// Collects cells of a column into any collection.
+    ///
+    /// The header (the first record) is not included.
+    /// If a column is out of range an empty collection is returned.
+    ///
+    /// # Example
     ///
     /// ```
+    /// use std::collections::HashSet;
     /// use tabled::builder::Builder;
     ///
     /// let mut builder = Builder::default();
-    /// builder.push_record((0..3).map(|i| i.to_string()));
-    /// builder.push_record(["i", "surname", "lastname"]);
+    /// builder.push_record(["name", "city"]);
+    /// builder.push_record(["Alex", "Berlin"]);
+    /// builder.push_record(["Maria", "Berlin"]);
+    ///
+    /// let names: Vec<String> = builder.collect_column(0);
+    /// let cities: HashSet<String> = builder.collect_column(1);
+    ///
+    /// assert_eq!(names, ["Alex", "Maria"]);
+    /// assert_eq!(cities.len(), 1);
     /// ```
-    pub fn push_record<R>(&mut self, record: R)
+    pub fn collect_column<B>(&self, col: usize) -> B
     where
-        R: IntoIterator,
-        R::Item: Into<String>,
+        B: FromIterator<String>,
     {
-        let list = create_row(record, self.count_columns, &self.empty_text);
-        let list_length = list.len();
+        self.data
+            .iter()
+            .skip(1)
+            .filter_map(|record| record.get(col))
+            .map(|cell| cell.as_ref().to_owned())
+            .collect()
+    }
 
-        if !is_size_eq(self.count_columns, list_length) {
-            let size = list_length - self.count_columns;
-            resize_rows(&mut self.data, size, &self.empty_text)
+    /// Checks that each record, including the header, has exactly [`Builder::count_columns`] cells.
+    ///
+    /// Unlike [`Builder::build`] which pads short records,
+    /// it's meant for code which considers ragged data a bug.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RaggedRecord`] describing the first record of a different length.
+    ///
+    /// ```
+    /// use tabled::{builder::Builder, grid::records::vec_records::CellInfo};
+    ///
+    /// let builder = Builder::from_vec(vec![
+    ///     vec![CellInfo::new(String::from("name")), CellInfo::new(String::from("age"))],
+    ///     vec![CellInfo::new(String::from("Alex"))],
+    /// ]);
+    ///
+    /// let err = builder.ensure_rectangular().unwrap_err();
+    /// assert_eq!((err.index, err.found, err.expected), (1, 1, 2));
+    /// ```
+    pub fn ensure_rectangular(&self) -> Result<(), RaggedRecord> {
+        let ragged = self
+            .data
+            .iter()
+            .position(|record| record.len() != self.count_columns);
+
+        match ragged {
+            Some(index) => Err(RaggedRecord {
+                index,
+                found: self.data[index].len(),
+                expected: self.count_columns,
+            }),
+            None => Ok(()),
         }
-
-        self.count_columns = list_length;
-        self.data.push(list);
     }
 
-    /// Insert a row into a specific position.
+    /// Asserts that each record, including the header, has exactly [`Builder::count_columns`] cells.
+    ///
+    /// See [`Builder::ensure_rectangular`].
     ///
     /// # Panics
     ///
-    /// Panics if `index > count_rows`.
-    pub fn insert_record<R>(&mut self, index: usize, record: R)
-    where
-        R: IntoIterator,
-        R::Item: Into<String>,
-    {
-        let list = create_row(record, self.count_columns, &self.empty_text);
-        let list_length = list.len();
-
-        if !is_size_eq(self.count_columns, list_length) {
-            let size = list_length - self.count_columns;
-            resize_rows(&mut self.data, size, &self.empty_text)
+    /// Panics with an index and a length of the first record of a different length.
+    #[track_caller]
+    pub fn assert_rectangular(&self) {
+        if let Err(err) = self.ensure_rectangular() {
+            panic!("{}", err);
         }
+    }
 
-        self.count_columns = list_length;
-        self.data.insert(index, list);
+    /// Checks whether an amount of records is equal to an amount of columns.
+    pub fn is_square(&self) -> bool {
+        self.data.len() == self.count_columns
     }
 
-    /// Clean removes empty columns and rows.
+    /// Adds empty columns or records so that the amount of records
+    /// is equal to the amount of columns.
+    ///
+    /// New cells are filled with a text set by [`Builder::set_empty`].
     ///
     /// # Example
     ///
     /// ```
-    /// use tabled::Table;
+    /// use tabled::builder::Builder;
     ///
-    /// let mut builder = Table::builder(&["Hello", "World", ""]);
-    /// builder.clean();
+    /// let mut builder = Builder::default();
+    /// builder.set_empty("0");
+    /// builder.push_record(["1", "0", "1"]);
+    /// builder.push_record(["0", "1", "1"]);
+    /// builder.pad_to_square();
     ///
-    /// let table = builder.build();
+    /// assert!(builder.is_square());
+    ///
+    /// let table = builder.build().to_string();
     ///
     /// assert_eq!(
-    ///     table.to_string(),
-    ///     "+-------+\n\
-    ///      | &str  |\n\
-    ///      +-------+\n\
-    ///      | Hello |\n\
-    ///      +-------+\n\
-    ///      | World |\n\
-    ///      +-------+"
+    ///     table,
+    ///     "+---+---+---+\n\
+    ///      | 1 | 0 | 1 |\n\
+    ///      +---+---+---+\n\
+    ///      | 0 | 1 | 1 |\n\
+    ///      +---+---+---+\n\
+    ///      | 0 | 0 | 0 |\n\
+    ///      +---+---+---+"
     /// )
     /// ```
-    pub fn clean(&mut self) {
-        self.count_columns -= remove_empty_columns(&mut self.data, self.count_columns);
-        remove_empty_rows(&mut self.data, self.count_columns);
+    pub fn pad_to_square(&mut self) -> &mut Self {
+        let size = std::cmp::max(self.data.len(), self.count_columns);
+
+        if self.count_columns < size {
+            let cells = self.create_empty_cells(self.count_columns..size);
+            resize_rows(&mut self.data, &cells);
+            self.count_columns = size;
+        }
+
+        while self.data.len() < size {
+            let row = self.create_empty_cells(0..size);
+            self.data.push(row);
+        }
+
+        self
     }
 
-    /// Removes a row with a specific position.
+    /// Remove all records.
+    pub fn clear(&mut self) {
+        self.data.clear();
+        self.count_columns = 0;
+    }
+
+    /// Compares builders cell by cell and returns a list of different cells.
     ///
-    /// Index expected to be in range.
-    /// `Builder::count_records() < x >= 0`
+    /// In case builders have different shapes,
+    /// cells which are present only in one of them are reported with [`None`] on the other side.
     ///
-    /// # Panics
+    /// # Example
     ///
-    /// Panics if `row_index > count_rows`.
-    pub fn remove_record(&mut self, index: usize) {
-        let _ = self.data.remove(index);
-    }
-
-    /// Removes a column with a specific position.
+    /// ```
+    /// use tabled::builder::{Builder, CellDiff};
     ///
-    /// Index expected to be in range.
-    /// `Builder::count_columns() < x >= 0`
+    /// let mut left = Builder::default();
+    /// left.push_record(["name", "age"]);
+    /// left.push_record(["Alex", "20"]);
     ///
-    /// # Panics
+    /// let mut right = left.clone();
+    /// right.remove_record(1);
+    /// right.push_record(["Alex", "21"]);
     ///
-    /// Panics if `index > count_columns`.
-    pub fn remove_column(&mut self, index: usize) {
-        for row in &mut self.data {
-            let _ = row.remove(index);
+    /// assert_eq!(
+    ///     left.diff(&right),
+    ///     vec![CellDiff {
+    ///         row: 1,
+    ///         col: 1,
+    ///         left: Some(String::from("20")),
+    ///         right: Some(String::from("21")),
+    ///     }],
+    /// );
+    /// ```
+    pub fn diff(&self, other: &Builder) -> Vec<CellDiff> {
+        let count_rows = std::cmp::max(self.data.len(), other.data.len());
+        let count_columns = std::cmp::max(self.count_columns, other.count_columns);
+
+        let get_cell = |b: &Builder, row: usize, col: usize| {
+            b.data
+                .get(row)
+                .and_then(|r| r.get(col))
+                .map(|cell| cell.as_ref().to_owned())
+        };
+
+        let mut diffs = Vec::new();
+        for row in 0..count_rows {
+            for col in 0..count_columns {
+                let left = get_cell(self, row, col);
+                let right = get_cell(other, row, col);
+                if left != right {
+                    diffs.push(CellDiff {
+                        row,
+                        col,
+                        left,
+                        right,
+                    });
+                }
+            }
         }
 
-        self.count_columns -= 1;
+        diffs
     }
 
-    /// Push a column.
-    pub fn push_column<I>(&mut self, column: I)
-    where
-        I: IntoIterator,
-        I::Item: Into<String>,
-    {
-        let mut iter = column.into_iter();
+    /// Appends columns of `other` to the right of the columns of `self`.
+    ///
+    /// Records are matched by an index, headers (the first records) are concatenated as well.
+    /// If builders have a different number of records,
+    /// the shorter one is padded with a text set by [`Builder::set_empty`].
+    ///
+    /// Column settings of `other` (empty texts, alignment hints, max widths and groups)
+    /// are moved to the corresponding new columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut before = Builder::from_iter([["name", "v1"], ["alex", "1"], ["bob", "2"]]);
+    /// let after = Builder::from_iter([["v2"], ["3"]]);
+    ///
+    /// before.hstack(after);
+    ///
+    /// assert_eq!(
+    ///     before.build().to_string(),
+    ///     "+------+----+----+\n\
+    ///      | name | v1 | v2 |\n\
+    ///      +------+----+----+\n\
+    ///      | alex | 1  | 3  |\n\
+    ///      +------+----+----+\n\
+    ///      | bob  | 2  |    |\n\
+    ///      +------+----+----+"
+    /// );
+    /// ```
+    pub fn hstack(&mut self, other: Builder) -> &mut Self {
+        let offset = self.count_columns;
+        let count_columns = offset + other.count_columns;
 
-        for row in self.data.iter_mut() {
-            let text = iter
-                .next()
-                .map(Into::into)
-                .map(CellInfo::new)
-                .unwrap_or(self.empty_text.clone());
+        for (col, text) in other.column_empty_texts {
+            self.column_empty_texts.push((col + offset, text));
+        }
 
-            row.push(text);
+        for (col, alignment) in other.alignment_hints {
+            self.alignment_hints.push((col + offset, alignment));
         }
 
-        for text in iter {
-            let text = CellInfo::new(text.into());
+        for (col, width, ellipsis) in other.column_max_widths {
+            self.column_max_widths.push((col + offset, width, ellipsis));
+        }
 
-            let mut row = Vec::with_capacity(self.count_columns + 1);
-            for _ in 0..self.count_columns {
-                row.push(self.empty_text.clone());
+        for (range, name) in other.column_groups {
+            let range = range.start + offset..range.end + offset;
+            self.column_groups.push((range, name));
+        }
+
+        let count_rows = std::cmp::max(self.data.len(), other.data.len());
+        let mut rhs = other.data.into_iter();
+        for row in 0..count_rows {
+            if row == self.data.len() {
+                let empty = self.create_empty_cells(0..offset);
+                self.data.push(empty);
             }
 
-            row.push(text);
-            self.data.push(row);
+            let mut record = rhs.next().unwrap_or_default();
+            record.truncate(count_columns - offset);
+
+            let empty = self.create_empty_cells(self.data[row].len()..offset);
+            self.data[row].extend(empty);
+            self.data[row].extend(record);
+
+            let empty = self.create_empty_cells(self.data[row].len()..count_columns);
+            self.data[row].extend(empty);
         }
 
-        self.count_columns += 1;
+        self.count_columns = count_columns;
+
+        self
     }
 
-    /// Insert a column with a specific position.
+    /// Joins 2 builders by a key column, like SQL `JOIN` does.
     ///
-    /// In case a column is bigger then the total amount of rows it will be truncated.
+    /// The result has all columns of `self` followed by all columns of `other`.
+    /// Records with equal keys in `on_self` and `on_other` columns are merged side by side,
+    /// a key with several matches produces a record per each pair.
+    /// Missing sides of [`JoinType::Left`], [`JoinType::Right`] and [`JoinType::Full`] joins
+    /// are filled with a text set by [`Builder::set_empty`].
     ///
-    /// # Panics
+    /// Headers (the first records) are not matched.
+    /// Header names of `other` which are present in `self` get a `_right` suffix,
+    /// see [`Builder::join_with_suffix`].
     ///
-    /// Panics if `index > count_columns`.
-    pub fn insert_column<I>(&mut self, index: usize, column: I)
-    where
-        I: IntoIterator,
-        I::Item: Into<String>,
-    {
-        let mut iter = column.into_iter();
-
-        for row in self.data.iter_mut() {
-            let text = iter
-                .next()
-                .map(Into::into)
-                .map(CellInfo::new)
-                .unwrap_or(self.empty_text.clone());
+    /// # Example
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use tabled::builder::{Builder, JoinType};
+    ///
+    /// let users = Builder::from_iter([["id", "name"], ["1", "Alex"], ["2", "Maria"]]);
+    /// let orders = Builder::from_iter([["id", "item"], ["2", "book"], ["3", "pen"]]);
+    ///
+    /// let table = users.join(orders, 0, 0, JoinType::Left).build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+----+-------+----------+------+\n\
+    ///      | id | name  | id_right | item |\n\
+    ///      +----+-------+----------+------+\n\
+    ///      | 1  | Alex  |          |      |\n\
+    ///      +----+-------+----------+------+\n\
+    ///      | 2  | Maria | 2        | book |\n\
+    ///      +----+-------+----------+------+"
+    /// );
+    /// ```
+    pub fn join(self, other: Builder, on_self: usize, on_other: usize, join: JoinType) -> Builder {
+        self.join_with_suffix(other, on_self, on_other, join, "_right")
+    }
 
-            row.insert(index, text);
+    /// Joins 2 builders by a key column, like SQL `JOIN` does.
+    ///
+    /// The same as [`Builder::join`],
+    /// but header names of `other` which are present in `self` get a given `suffix`.
+    pub fn join_with_suffix(
+        self,
+        other: Builder,
+        on_self: usize,
+        on_other: usize,
+        join: JoinType,
+        suffix: &str,
+    ) -> Builder {
+        let empty = self.empty_text.clone();
+        let lhs_empty = vec![empty.clone(); self.count_columns];
+        let rhs_empty = vec![empty.clone(); other.count_columns];
+
+        let mut header = Vec::with_capacity(self.count_columns + other.count_columns);
+        header.extend(
+            self.data
+                .first()
+                .cloned()
+                .unwrap_or_else(|| lhs_empty.clone()),
+        );
+        let rhs_header = other
+            .data
+            .first()
+            .cloned()
+            .unwrap_or_else(|| rhs_empty.clone());
+        for cell in rhs_header {
+            let is_duplicate = header.iter().take(self.count_columns).any(|c| c == &cell);
+            if is_duplicate {
+                header.push(CellInfo::new(format!("{}{}", cell.as_ref(), suffix)));
+            } else {
+                header.push(cell);
+            }
         }
 
-        for text in iter {
-            let text = CellInfo::new(text.into());
-
-            let mut row = Vec::with_capacity(self.count_columns + 1);
-            for _ in 0..index {
-                row.push(self.empty_text.clone());
+        let lhs = self.data.get(1..).unwrap_or_default();
+        let rhs = other.data.get(1..).unwrap_or_default();
+        let key = |record: &[CellInfo<String>], col: usize| {
+            record.get(col).map(|cell| cell.as_ref().to_owned())
+        };
+
+        let merge = |l: &[CellInfo<String>], r: &[CellInfo<String>]| {
+            let mut record = l.to_vec();
+            record.extend_from_slice(r);
+            record
+        };
+
+        let mut data = vec![header];
+        let mut is_rhs_matched = vec![false; rhs.len()];
+
+        match join {
+            JoinType::Inner | JoinType::Left | JoinType::Full => {
+                for l in lhs {
+                    let lkey = key(l, on_self);
+                    let mut is_matched = false;
+                    for (i, r) in rhs.iter().enumerate() {
+                        if lkey.is_some() && lkey == key(r, on_other) {
+                            data.push(merge(l, r));
+                            is_rhs_matched[i] = true;
+                            is_matched = true;
+                        }
+                    }
+
+                    if !is_matched && join != JoinType::Inner {
+                        data.push(merge(l, &rhs_empty));
+                    }
+                }
+
+                if join == JoinType::Full {
+                    for (r, _) in rhs.iter().zip(is_rhs_matched).filter(|(_, m)| !m) {
+                        data.push(merge(&lhs_empty, r));
+                    }
+                }
             }
-
-            row.push(text);
-
-            for _ in index..self.count_columns {
-                row.push(self.empty_text.clone());
+            JoinType::Right => {
+                for r in rhs {
+                    let rkey = key(r, on_other);
+                    let mut is_matched = false;
+                    for l in lhs {
+                        if rkey.is_some() && rkey == key(l, on_self) {
+                            data.push(merge(l, r));
+                            is_matched = true;
+                        }
+                    }
+
+                    if !is_matched {
+                        data.push(merge(&lhs_empty, r));
+                    }
+                }
             }
         }
 
-        self.count_columns += 1;
-    }
+        let mut builder = Builder::from_vec(data);
+        builder.empty_text = empty;
 
-    /// Remove all records.
-    pub fn clear(&mut self) {
-        self.data.clear();
-        self.count_columns = 0;
+        builder
     }
 
     /// Returns an amount of columns which would be present in a built table.
@@ -355,6 +2605,143 @@ impl Builder {
     }
 }
 
+/// A cell which differs in 2 builders.
+///
+/// See [`Builder::diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellDiff {
+    /// A row index.
+    pub row: usize,
+    /// A column index.
+    pub col: usize,
+    /// A content of the cell in the first builder.
+    pub left: Option<String>,
+    /// A content of the cell in the second builder.
+    pub right: Option<String>,
+}
+
+/// A kind of a character which might break a table layout.
+///
+/// See [`Builder::scan_width_hazards`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WidthHazard {
+    /// A control character, like `\t` or `\r`.
+    ControlChar,
+    /// A zero width character, like a combining mark or a zero width joiner.
+    ZeroWidth,
+    /// A character which width depends on a terminal (East Asian Ambiguous).
+    AmbiguousWidth,
+}
+
+impl WidthHazard {
+    /// Checks a character against its width as it's measured by a table.
+    fn of(c: char, width: usize) -> Option<Self> {
+        if c == '\n' || (cfg!(feature = "ansi") && c == '\u{1b}') {
+            return None;
+        }
+
+        if c.is_control() {
+            return Some(Self::ControlChar);
+        }
+
+        if width == 0 {
+            return Some(Self::ZeroWidth);
+        }
+
+        if Some(width) != unicode_width::UnicodeWidthChar::width_cjk(c) {
+            return Some(Self::AmbiguousWidth);
+        }
+
+        None
+    }
+}
+
+/// A kind of [`Builder::join`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JoinType {
+    /// Only records which have a match in both builders are kept.
+    Inner,
+    /// All records of the left builder are kept.
+    Left,
+    /// All records of the right builder are kept.
+    Right,
+    /// All records of both builders are kept.
+    Full,
+}
+
+/// An error which is returned when a data with a different amount
+/// of columns is added to a [`Builder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnCountMismatch {
+    /// An amount of columns in a [`Builder`].
+    pub expected: usize,
+    /// An amount of columns in a data.
+    pub found: usize,
+}
+
+impl ColumnCountMismatch {
+    fn new(expected: usize, found: usize) -> Self {
+        Self { expected, found }
+    }
+}
+
+impl fmt::Display for ColumnCountMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected {} columns but found {}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ColumnCountMismatch {}
+
+/// An error which is returned by [`Builder::ensure_rectangular`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RaggedRecord {
+    /// An index of a record, where `0` is a header.
+    pub index: usize,
+    /// An amount of cells in the record.
+    pub found: usize,
+    /// An amount of columns in a [`Builder`].
+    pub expected: usize,
+}
+
+impl fmt::Display for RaggedRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "record {} has {} cells but expected {}",
+            self.index, self.found, self.expected
+        )
+    }
+}
+
+impl std::error::Error for RaggedRecord {}
+
+/// An error which is returned by [`Builder::set_column_groups`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnGroupError {
+    /// A range is empty or exceeds the amount of columns.
+    OutOfBounds(Range<usize>),
+    /// Two ranges overlap.
+    Overlap(Range<usize>, Range<usize>),
+}
+
+impl fmt::Display for ColumnGroupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds(range) => write!(f, "column group {:?} is out of bounds", range),
+            Self::Overlap(lhs, rhs) => {
+                write!(f, "column groups {:?} and {:?} overlap", lhs, rhs)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ColumnGroupError {}
+
 impl From<Builder> for Vec<Vec<String>> {
     fn from(builder: Builder) -> Self {
         builder
@@ -408,6 +2795,12 @@ impl From<Vec<Vec<String>>> for Builder {
             data,
             count_columns,
             empty_text: CellInfo::default(),
+            column_empty_texts: Vec::new(),
+            null_values: Vec::new(),
+            column_groups: Vec::new(),
+            alignment_hints: Vec::new(),
+            column_max_widths: Vec::new(),
+            max_records: None,
         }
     }
 }
@@ -420,14 +2813,21 @@ impl From<Vec<Vec<CellInfo<String>>>> for Builder {
             data,
             count_columns,
             empty_text: CellInfo::default(),
+            column_empty_texts: Vec::new(),
+            null_values: Vec::new(),
+            column_groups: Vec::new(),
+            alignment_hints: Vec::new(),
+            column_max_widths: Vec::new(),
+            max_records: None,
         }
     }
 }
 
-fn create_row<R>(row: R, size: usize, default: &CellInfo<String>) -> Vec<CellInfo<String>>
+fn create_row<'a, R, F>(row: R, size: usize, default: F) -> Vec<CellInfo<String>>
 where
     R: IntoIterator,
     R::Item: Into<String>,
+    F: Fn(usize) -> &'a CellInfo<String>,
 {
     let mut list = Vec::with_capacity(size);
     for text in row {
@@ -436,31 +2836,24 @@ where
         list.push(text);
     }
 
-    if list.len() < size {
-        for _ in 0..size - list.len() {
-            let text = default.clone();
-            list.push(text);
-        }
+    for col in list.len()..size {
+        let text = default(col).clone();
+        list.push(text);
     }
 
     list
 }
 
-fn remove_empty_columns(data: &mut [Vec<CellInfo<String>>], count_columns: usize) -> usize {
+fn remove_empty_columns(
+    data: &mut [Vec<CellInfo<String>>],
+    count_columns: usize,
+    nulls: &[String],
+) -> usize {
     let mut deleted = 0;
     for col in 0..count_columns {
         let col = col - deleted;
 
-        let mut is_empty_column = true;
-        for row in data.iter() {
-            let text = &row[col];
-            if !text.as_ref().is_empty() {
-                is_empty_column = false;
-                break;
-            }
-        }
-
-        if is_empty_column {
+        if is_empty_column(data, col, nulls) {
             for row in data.iter_mut() {
                 let _ = row.remove(col);
             }
@@ -472,7 +2865,18 @@ fn remove_empty_columns(data: &mut [Vec<CellInfo<String>>], count_columns: usize
     deleted
 }
 
-fn remove_empty_rows(data: &mut Vec<Vec<CellInfo<String>>>, count_columns: usize) {
+fn is_empty_column(data: &[Vec<CellInfo<String>>], col: usize, nulls: &[String]) -> bool {
+    data.iter().all(|row| match row.get(col) {
+        Some(text) => is_empty_cell(text.as_ref(), nulls),
+        None => true,
+    })
+}
+
+fn remove_empty_rows(
+    data: &mut Vec<Vec<CellInfo<String>>>,
+    count_columns: usize,
+    nulls: &[String],
+) {
     let mut deleted = 0;
 
     for row in 0..data.len() {
@@ -481,7 +2885,7 @@ fn remove_empty_rows(data: &mut Vec<Vec<CellInfo<String>>>, count_columns: usize
         let mut is_empty_row = true;
         for col in 0..count_columns {
             let cell = &data[row][col];
-            if !cell.as_ref().is_empty() {
+            if !is_empty_cell(cell.as_ref(), nulls) {
                 is_empty_row = false;
                 break;
             }
@@ -494,12 +2898,84 @@ fn remove_empty_rows(data: &mut Vec<Vec<CellInfo<String>>>, count_columns: usize
     }
 }
 
-fn resize_rows(data: &mut Vec<Vec<CellInfo<String>>>, size: usize, empty_text: &CellInfo<String>) {
+fn is_empty_cell(text: &str, nulls: &[String]) -> bool {
+    text.is_empty() || is_null(text, nulls)
+}
+
+fn is_null(text: &str, nulls: &[String]) -> bool {
+    nulls.iter().any(|null| null == text)
+}
+
+fn move_element<T>(list: &mut [T], from: usize, to: usize) {
+    use std::cmp::Ordering;
+
+    match from.cmp(&to) {
+        Ordering::Less => list[from..=to].rotate_left(1),
+        Ordering::Greater => list[to..=from].rotate_right(1),
+        Ordering::Equal => {}
+    }
+}
+
+fn resize_rows(data: &mut Vec<Vec<CellInfo<String>>>, cells: &[CellInfo<String>]) {
     for row in data {
-        append_vec(row, empty_text.clone(), size);
+        row.extend_from_slice(cells);
     }
 }
 
+/// Moves a column group to new positions of its columns.
+///
+/// A group keeps contiguous columns, it may grow over newly inserted columns,
+/// but if its columns are split by other columns only the longest part is kept.
+/// A group is dropped if all its columns are removed.
+fn remap_column_group<F>(range: Range<usize>, count_columns: usize, f: F) -> Option<Range<usize>>
+where
+    F: Fn(usize) -> Option<usize>,
+{
+    let mut columns = range.clone().filter_map(&f).collect::<Vec<_>>();
+    columns.sort_unstable();
+
+    let others = (0..count_columns)
+        .filter(|col| !range.contains(col))
+        .filter_map(&f)
+        .collect::<HashSet<_>>();
+
+    let mut runs: Vec<Range<usize>> = Vec::new();
+    for col in columns {
+        match runs.last_mut() {
+            Some(run) if (run.end..col).all(|c| !others.contains(&c)) => run.end = col + 1,
+            _ => runs.push(col..col + 1),
+        }
+    }
+
+    // the first of the longest parts
+    runs.into_iter().rev().max_by_key(|run| run.len())
+}
+
+fn remap_column<F>(col: &mut usize, f: F) -> bool
+where
+    F: Fn(usize) -> Option<usize>,
+{
+    match f(*col) {
+        Some(new) => {
+            *col = new;
+            true
+        }
+        None => false,
+    }
+}
+
+fn get_empty_text<'a>(
+    empty_text: &'a CellInfo<String>,
+    column_empty_texts: &'a [(usize, CellInfo<String>)],
+    col: usize,
+) -> &'a CellInfo<String> {
+    column_empty_texts
+        .iter()
+        .find(|(c, _)| *c == col)
+        .map(|(_, text)| text)
+        .unwrap_or(empty_text)
+}
+
 fn append_vec<T>(v: &mut Vec<T>, value: T, n: usize)
 where
     T: Clone,
@@ -0,0 +1,274 @@
+use std::fmt;
+
+use crate::{
+    builder::Builder,
+    settings::{object::Columns, Alignment},
+    Table,
+};
+
+/// [`TableDef`] is a set of column definitions,
+/// which can be used to build a [`Table`] from any type without [`Tabled`] implementation.
+///
+/// Each column consists of a header, a function which extracts a value from a row
+/// and an optional alignment.
+/// Columns are rendered in the order they were added.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{builder::TableDef, settings::Alignment};
+///
+/// struct User {
+///     name: String,
+///     age: u8,
+/// }
+///
+/// let users = vec![
+///     User { name: String::from("Alice"), age: 7 },
+///     User { name: String::from("Bob"), age: 32 },
+/// ];
+///
+/// let table = TableDef::new()
+///     .column("Name", |u: &User| u.name.clone())
+///     .column_with("Age", |u| u.age.to_string(), Alignment::right())
+///     .build(&users)
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+-------+-----+\n\
+///      | Name  | Age |\n\
+///      +-------+-----+\n\
+///      | Alice |   7 |\n\
+///      +-------+-----+\n\
+///      | Bob   |  32 |\n\
+///      +-------+-----+"
+/// );
+/// ```
+///
+/// [`Tabled`]: crate::Tabled
+pub struct TableDef<'a, T> {
+    columns: Vec<ColumnDef<'a, T>>,
+}
+
+type Accessor<'a, T> = Box<dyn Fn(&T) -> String + 'a>;
+
+struct ColumnDef<'a, T> {
+    header: String,
+    value: Accessor<'a, T>,
+    alignment: Option<Alignment>,
+}
+
+impl<'a, T> TableDef<'a, T> {
+    /// Creates a [`TableDef`] with no columns.
+    pub fn new() -> Self {
+        Self {
+            columns: Vec::new(),
+        }
+    }
+
+    /// Adds a column.
+    pub fn column<S, F>(self, header: S, value: F) -> Self
+    where
+        S: Into<String>,
+        F: Fn(&T) -> String + 'a,
+    {
+        self.add_column(header.into(), Box::new(value), None)
+    }
+
+    /// Adds a column with a given alignment.
+    ///
+    /// The alignment is applied to the whole column, including its header.
+    pub fn column_with<S, F>(self, header: S, value: F, alignment: Alignment) -> Self
+    where
+        S: Into<String>,
+        F: Fn(&T) -> String + 'a,
+    {
+        self.add_column(header.into(), Box::new(value), Some(alignment))
+    }
+
+    /// Returns an amount of defined columns.
+    pub fn count_columns(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Builds a [`Table`] from a given data.
+    pub fn build<'b, I>(&self, data: I) -> Table
+    where
+        I: IntoIterator<Item = &'b T>,
+        T: 'b,
+    {
+        let mut builder = Builder::with_capacity(0, self.columns.len());
+        builder.push_record(self.columns.iter().map(|c| c.header.clone()));
+
+        for row in data {
+            builder.push_record(self.columns.iter().map(|c| (c.value)(row)));
+        }
+
+        let mut table = builder.build();
+
+        for (i, column) in self.columns.iter().enumerate() {
+            if let Some(alignment) = column.alignment {
+                let _ = table.modify(Columns::single(i), alignment);
+            }
+        }
+
+        table
+    }
+
+    fn add_column(
+        mut self,
+        header: String,
+        value: Accessor<'a, T>,
+        alignment: Option<Alignment>,
+    ) -> Self {
+        self.columns.push(ColumnDef {
+            header,
+            value,
+            alignment,
+        });
+
+        self
+    }
+}
+
+impl<T> Default for TableDef<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for TableDef<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let headers = self.columns.iter().map(|c| &c.header).collect::<Vec<_>>();
+
+        f.debug_struct("TableDef")
+            .field("columns", &headers)
+            .finish()
+    }
+}
+
+/// [`Schema`] is a list of headers and a list of accessors of a dynamic record,
+/// which are defined separately, e.g. when a layout is read at runtime.
+///
+/// Each accessor produces a cell of a column with the header of the same index.
+/// It's used by [`Table::with_schema`].
+///
+/// See [`TableDef`] in case headers and accessors are known together.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{builder::Schema, Table};
+///
+/// let rows = vec![vec!["Alice", "7"], vec!["Bob", "32"]];
+///
+/// let schema = Schema::new(["age", "name"])
+///     .field(|row: &Vec<&str>| row[1].to_owned())
+///     .field(|row| row[0].to_owned());
+///
+/// let table = Table::with_schema(&schema, &rows).unwrap();
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+-----+-------+\n\
+///      | age | name  |\n\
+///      +-----+-------+\n\
+///      | 7   | Alice |\n\
+///      +-----+-------+\n\
+///      | 32  | Bob   |\n\
+///      +-----+-------+"
+/// );
+/// ```
+///
+/// [`Table::with_schema`]: crate::Table::with_schema
+pub struct Schema<'a, T> {
+    headers: Vec<String>,
+    fields: Vec<Accessor<'a, T>>,
+}
+
+impl<'a, T> Schema<'a, T> {
+    /// Creates a [`Schema`] with given headers and no accessors.
+    pub fn new<I>(headers: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            headers: headers.into_iter().map(Into::into).collect(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Adds an accessor of a next column.
+    pub fn field<F>(mut self, value: F) -> Self
+    where
+        F: Fn(&T) -> String + 'a,
+    {
+        self.fields.push(Box::new(value));
+        self
+    }
+
+    /// Returns headers of the schema.
+    pub fn headers(&self) -> &[String] {
+        &self.headers
+    }
+
+    /// Builds a [`Table`] from a given data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SchemaError`] if an amount of accessors is different from an amount of headers.
+    pub(crate) fn build<'b, I>(&self, data: I) -> Result<Table, SchemaError>
+    where
+        I: IntoIterator<Item = &'b T>,
+        T: 'b,
+    {
+        if self.headers.len() != self.fields.len() {
+            return Err(SchemaError {
+                headers: self.headers.len(),
+                fields: self.fields.len(),
+            });
+        }
+
+        let mut builder = Builder::with_capacity(0, self.headers.len());
+        builder.push_record(self.headers.iter().cloned());
+
+        for row in data {
+            builder.push_record(self.fields.iter().map(|field| field(row)));
+        }
+
+        Ok(builder.build())
+    }
+}
+
+impl<T> fmt::Debug for Schema<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Schema")
+            .field("headers", &self.headers)
+            .field("fields", &self.fields.len())
+            .finish()
+    }
+}
+
+/// An error which is returned when a [`Schema`] has
+/// a different amount of accessors and headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchemaError {
+    /// An amount of headers.
+    pub headers: usize,
+    /// An amount of accessors.
+    pub fields: usize,
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a schema has {} headers but {} fields",
+            self.headers, self.fields
+        )
+    }
+}
+
+impl std::error::Error for SchemaError {}
@@ -0,0 +1,135 @@
+use std::{
+    fmt,
+    io::{self, BufRead, BufReader, Read, Write},
+};
+
+use crate::grid::records::vec_records::CellInfo;
+
+use super::Builder;
+
+/// A way a tab or a line break inside a cell is handled by [`Builder::into_tsv`].
+///
+/// [`Builder::into_tsv`]: crate::builder::Builder::into_tsv
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TsvPolicy {
+    /// A tab or a line break is replaced with a space.
+    #[default]
+    ReplaceWithSpace,
+    /// An error of [`io::ErrorKind::InvalidData`] kind is returned.
+    Reject,
+}
+
+/// An error which is returned by [`Builder::from_tsv`].
+///
+/// [`Builder::from_tsv`]: crate::builder::Builder::from_tsv
+#[derive(Debug)]
+pub enum ParseError {
+    /// An input can't be read, or it's not a valid UTF-8.
+    Io(io::Error),
+    /// A line has a different amount of cells than the first one.
+    ///
+    /// As each tab is a delimiter, a tab inside a cell produces this error too.
+    ColumnCountMismatch {
+        /// A line number, starting from 1.
+        line: usize,
+        /// An amount of cells in the first line.
+        expected: usize,
+        /// An amount of cells in the line.
+        found: usize,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to read TSV input: {}", err),
+            Self::ColumnCountMismatch {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {} has {} cells but expected {} (is there an unescaped tab?)",
+                line, found, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::ColumnCountMismatch { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for ParseError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Parses tab-separated values into a [`Builder`], a record per line.
+pub(super) fn parse_tsv<R: Read>(reader: R) -> Result<Builder, ParseError> {
+    let mut builder = Builder::default();
+    let mut count_columns = None;
+
+    for (i, line) in BufReader::new(reader).lines().enumerate() {
+        let line = line?;
+        let line = line.strip_suffix('\r').unwrap_or(&line);
+
+        let record = line.split('\t').collect::<Vec<_>>();
+
+        let expected = *count_columns.get_or_insert(record.len());
+        if record.len() != expected {
+            return Err(ParseError::ColumnCountMismatch {
+                line: i + 1,
+                expected,
+                found: record.len(),
+            });
+        }
+
+        builder.push_record(record);
+    }
+
+    Ok(builder)
+}
+
+/// Writes records as tab-separated values, a line per record.
+pub(super) fn write_tsv<W: Write>(
+    data: &[Vec<CellInfo<String>>],
+    count_columns: usize,
+    mut writer: W,
+    policy: TsvPolicy,
+) -> io::Result<()> {
+    let mut buf = String::new();
+    for (row, record) in data.iter().enumerate() {
+        buf.clear();
+
+        for col in 0..count_columns {
+            if col > 0 {
+                buf.push('\t');
+            }
+
+            let text = record.get(col).map_or("", |cell| cell.as_ref());
+            if policy == TsvPolicy::Reject && text.contains(is_delimiter) {
+                let msg = format!("a cell ({}, {}) contains a tab or a line break", row, col);
+                return Err(io::Error::new(io::ErrorKind::InvalidData, msg));
+            }
+
+            buf.extend(text.chars().map(|c| if is_delimiter(c) { ' ' } else { c }));
+        }
+
+        buf.push('\n');
+        writer.write_all(buf.as_bytes())?;
+    }
+
+    writer.flush()
+}
+
+/// Checks whether a char can't be kept in a cell, as it separates cells or records.
+fn is_delimiter(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\r')
+}
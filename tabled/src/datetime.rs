@@ -0,0 +1,56 @@
+//! This module contains formatting functions for [`chrono`] types.
+//!
+//! The functions have a signature expected by `#[tabled(display_with = "...")]`,
+//! so they can be used for fields of a derived [`Tabled`] type.
+//!
+//! # Example
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use tabled::{Table, Tabled};
+//!
+//! #[derive(Tabled)]
+//! struct Release {
+//!     version: &'static str,
+//!     #[tabled(display_with = "tabled::datetime::date")]
+//!     date: NaiveDate,
+//! }
+//!
+//! let data = [Release { version: "0.15.0", date: NaiveDate::from_ymd_opt(2023, 12, 20).unwrap() }];
+//!
+//! let table = Table::new(data).to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     "+---------+------------+\n\
+//!      | version | date       |\n\
+//!      +---------+------------+\n\
+//!      | 0.15.0  | 2023-12-20 |\n\
+//!      +---------+------------+"
+//! );
+//! ```
+//!
+//! [`Tabled`]: crate::Tabled
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone};
+
+/// Formats a date as `YYYY-MM-DD`.
+pub fn date(date: &NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+/// Formats a date and time as `YYYY-MM-DD HH:MM:SS`.
+///
+/// Fractional seconds are dropped.
+pub fn datetime(datetime: &NaiveDateTime) -> String {
+    datetime.format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+/// Formats a date and time with a time zone according to RFC 3339.
+pub fn rfc3339<Tz>(datetime: &DateTime<Tz>) -> String
+where
+    Tz: TimeZone,
+    Tz::Offset: std::fmt::Display,
+{
+    datetime.to_rfc3339()
+}
@@ -1,8 +1,14 @@
-use std::ops::{Deref, DerefMut};
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
 
-use crate::grid::{
-    ansi::ANSIBuf,
-    config::{Entity, EntityMap, SpannedConfig},
+use crate::{
+    grid::{
+        ansi::ANSIBuf,
+        config::{Entity, EntityMap, HorizontalLine, SpannedConfig},
+    },
+    settings::panel::Caption,
 };
 
 /// A spanned configuration plus colors for cells.
@@ -10,6 +16,11 @@ use crate::grid::{
 pub struct ColoredConfig {
     config: SpannedConfig,
     colors: ColorMap,
+    horizontal_line_repeat: usize,
+    captions: Vec<Caption>,
+    preset_header_line: Option<HorizontalLine<char>>,
+    // original and changed texts of cells changed by column width hints
+    hinted_texts: HashMap<(usize, usize), (String, String)>,
 }
 
 impl ColoredConfig {
@@ -18,6 +29,10 @@ impl ColoredConfig {
         Self {
             config,
             colors: ColorMap::default(),
+            horizontal_line_repeat: 1,
+            captions: Vec::new(),
+            preset_header_line: None,
+            hinted_texts: HashMap::new(),
         }
     }
 
@@ -60,6 +75,73 @@ impl ColoredConfig {
         &self.colors
     }
 
+    /// Set a number of times each horizontal line is rendered.
+    ///
+    /// `0` is treated as `1`.
+    pub fn set_horizontal_line_repeat(&mut self, n: usize) -> &mut Self {
+        self.horizontal_line_repeat = n;
+        self
+    }
+
+    /// Returns a number of times each horizontal line is rendered.
+    pub fn get_horizontal_line_repeat(&self) -> usize {
+        std::cmp::max(self.horizontal_line_repeat, 1)
+    }
+
+    /// Adds a caption which is rendered outside of a table borders.
+    pub fn push_caption(&mut self, caption: Caption) -> &mut Self {
+        self.captions.push(caption);
+        self
+    }
+
+    /// Returns a list of captions in order they were added.
+    pub fn get_captions(&self) -> &[Caption] {
+        &self.captions
+    }
+
+    /// Sets a line under a header which was set by a preset [`Style`],
+    /// rather than explicitly.
+    ///
+    /// [`Style`]: crate::settings::Style
+    pub(crate) fn set_preset_header_line(&mut self, line: Option<HorizontalLine<char>>) {
+        self.preset_header_line = line;
+    }
+
+    /// Checks whether a line under a header is the one set by a preset [`Style`].
+    ///
+    /// [`Style`]: crate::settings::Style
+    pub(crate) fn is_preset_header_line(&self) -> bool {
+        self.preset_header_line.is_some()
+            && self.config.get_horizontal_line(1) == self.preset_header_line.as_ref()
+    }
+
+    /// Remembers an original text of a cell which was changed by a column width hint
+    /// (see [`Tabled::column_hints`]).
+    ///
+    /// [`Tabled::column_hints`]: crate::Tabled::column_hints
+    pub(crate) fn set_hinted_text(&mut self, pos: (usize, usize), original: String, text: String) {
+        let _ = self.hinted_texts.insert(pos, (original, text));
+    }
+
+    /// Returns an original text of a cell changed by a column width hint,
+    /// unless the cell was changed since.
+    ///
+    /// The cell is not considered to be changed by the hint afterwards.
+    pub(crate) fn take_hinted_text(&mut self, pos: (usize, usize), text: &str) -> Option<String> {
+        let (original, hinted) = self.hinted_texts.remove(&pos)?;
+        (hinted == text).then_some(original)
+    }
+
+    /// Checks whether any cell was changed by a column width hint.
+    pub(crate) fn has_hinted_texts(&self) -> bool {
+        !self.hinted_texts.is_empty()
+    }
+
+    /// Forgets original texts of cells changed by column width hints.
+    pub(crate) fn clear_hinted_texts(&mut self) {
+        self.hinted_texts.clear();
+    }
+
     /// Returns an inner config.
     pub fn into_inner(self) -> SpannedConfig {
         self.config
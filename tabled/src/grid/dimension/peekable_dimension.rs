@@ -1,8 +1,11 @@
-use crate::grid::{
-    config::SpannedConfig,
-    dimension::{Dimension, Estimate},
-    records::vec_records::{CellInfo, VecRecords},
-    records::Records,
+use crate::{
+    builder::BuilderRecords,
+    grid::{
+        config::SpannedConfig,
+        dimension::{Dimension, Estimate},
+        records::vec_records::{CellInfo, VecRecords},
+        records::Records,
+    },
 };
 
 /// PeekableDimension is a [`Dimension`] implementation for a [`Table`]
@@ -52,7 +55,16 @@ where
     T: AsRef<str>,
 {
     fn estimate(&mut self, records: &VecRecords<CellInfo<T>>, cfg: &SpannedConfig) {
-        let (width, height) = estimation::build_dimensions(records, cfg);
+        let (width, height) = estimation::build_dimensions(records, records.count_columns(), cfg);
+        self.width = width;
+        self.height = height;
+    }
+}
+
+impl Estimate<BuilderRecords<'_>, SpannedConfig> for PeekableDimension {
+    fn estimate(&mut self, records: BuilderRecords<'_>, cfg: &SpannedConfig) {
+        let count_columns = records.count_columns();
+        let (width, height) = estimation::build_dimensions(records.rows(), count_columns, cfg);
         self.width = width;
         self.height = height;
     }
@@ -67,20 +79,19 @@ mod estimation {
     use super::*;
 
     pub(super) fn build_dimensions<T: AsRef<str>>(
-        records: &VecRecords<CellInfo<T>>,
+        records: &[Vec<CellInfo<T>>],
+        count_columns: usize,
         cfg: &SpannedConfig,
     ) -> (Vec<usize>, Vec<usize>) {
-        let count_columns = records.count_columns();
-
         let mut widths = vec![0; count_columns];
         let mut heights = vec![];
 
         let mut vspans = HashMap::new();
         let mut hspans = HashMap::new();
 
-        for (row, columns) in records.iter_rows().enumerate() {
+        for (row, columns) in records.iter().enumerate() {
             let mut row_height = 0;
-            for (col, cell) in columns.iter().enumerate() {
+            for (col, cell) in columns.iter().enumerate().take(count_columns) {
                 let pos = (row, col);
                 if !cfg.is_cell_visible(pos) {
                     continue;
@@ -156,7 +167,7 @@ mod estimation {
             return;
         }
 
-        inc_range(heights, max_span_height - range_height, start, end);
+        inc_range_evenly(heights, max_span_height - range_height, start, end);
     }
 
     fn range_height(
@@ -204,6 +215,26 @@ mod estimation {
         }
     }
 
+    /// Increases a range of values by `size` in total,
+    /// so they differ at most by 1 and the leftover goes to the first ones.
+    fn inc_range_evenly(list: &mut [usize], size: usize, start: usize, end: usize) {
+        if list.is_empty() {
+            return;
+        }
+
+        let span = end - start;
+        let one = size / span;
+        let rest = size - span * one;
+
+        for (i, value) in list[start..end].iter_mut().enumerate() {
+            *value += one;
+
+            if i < rest {
+                *value += 1;
+            }
+        }
+    }
+
     fn adjust_vspans(
         cfg: &SpannedConfig,
         len: usize,
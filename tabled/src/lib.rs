@@ -305,9 +305,16 @@ pub mod macros;
 
 pub mod grid;
 
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+pub mod datetime;
+
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-pub use crate::{tabled::Tabled, tables::Table};
+pub use crate::{
+    tabled::{CellFormatter, ColumnHint, Tabled},
+    tables::Table,
+};
 
 /// A derive macro to implement a [`Tabled`] trait.
 ///
@@ -430,6 +437,31 @@ pub use crate::{tabled::Tabled, tables::Table};
 /// }
 /// ```
 ///
+/// A formatter can be also a type implementing [`CellFormatter`],
+/// set by `#[tabled(with = "Formatter")]`.
+/// It's handy when a formatter is shared by many fields, or it's parametrized.
+///
+/// ```rust,no_run
+/// use tabled::{CellFormatter, Tabled};
+///
+/// struct Money;
+///
+/// impl CellFormatter<u64> for Money {
+///     fn format(cents: &u64) -> String {
+///         format!("${}.{:02}", cents / 100, cents % 100)
+///     }
+/// }
+///
+/// #[derive(Tabled)]
+/// pub struct Order {
+///     pub id: i64,
+///     #[tabled(with = "Money")]
+///     pub price: u64,
+///     #[tabled(with = "Money")]
+///     pub discount: u64,
+/// }
+/// ```
+///
 /// There's also a probably more suitable way for formatting, if your format is constant.
 /// Using `#[tabled(format = "{}")]` and `#[tabled(format("{}"))]` and proving a general formatting string.
 ///
@@ -464,6 +496,23 @@ pub use crate::{tabled::Tabled, tables::Table};
 /// }
 /// ```
 ///
+/// Names set by `#[serde(rename = "")]` can be reused by `#[tabled(serde_rename)]`.
+/// `#[tabled(rename = "")]` still takes precedence over them.
+///
+/// ```rust,no_run
+/// use tabled::Tabled;
+/// use serde::Serialize;
+///
+/// #[derive(Tabled, Serialize)]
+/// #[tabled(serde_rename)]
+/// struct Person {
+///     #[serde(rename = "ID")]
+///     id: u8,
+///     #[serde(rename = "Full Name")]
+///     name: String,
+/// }
+/// ```
+///
 /// ### Inline
 ///
 /// It's possible to inline internal data if it implements the `Tabled` trait using `#[tabled(inline)]`.
@@ -512,6 +561,80 @@ pub use crate::{tabled::Tabled, tables::Table};
 ///     price: f32,
 /// }
 /// ```
+///
+/// ### Transparent
+///
+/// A newtype can be marked with `#[tabled(transparent)]` so it's rendered as its inner value.
+/// When such a type is inlined the column name is taken from the outer field.
+///
+/// ```rust,no_run
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// #[tabled(transparent)]
+/// struct UserId(u64);
+///
+/// #[derive(Tabled)]
+/// struct User {
+///     #[tabled(inline)]
+///     id: UserId,
+///     name: String,
+/// }
+/// ```
+///
+/// It can be used only on a struct with a single field.
+///
+/// ```rust,compile_fail
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// #[tabled(transparent)]
+/// struct Point(u64, u64);
+/// ```
+///
+/// ### Column width
+///
+/// A width of a column can be limited by `#[tabled(width = 40)]`,
+/// the hint is applied by [`Table::new`] (see [`Tabled::column_hints`]).
+/// A width set for the column afterwards is applied to the original content instead.
+/// A content is wrapped by default, `#[tabled(truncate)]` truncates it instead.
+///
+/// ```
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Task {
+///     #[tabled(width = 8, wrap)]
+///     description: &'static str,
+///     #[tabled(width = 3, truncate)]
+///     tag: &'static str,
+/// }
+///
+/// let table = Table::new([Task { description: "cook a dinner", tag: "home" }]);
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+----------+-----+\n\
+///      | descript | tag |\n\
+///      | ion      |     |\n\
+///      +----------+-----+\n\
+///      | cook a d | hom |\n\
+///      | inner    |     |\n\
+///      +----------+-----+"
+/// );
+/// ```
+///
+/// `wrap` and `truncate` can't be used without a width.
+///
+/// ```rust,compile_fail
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// struct Task {
+///     #[tabled(truncate)]
+///     description: String,
+/// }
+/// ```
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use tabled_derive::Tabled;
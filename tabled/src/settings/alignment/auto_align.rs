@@ -0,0 +1,112 @@
+use crate::{
+    grid::{
+        config::{AlignmentHorizontal, ColoredConfig, Entity},
+        records::{ExactRecords, PeekableRecords, Records},
+    },
+    settings::TableOption,
+    util::string::is_number,
+};
+
+/// [`AutoAlign`] sets an alignment of columns based on their content.
+///
+/// The first row is considered to be a header,
+/// so it's not inspected but it's aligned together with its column.
+///
+/// An alignment set afterwards for a particular column overrides it.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{
+///     builder::Builder,
+///     settings::{object::Columns, Alignment, AutoAlign, Modify},
+/// };
+/// use std::iter::FromIterator;
+///
+/// let data = [
+///     ["item", "price", "qty"],
+///     ["apple", "$1,200.50", "3"],
+///     ["pear", "$15", "12"],
+/// ];
+///
+/// let mut table = Builder::from_iter(data).build();
+/// table
+///     .with(AutoAlign::numbers())
+///     .with(Modify::new(Columns::single(2)).with(Alignment::left()));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+-------+-----------+-----+\n\
+///      | item  |     price | qty |\n\
+///      +-------+-----------+-----+\n\
+///      | apple | $1,200.50 | 3   |\n\
+///      +-------+-----------+-----+\n\
+///      | pear  |       $15 | 12  |\n\
+///      +-------+-----------+-----+"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct AutoAlign {
+    threshold: f64,
+}
+
+impl AutoAlign {
+    /// Right aligns columns which contain numbers.
+    ///
+    /// Integers and floats are recognized,
+    /// as well as the ones with a `%` suffix, a currency symbol prefix
+    /// and `,`/`_` thousands separators.
+    /// Empty cells are ignored.
+    ///
+    /// By default all non empty cells must be numbers,
+    /// see [`AutoAlign::threshold`].
+    pub fn numbers() -> Self {
+        Self { threshold: 1.0 }
+    }
+
+    /// Sets a minimal fraction of non empty cells which must be numbers,
+    /// for a column to be considered numeric.
+    ///
+    /// The value is expected to be in `0.0..=1.0` range.
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for AutoAlign
+where
+    R: Records + ExactRecords + PeekableRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        for col in 0..count_columns {
+            let mut count_values = 0;
+            let mut count_numbers = 0;
+
+            for row in 1..count_rows {
+                let text = records.get_text((row, col)).trim();
+                if text.is_empty() {
+                    continue;
+                }
+
+                count_values += 1;
+                if is_number(text) {
+                    count_numbers += 1;
+                }
+            }
+
+            let is_numeric =
+                count_values > 0 && count_numbers as f64 >= self.threshold * count_values as f64;
+            if is_numeric {
+                cfg.set_alignment_horizontal(Entity::Column(col), AlignmentHorizontal::Right);
+            }
+        }
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{
+    grid::config::{ColoredConfig, Entity},
+    settings::{Alignment, CellOption, TableOption},
+};
+
+/// [`HeaderAlign`] sets one alignment for a header (the first row)
+/// and another one for the rest of rows.
+///
+/// It's supposed to be created by [`Alignment::center_headers`].
+///
+/// An alignment set afterwards for particular cells overrides it.
+///
+/// # Example
+///
+/// ```
+/// use std::iter::FromIterator;
+/// use tabled::{settings::Alignment, Table};
+///
+/// let mut table = Table::from_iter([["name", "description"], ["tabled", "tables"]]);
+/// table.with(Alignment::center_headers());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+--------+-------------+\n\
+///      |  name  | description |\n\
+///      +--------+-------------+\n\
+///      | tabled | tables      |\n\
+///      +--------+-------------+"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HeaderAlign {
+    header: Alignment,
+    data: Alignment,
+}
+
+impl HeaderAlign {
+    pub(crate) const fn new(header: Alignment, data: Alignment) -> Self {
+        Self { header, data }
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for HeaderAlign {
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, dims: &mut D) {
+        TableOption::change(self.data, records, cfg, dims);
+        CellOption::change(self.header, records, cfg, Entity::Row(0));
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
@@ -24,6 +24,16 @@ use AlignmentInner::*;
 #[cfg(feature = "std")]
 use crate::grid::config::ColoredConfig;
 
+#[cfg(feature = "std")]
+mod auto_align;
+#[cfg(feature = "std")]
+mod header_align;
+
+#[cfg(feature = "std")]
+pub use auto_align::AutoAlign;
+#[cfg(feature = "std")]
+pub use header_align::HeaderAlign;
+
 /// Alignment represent a horizontal and vertical alignment setting for any cell on a [`Table`].
 ///
 /// An alignment strategy can be set by [`AlignmentStrategy`].
@@ -137,6 +147,14 @@ impl Alignment {
         Self::vertical(AlignmentVertical::Center)
     }
 
+    /// Centers a header (the first row) and left aligns the rest of rows.
+    ///
+    /// See [`HeaderAlign`].
+    #[cfg(feature = "std")]
+    pub const fn center_headers() -> HeaderAlign {
+        HeaderAlign::new(Self::center(), Self::left())
+    }
+
     /// Returns an alignment with the given horizontal alignment.
     const fn horizontal(alignment: AlignmentHorizontal) -> Self {
         Self::new(Horizontal(alignment))
@@ -35,6 +35,45 @@ pub trait CellOption<R, C> {
     }
 }
 
+/// An object safe version of [`CellOption`],
+/// so options of different types can be kept together, e.g. in a `Vec<Box<dyn DynCellOption<R, C>>>`.
+///
+/// It's implemented for any [`CellOption`] which is [`Clone`].
+pub trait DynCellOption<R, C> {
+    /// Applies a copy of an option to a part of a grid targeted by [`Entity`].
+    ///
+    /// See [`CellOption::change`].
+    fn change_copy(&self, records: &mut R, cfg: &mut C, entity: Entity);
+
+    /// See [`CellOption::hint_change`].
+    fn hint_change(&self) -> Option<Entity>;
+}
+
+impl<T, R, C> DynCellOption<R, C> for T
+where
+    T: CellOption<R, C> + Clone,
+{
+    fn change_copy(&self, records: &mut R, cfg: &mut C, entity: Entity) {
+        self.clone().change(records, cfg, entity)
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        CellOption::hint_change(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, R, C> CellOption<R, C> for Vec<T>
+where
+    T: CellOption<R, C>,
+{
+    fn change(self, records: &mut R, cfg: &mut C, entity: Entity) {
+        for opt in self {
+            opt.change(records, cfg, entity)
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl<R, C> CellOption<R, C> for String
 where
@@ -36,6 +36,55 @@ impl<F> FormatContent<F> {
         self.multiline = true;
         self
     }
+
+    /// Composes the formatting with a next one, so they're applied in a single pass over cells.
+    ///
+    /// The result is the same as of applying them one by one,
+    /// yet each cell is visited and measured once instead of once per formatting,
+    /// which makes a difference on big tables.
+    ///
+    /// [`FormatContent::multiline`] set before the call is kept for the previous functions.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{Format, object::Rows, Modify}};
+    ///
+    /// let format = Format::content(|s| s.trim().to_owned())
+    ///     .then(|s| s.to_uppercase())
+    ///     .then(|s| format!("[{}]", s));
+    ///
+    /// let table = Table::new([" a ", "b "])
+    ///     .with(Modify::new(Rows::new(1..)).with(format))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+------+\n\
+    ///      | &str |\n\
+    ///      +------+\n\
+    ///      | [A]  |\n\
+    ///      +------+\n\
+    ///      | [B]  |\n\
+    ///      +------+"
+    /// );
+    /// ```
+    pub fn then<G>(self, mut next: G) -> FormatContent<impl FnMut(&str) -> String + Clone>
+    where
+        F: FnMut(&str) -> String + Clone,
+        G: FnMut(&str) -> String + Clone,
+    {
+        let is_multiline = self.multiline;
+        let mut f = self.f;
+
+        FormatContent::new(move |text: &str| {
+            let text = if is_multiline {
+                multiline(f.clone())(text)
+            } else {
+                f(text)
+            };
+
+            next(&text)
+        })
+    }
 }
 
 impl<F, R, D, C> TableOption<R, C, D> for FormatContent<F>
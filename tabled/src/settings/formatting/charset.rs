@@ -1,5 +1,5 @@
 use crate::{
-    grid::config::Entity,
+    grid::config::{Border, Borders, ColoredConfig, Entity, HorizontalLine, VerticalLine},
     grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut},
     settings::{CellOption, TableOption},
 };
@@ -38,6 +38,63 @@ impl Charset {
     pub fn clean() -> CleanCharset {
         CleanCharset
     }
+
+    /// Returns [`InvisibleCharset`] which removes invisible characters,
+    /// like zero width spaces, BOMs and soft hyphens.
+    ///
+    /// A zero width joiner is kept inside an emoji sequence.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Table, settings::formatting::Charset};
+    ///
+    /// let mut table = Table::new(["\u{FEFF}BOM", "zero\u{200B}width"]);
+    /// table.with(Charset::strip_invisible());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-----------+\n\
+    ///      | &str      |\n\
+    ///      +-----------+\n\
+    ///      | BOM       |\n\
+    ///      +-----------+\n\
+    ///      | zerowidth |\n\
+    ///      +-----------+"
+    /// )
+    /// ```
+    pub fn strip_invisible() -> InvisibleCharset {
+        InvisibleCharset
+    }
+
+    /// Returns [`AsciiCharset`] which replaces border characters
+    /// by their closest ASCII equivalents (`─` by `-`, `│` by `|`, `┼` by `+` etc.).
+    ///
+    /// It's meant to be applied after a [`Style`] is set.
+    /// The content is left as is unless [`AsciiCharset::content`] is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{formatting::Charset, Style}};
+    ///
+    /// let mut table = Table::new(["Hello"]);
+    /// table.with(Style::rounded()).with(Charset::ascii());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+\n\
+    ///      | &str  |\n\
+    ///      +-------+\n\
+    ///      | Hello |\n\
+    ///      +-------+"
+    /// )
+    /// ```
+    ///
+    /// [`Style`]: crate::settings::Style
+    pub fn ascii() -> AsciiCharset {
+        AsciiCharset { content: None }
+    }
 }
 
 /// [`CleanCharset`] removes all `\t` and `\r` occurrences.
@@ -102,3 +159,287 @@ where
         }
     }
 }
+
+/// [`InvisibleCharset`] removes invisible characters,
+/// like zero width spaces, BOMs and soft hyphens.
+///
+/// A zero width joiner is kept inside an emoji sequence.
+#[derive(Debug, Default, Clone)]
+pub struct InvisibleCharset;
+
+impl<R, D, C> TableOption<R, C, D> for InvisibleCharset
+where
+    R: Records + ExactRecords + RecordsMut<String> + PeekableRecords,
+{
+    fn change(self, records: &mut R, _: &mut C, _: &mut D) {
+        for row in 0..records.count_rows() {
+            for col in 0..records.count_columns() {
+                let pos = (row, col);
+                let text = records.get_text(pos);
+                if let Some(text) = strip_invisible(text) {
+                    records.set(pos, text);
+                }
+            }
+        }
+    }
+}
+
+impl<R, C> CellOption<R, C> for InvisibleCharset
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, _: &mut C, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+        for pos in entity.iter(count_rows, count_cols) {
+            let text = records.get_text(pos);
+            if let Some(text) = strip_invisible(text) {
+                records.set(pos, text);
+            }
+        }
+    }
+}
+
+/// [`AsciiCharset`] replaces non ASCII border characters
+/// by their closest ASCII equivalents.
+///
+/// Optionally it replaces non ASCII characters of a content.
+///
+/// Can be created by [`Charset::ascii`].
+#[derive(Debug, Clone)]
+pub struct AsciiCharset<F = fn(char) -> Option<char>> {
+    content: Option<F>,
+}
+
+impl<F> AsciiCharset<F> {
+    /// Sets a function which is called for each non ASCII character of a content.
+    ///
+    /// The character is replaced by a returned one, or removed in case [`None`] returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{formatting::Charset, Style}};
+    ///
+    /// let mut table = Table::new(["Café", "naïve ☕"]);
+    /// table.with(Style::modern()).with(Charset::ascii().content(|c| match c {
+    ///     'é' => Some('e'),
+    ///     'ï' => Some('i'),
+    ///     _ => None,
+    /// }));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+--------+\n\
+    ///      | &str   |\n\
+    ///      +--------+\n\
+    ///      | Cafe   |\n\
+    ///      +--------+\n\
+    ///      | naive  |\n\
+    ///      +--------+"
+    /// )
+    /// ```
+    pub fn content<P>(self, replacer: P) -> AsciiCharset<P>
+    where
+        P: Fn(char) -> Option<char>,
+    {
+        AsciiCharset {
+            content: Some(replacer),
+        }
+    }
+}
+
+impl<R, D, F> TableOption<R, ColoredConfig, D> for AsciiCharset<F>
+where
+    R: Records + ExactRecords + RecordsMut<String> + PeekableRecords,
+    F: Fn(char) -> Option<char>,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        cfg_set_ascii_borders(cfg, (count_rows, count_columns));
+
+        if let Some(replacer) = self.content {
+            for row in 0..count_rows {
+                for col in 0..count_columns {
+                    let pos = (row, col);
+                    let text = records.get_text(pos);
+                    if !text.is_ascii() {
+                        let text = text
+                            .chars()
+                            .filter_map(|c| if c.is_ascii() { Some(c) } else { replacer(c) })
+                            .collect();
+                        records.set(pos, text);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn cfg_set_ascii_borders(cfg: &mut ColoredConfig, shape: (usize, usize)) {
+    let borders = ascii_borders(cfg.get_borders());
+    cfg.set_borders(borders);
+
+    if let Some(&c) = cfg.get_border_default() {
+        cfg.set_border_default(ascii_border_char(c));
+    }
+
+    let missing = cfg.get_borders_missing();
+    cfg.set_borders_missing(ascii_border_char(missing));
+
+    for (row, line) in cfg.get_horizontal_lines() {
+        let line = HorizontalLine {
+            main: line.main.map(ascii_border_char),
+            intersection: line.intersection.map(ascii_border_char),
+            left: line.left.map(ascii_border_char),
+            right: line.right.map(ascii_border_char),
+        };
+        cfg.insert_horizontal_line(row, line);
+    }
+
+    for (col, line) in cfg.get_vertical_lines() {
+        let line = VerticalLine {
+            main: line.main.map(ascii_border_char),
+            intersection: line.intersection.map(ascii_border_char),
+            top: line.top.map(ascii_border_char),
+            bottom: line.bottom.map(ascii_border_char),
+        };
+        cfg.insert_vertical_line(col, line);
+    }
+
+    cfg.map_overridden_chars(ascii_border_char);
+
+    // only cells with their own borders are left not converted by now
+    let (count_rows, count_columns) = shape;
+    for row in 0..count_rows {
+        for col in 0..count_columns {
+            let border = cfg.get_border((row, col), shape);
+            if !is_ascii_border(&border) {
+                cfg.set_border((row, col), ascii_border(border));
+            }
+        }
+    }
+
+    let mut margin = cfg.get_margin();
+    margin.left.fill = ascii_border_char(margin.left.fill);
+    margin.right.fill = ascii_border_char(margin.right.fill);
+    margin.top.fill = ascii_border_char(margin.top.fill);
+    margin.bottom.fill = ascii_border_char(margin.bottom.fill);
+    cfg.set_margin(margin);
+}
+
+fn ascii_borders(b: &Borders<char>) -> Borders<char> {
+    let c = |c: Option<char>| c.map(ascii_border_char);
+
+    Borders {
+        top: c(b.top),
+        top_left: c(b.top_left),
+        top_right: c(b.top_right),
+        top_intersection: c(b.top_intersection),
+        bottom: c(b.bottom),
+        bottom_left: c(b.bottom_left),
+        bottom_right: c(b.bottom_right),
+        bottom_intersection: c(b.bottom_intersection),
+        horizontal: c(b.horizontal),
+        vertical: c(b.vertical),
+        intersection: c(b.intersection),
+        left: c(b.left),
+        left_intersection: c(b.left_intersection),
+        right: c(b.right),
+        right_intersection: c(b.right_intersection),
+    }
+}
+
+fn ascii_border(b: Border<char>) -> Border<char> {
+    let c = |c: Option<char>| c.map(ascii_border_char);
+
+    Border {
+        top: c(b.top),
+        bottom: c(b.bottom),
+        left: c(b.left),
+        right: c(b.right),
+        left_top_corner: c(b.left_top_corner),
+        left_bottom_corner: c(b.left_bottom_corner),
+        right_top_corner: c(b.right_top_corner),
+        right_bottom_corner: c(b.right_bottom_corner),
+    }
+}
+
+fn is_ascii_border(b: &Border<char>) -> bool {
+    [
+        b.top,
+        b.bottom,
+        b.left,
+        b.right,
+        b.left_top_corner,
+        b.left_bottom_corner,
+        b.right_top_corner,
+        b.right_bottom_corner,
+    ]
+    .iter()
+    .flatten()
+    .all(char::is_ascii)
+}
+
+fn ascii_border_char(c: char) -> char {
+    match c {
+        c if c.is_ascii() => c,
+        '═' => '=',
+        '─' | '━' | '┄' | '┅' | '┈' | '┉' | '╌' | '╍' | '╴' | '╶' | '╸' | '╺' | '╼' | '╾' => {
+            '-'
+        }
+        '│' | '┃' | '║' | '┆' | '┇' | '┊' | '┋' | '╎' | '╏' | '╵' | '╷' | '╹' | '╻' | '╽' | '╿' => {
+            '|'
+        }
+        '\u{2500}'..='\u{257F}' => '+',
+        _ => '?',
+    }
+}
+
+const ZWJ: char = '\u{200D}';
+
+fn strip_invisible(text: &str) -> Option<String> {
+    if !text.chars().any(|c| c == ZWJ || is_invisible_char(c)) {
+        return None;
+    }
+
+    let mut buf = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ZWJ {
+            let prev = buf.chars().next_back();
+            let next = chars.peek().copied();
+            let is_emoji_sequence = matches!(prev, Some(c) if is_emoji_part(c))
+                && matches!(next, Some(c) if is_emoji(c));
+            if is_emoji_sequence {
+                buf.push(c);
+            }
+
+            continue;
+        }
+
+        if !is_invisible_char(c) {
+            buf.push(c);
+        }
+    }
+
+    Some(buf)
+}
+
+fn is_invisible_char(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}' | '\u{200B}' | '\u{200C}' | '\u{200E}' | '\u{200F}' | '\u{2060}' | '\u{FEFF}'
+    )
+}
+
+fn is_emoji(c: char) -> bool {
+    matches!(c, '\u{2600}'..='\u{27BF}' | '\u{2B00}'..='\u{2BFF}' | '\u{1F000}'..='\u{1FAFF}')
+}
+
+fn is_emoji_part(c: char) -> bool {
+    // a variation selector may follow an emoji before a joiner
+    is_emoji(c) || c == '\u{FE0F}'
+}
@@ -0,0 +1,56 @@
+use crate::{
+    grid::records::vec_records::{CellInfo, VecRecords},
+    settings::TableOption,
+};
+
+/// [`KeepTrailingNewline`] makes a trailing `\n` character of a cell start a new empty line.
+///
+/// By default a single trailing `\n` is not rendered,
+/// so `"a\n"` takes 1 line and `"\n\n"` takes 2 lines.
+/// The option brings back the behavior where each `\n` starts a new line.
+///
+/// It's applied to the current content of the cells,
+/// so a cell which is changed afterwards (for example by [`Format`]) uses the default behavior.
+/// Therefore it's better to use it as a last setting.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{builder::Builder, settings::formatting::KeepTrailingNewline};
+///
+/// let mut builder = Builder::default();
+/// builder.push_record(["name", "note"]);
+/// builder.push_record(["Tom\n", "-"]);
+///
+/// let mut table = builder.build();
+/// table.with(KeepTrailingNewline);
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+------+------+\n\
+///      | name | note |\n\
+///      +------+------+\n\
+///      | Tom  | -    |\n\
+///      |      |      |\n\
+///      +------+------+"
+/// )
+/// ```
+///
+/// [`Format`]: crate::settings::Format
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeepTrailingNewline;
+
+impl<C, D> TableOption<VecRecords<CellInfo<String>>, C, D> for KeepTrailingNewline {
+    fn change(self, records: &mut VecRecords<CellInfo<String>>, _: &mut C, _: &mut D) {
+        for row in records.iter_mut() {
+            for cell in row.iter_mut() {
+                if !cell.as_ref().ends_with('\n') {
+                    continue;
+                }
+
+                let text = std::mem::take(cell).into_inner();
+                *cell = CellInfo::with_trailing_newline(text);
+            }
+        }
+    }
+}
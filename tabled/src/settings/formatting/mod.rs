@@ -2,19 +2,28 @@
 //!
 //! - [`TrimStrategy`] and [`AlignmentStrategy`] allows to set [`Alignment`] settings.
 //! - [`TabSize`] sets a default tab size.
+//! - [`TrimLines`] removes blank lines of a cell.
+//! - [`KeepTrailingNewline`] makes a trailing `\n` of a cell start a new line.
 //! - [`Charset`] responsible for special char treatment.
 //! - [`Justification`] responsible for justification space of content.
+//! - [`MultilineAlignment`] responsible for a vertical position of content within a row.
 //!
 //! [`Alignment`]: crate::settings::Alignment
 
 mod alignment_strategy;
 mod charset;
 mod justification;
+mod keep_trailing_newline;
+mod multiline_alignment;
 mod tab_size;
+mod trim_lines;
 mod trim_strategy;
 
 pub use alignment_strategy::AlignmentStrategy;
-pub use charset::{Charset, CleanCharset};
+pub use charset::{AsciiCharset, Charset, CleanCharset, InvisibleCharset};
 pub use justification::Justification;
+pub use keep_trailing_newline::KeepTrailingNewline;
+pub use multiline_alignment::{MultilineAlignment, VAlignment};
 pub use tab_size::TabSize;
+pub use trim_lines::TrimLines;
 pub use trim_strategy::TrimStrategy;
@@ -0,0 +1,102 @@
+use crate::{
+    grid::config::{AlignmentVertical, ColoredConfig, Entity},
+    settings::{CellOption, TableOption},
+};
+
+/// A vertical position of content within a row.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VAlignment {
+    /// Content is placed at the top of a row.
+    Top,
+    /// Content is placed in the middle of a row.
+    Middle,
+    /// Content is placed at the bottom of a row.
+    Bottom,
+}
+
+/// [`MultilineAlignment`] controls how content is positioned vertically
+/// when a row is taller than the content of a cell.
+///
+/// A row gets taller when any other cell in it has more lines,
+/// or when its height is set explicitly, like by [`Height::list`].
+///
+/// By default content is aligned to the top.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{
+///     Table,
+///     settings::{formatting::{MultilineAlignment, VAlignment}, Height},
+/// };
+///
+/// let data = [["Multi\nline\ntext", "one"]];
+///
+/// let mut table = Table::new(data);
+/// table
+///     .with(Height::list([2, 3]))
+///     .with(MultilineAlignment(VAlignment::Bottom));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+-------+-----+\n\
+///      |       |     |\n\
+///      | 0     | 1   |\n\
+///      +-------+-----+\n\
+///      | Multi |     |\n\
+///      | line  |     |\n\
+///      | text  | one |\n\
+///      +-------+-----+"
+/// );
+/// ```
+///
+/// [`Height::list`]: crate::settings::Height::list
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct MultilineAlignment(pub VAlignment);
+
+impl MultilineAlignment {
+    /// Returns a vertical alignment which will be used.
+    pub fn vertical(&self) -> VAlignment {
+        self.0
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for MultilineAlignment {
+    fn change(self, _: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        cfg.set_alignment_vertical(Entity::Global, self.0.into());
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
+
+impl<R> CellOption<R, ColoredConfig> for MultilineAlignment {
+    fn change(self, _: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        cfg.set_alignment_vertical(entity, self.0.into());
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
+
+impl From<VAlignment> for AlignmentVertical {
+    fn from(value: VAlignment) -> Self {
+        match value {
+            VAlignment::Top => AlignmentVertical::Top,
+            VAlignment::Middle => AlignmentVertical::Center,
+            VAlignment::Bottom => AlignmentVertical::Bottom,
+        }
+    }
+}
+
+impl From<AlignmentVertical> for VAlignment {
+    fn from(value: AlignmentVertical) -> Self {
+        match value {
+            AlignmentVertical::Top => VAlignment::Top,
+            AlignmentVertical::Center => VAlignment::Middle,
+            AlignmentVertical::Bottom => VAlignment::Bottom,
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    grid::config::Entity,
+    grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut},
+    settings::{CellOption, TableOption},
+};
+
+/// [`TrimLines`] removes blank lines of a cell content.
+///
+/// A line is considered to be blank if it has only whitespace characters.
+///
+/// It changes the content itself, so a height of a row is calculated after trimming.
+/// A top and bottom [`Padding`] is not affected, it's applied to the trimmed content.
+///
+/// It can be applied to a whole table or to particular cells via [`Modify`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{builder::Builder, settings::formatting::TrimLines};
+///
+/// let mut builder = Builder::default();
+/// builder.push_record(["log", "level"]);
+/// builder.push_record(["\n\nconnection lost\n", "error"]);
+///
+/// let mut table = builder.build();
+/// table.with(TrimLines::top()).with(TrimLines::bottom());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+-----------------+-------+\n\
+///      | log             | level |\n\
+///      +-----------------+-------+\n\
+///      | connection lost | error |\n\
+///      +-----------------+-------+"
+/// )
+/// ```
+///
+/// [`Padding`]: crate::settings::Padding
+/// [`Modify`]: crate::settings::Modify
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TrimLines {
+    strategy: TrimLinesStrategy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum TrimLinesStrategy {
+    Top,
+    Bottom,
+    Collapse,
+}
+
+impl TrimLines {
+    /// Removes leading blank lines.
+    pub fn top() -> Self {
+        Self::new(TrimLinesStrategy::Top)
+    }
+
+    /// Removes trailing blank lines.
+    pub fn bottom() -> Self {
+        Self::new(TrimLinesStrategy::Bottom)
+    }
+
+    /// Squashes each run of blank lines into a single empty line.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::formatting::TrimLines};
+    ///
+    /// let mut table = Table::new(["first\n\n \n\nsecond"]);
+    /// table.with(TrimLines::collapse());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+--------+\n\
+    ///      | &str   |\n\
+    ///      +--------+\n\
+    ///      | first  |\n\
+    ///      |        |\n\
+    ///      | second |\n\
+    ///      +--------+"
+    /// )
+    /// ```
+    pub fn collapse() -> Self {
+        Self::new(TrimLinesStrategy::Collapse)
+    }
+
+    fn new(strategy: TrimLinesStrategy) -> Self {
+        Self { strategy }
+    }
+
+    fn trim(&self, text: &str) -> Option<String> {
+        let lines = text.split('\n').collect::<Vec<_>>();
+        let is_blank = |line: &str| line.trim().is_empty();
+
+        if lines.iter().all(|line| is_blank(line)) {
+            let is_trimmed = self.strategy == TrimLinesStrategy::Collapse && lines.len() == 1;
+            if text.is_empty() || is_trimmed {
+                return None;
+            }
+
+            return Some(String::new());
+        }
+
+        let lines = match self.strategy {
+            TrimLinesStrategy::Top => {
+                let start = lines.iter().position(|line| !is_blank(line))?;
+                if start == 0 {
+                    return None;
+                }
+
+                lines[start..].to_vec()
+            }
+            TrimLinesStrategy::Bottom => {
+                let end = lines.iter().rposition(|line| !is_blank(line))?;
+                if end + 1 == lines.len() {
+                    return None;
+                }
+
+                lines[..=end].to_vec()
+            }
+            TrimLinesStrategy::Collapse => {
+                let mut list: Vec<&str> = Vec::with_capacity(lines.len());
+                for line in lines {
+                    if !is_blank(line) {
+                        list.push(line);
+                        continue;
+                    }
+
+                    let is_run = list.last() == Some(&"");
+                    if !is_run {
+                        list.push("");
+                    }
+                }
+
+                list
+            }
+        };
+
+        Some(lines.join("\n"))
+    }
+}
+
+impl<R, D, C> TableOption<R, C, D> for TrimLines
+where
+    R: Records + ExactRecords + RecordsMut<String> + PeekableRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut C, _: &mut D) {
+        CellOption::change(self, records, cfg, Entity::Global);
+    }
+}
+
+impl<R, C> CellOption<R, C> for TrimLines
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, _: &mut C, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+        for pos in entity.iter(count_rows, count_cols) {
+            let text = records.get_text(pos);
+            if let Some(text) = self.trim(text) {
+                records.set(pos, text);
+            }
+        }
+    }
+}
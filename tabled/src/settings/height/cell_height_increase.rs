@@ -3,11 +3,10 @@ use crate::{
     grid::config::Entity,
     grid::dimension::CompleteDimensionVecRecords,
     grid::records::{ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut},
-    grid::util::string::count_lines,
     settings::{measurement::Measurement, peaker::Peaker, CellOption, Height, TableOption},
 };
 
-use super::TableHeightIncrease;
+use super::{util::get_rendered_text, TableHeightIncrease};
 
 /// A modification for cell/table to increase its height.
 ///
@@ -59,13 +58,12 @@ where
                 continue;
             }
 
-            let text = records.get_text(pos);
-
-            let cell_height = count_lines(text);
+            let cell_height = records.count_lines(pos);
             if cell_height >= height {
                 continue;
             }
 
+            let text = get_rendered_text(records, pos);
             let content = add_lines(text, height - cell_height);
             records.set(pos, content);
         }
@@ -95,9 +93,11 @@ where
 }
 
 fn add_lines(s: &str, n: usize) -> String {
-    let mut text = String::with_capacity(s.len() + n);
+    let mut text = String::with_capacity(s.len() + n + 1);
     text.push_str(s);
     text.extend(std::iter::repeat('\n').take(n));
+    // a last empty line needs its own '\n' to be rendered
+    text.push('\n');
 
     text
 }
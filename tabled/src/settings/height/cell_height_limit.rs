@@ -3,18 +3,24 @@ use crate::{
         config::{ColoredConfig, Entity},
         dimension::CompleteDimensionVecRecords,
         records::{ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut},
-        util::string::{count_lines, get_lines},
+        util::string::get_lines,
+    },
+    settings::{
+        measurement::Measurement,
+        peaker::{Peaker, PriorityMax},
+        CellOption, Height, TableOption,
     },
-    settings::{measurement::Measurement, peaker::Peaker, CellOption, Height, TableOption},
 };
 
 use super::table_height_limit::TableHeightLimit;
 
-/// A modification for cell/table to increase its height.
+/// A modification for cell/table to limit its height.
 ///
-/// If used for a [`Table`] [`PriorityNone`] is used.
+/// If used for a [`Table`] [`PriorityMax`] is used,
+/// so the tallest rows are shrunk first, starting from the bottom,
+/// and lines are cut from the end of a cell.
 ///
-/// [`PriorityNone`]: crate::settings::peaker::PriorityNone
+/// [`PriorityMax`]: crate::settings::peaker::PriorityMax
 /// [`Table`]: crate::Table
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CellHeightLimit<W = usize> {
@@ -59,13 +65,12 @@ where
                 continue;
             }
 
-            let text = records.get_text(pos);
-            let count_lines = count_lines(text);
-
+            let count_lines = records.count_lines(pos);
             if count_lines <= height {
                 continue;
             }
 
+            let text = records.get_text(pos);
             let content = limit_lines(text, height);
             records.set(pos, content);
         }
@@ -86,7 +91,9 @@ where
         dims: &mut CompleteDimensionVecRecords<'_>,
     ) {
         let height = self.height.measure(&*records, cfg);
-        TableHeightLimit::new(height).change(records, cfg, dims)
+        TableHeightLimit::new(height)
+            .priority::<PriorityMax>()
+            .change(records, cfg, dims)
     }
 }
 
@@ -100,5 +107,10 @@ fn limit_lines(s: &str, n: usize) -> String {
         text.push_str(&line);
     }
 
+    // a last empty line needs its own '\n' to be rendered
+    if text.ends_with('\n') {
+        text.push('\n');
+    }
+
     text
 }
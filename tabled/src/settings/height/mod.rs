@@ -164,12 +164,13 @@ impl Height {
     /// ];
     ///
     /// let table = Table::new(&data)
-    ///     .with(Height::limit(6))
+    ///     .with(Height::limit(7))
     ///     .to_string();
     ///
     /// assert_eq!(
     ///     table,
     ///     "+------+------+------------+\n\
+    ///      | &str | &str | &str       |\n\
     ///      +------+------+------------+\n\
     ///      | Some | here | and here   |\n\
     ///      +------+------+------------+\n\
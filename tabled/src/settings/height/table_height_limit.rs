@@ -1,9 +1,11 @@
+use std::cmp;
+
 use crate::{
     grid::{
-        config::ColoredConfig,
+        config::{ColoredConfig, Indent, Sides},
         dimension::CompleteDimensionVecRecords,
         records::{ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut},
-        util::string::{count_lines, get_lines},
+        util::string::get_lines,
     },
     settings::{
         measurement::Measurement,
@@ -73,20 +75,43 @@ where
             return;
         }
 
-        decrease_list(&mut heights, total, height, self.priority);
+        // content lines are cut first, while a row keeps at least 1 line and its padding,
+        // then padding is cut, and only then a row may be dropped completely
+        let padded_heights = (0..count_rows)
+            .map(|row| {
+                (0..count_cols)
+                    .map(|col| {
+                        let pad = cfg.get_padding((row, col).into());
+                        pad.top.size + pad.bottom.size + 1
+                    })
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+
+        let mut value = total;
+        for min in [padded_heights, vec![1; count_rows], vec![0; count_rows]] {
+            value = decrease_list(&mut heights, &min, value, height, self.priority.clone());
+        }
 
         for (row, &height) in heights.iter().enumerate() {
             for col in 0..count_cols {
-                let text = records.get_text((row, col));
-                let count_lines = count_lines(text);
+                let pos = (row, col);
+                let mut pad = cfg.get_padding(pos.into());
+                let height = fit_padding(&mut pad, height);
+                if pad != cfg.get_padding(pos.into()) {
+                    cfg.set_padding(pos.into(), pad);
+                }
 
+                let count_lines = records.count_lines(pos);
                 if count_lines <= height {
                     continue;
                 }
 
+                let text = records.get_text(pos);
                 let text = limit_lines(text, height);
 
-                records.set((row, col), text);
+                records.set(pos, text);
             }
         }
 
@@ -94,20 +119,50 @@ where
     }
 }
 
-fn decrease_list<P>(list: &mut [usize], total: usize, mut value: usize, mut peaker: P)
+/// Decreases rows not lower than a given minimum, returns a total height left.
+fn decrease_list<P>(
+    list: &mut [usize],
+    min: &[usize],
+    mut total: usize,
+    value: usize,
+    mut peaker: P,
+) -> usize
 where
     P: Peaker,
 {
-    while value != total {
-        let p = peaker.peak(&[], list);
-        let row = match p {
+    let mut available = list
+        .iter()
+        .zip(min)
+        .map(|(height, min)| height.saturating_sub(*min))
+        .collect::<Vec<_>>();
+
+    while total > value {
+        let row = match peaker.peak(&[], &available) {
             Some(row) => row,
             None => break,
         };
 
         list[row] -= 1;
-        value += 1;
+        available[row] -= 1;
+        total -= 1;
     }
+
+    total
+}
+
+/// Cuts a padding so a cell of a given height keeps at least 1 line of content,
+/// returns an amount of lines left for the content.
+fn fit_padding(pad: &mut Sides<Indent>, height: usize) -> usize {
+    let size = pad.top.size + pad.bottom.size;
+    if height > size {
+        return height - size;
+    }
+
+    let space = height.saturating_sub(1);
+    pad.top.size = cmp::min(pad.top.size, space);
+    pad.bottom.size = space - pad.top.size;
+
+    cmp::min(height, 1)
 }
 
 fn limit_lines(s: &str, n: usize) -> String {
@@ -120,5 +175,10 @@ fn limit_lines(s: &str, n: usize) -> String {
         text.push_str(&line);
     }
 
+    // a last empty line needs its own '\n' to be rendered
+    if text.ends_with('\n') {
+        text.push('\n');
+    }
+
     text
 }
@@ -1,23 +1,45 @@
 use crate::grid::{
-    config::SpannedConfig,
+    config::{Position, SpannedConfig},
     dimension::SpannedGridDimension,
-    records::{ExactRecords, IntoRecords, Records},
+    records::{vec_records::VecRecords, ExactRecords, PeekableRecords, Records},
+    util::string::count_lines,
 };
 
-pub(crate) fn get_table_height<R>(records: R, cfg: &SpannedConfig) -> (usize, Vec<usize>)
+pub(crate) fn get_table_height<R>(records: &R, cfg: &SpannedConfig) -> (usize, Vec<usize>)
 where
-    R: Records + ExactRecords,
-    <R::Iter as IntoRecords>::Cell: AsRef<str>,
+    R: Records + ExactRecords + PeekableRecords,
 {
-    let count_horizontals = cfg.count_horizontal(records.count_rows());
+    let count_rows = records.count_rows();
+    let count_columns = records.count_columns();
+    let count_horizontals = cfg.count_horizontal(count_rows);
 
     let margin = cfg.get_margin();
     let margin_size = margin.top.size + margin.bottom.size;
 
-    let list = SpannedGridDimension::height(records, cfg);
+    let data = (0..count_rows)
+        .map(|row| {
+            (0..count_columns)
+                .map(|col| get_rendered_text(records, (row, col)))
+                .collect()
+        })
+        .collect();
+
+    let list = SpannedGridDimension::height(&VecRecords::new(data), cfg);
     let total = list.iter().sum::<usize>();
 
     let total = total + count_horizontals + margin_size;
 
     (total, list)
 }
+
+/// Returns a text of a cell without a trailing '\n' if it's not rendered as a line.
+pub(crate) fn get_rendered_text<R>(records: &R, pos: Position) -> &str
+where
+    R: PeekableRecords,
+{
+    let text = records.get_text(pos);
+    match text.strip_suffix('\n') {
+        Some(content) if count_lines(text) > records.count_lines(pos) => content,
+        _ => text,
+    }
+}
@@ -9,8 +9,23 @@ use crate::{
 };
 
 /// The structure is an implementation of [`Location`] to search for cells with a specified condition.
-#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct ByCondition<F>(F);
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ByCondition<F> {
+    cond: F,
+    header: bool,
+}
+
+impl<F> Default for ByCondition<F>
+where
+    F: Default,
+{
+    fn default() -> Self {
+        Self {
+            cond: F::default(),
+            header: true,
+        }
+    }
+}
 
 impl<F> ByCondition<F> {
     /// Constructs a new object of the structure.
@@ -18,7 +33,18 @@ impl<F> ByCondition<F> {
     where
         F: Fn(&str) -> bool,
     {
-        Self(search)
+        Self {
+            cond: search,
+            header: true,
+        }
+    }
+
+    /// Sets whether the first row (a header) is searched through.
+    ///
+    /// It's searched by default.
+    pub fn header(mut self, on: bool) -> Self {
+        self.header = on;
+        self
     }
 }
 
@@ -32,10 +58,11 @@ where
 
     fn locate(&mut self, records: &R) -> Self::IntoIter {
         // todo: can be optimized by creating Iterator
-        let cond = &self.0;
+        let cond = &self.cond;
+        let skip = !self.header as usize;
 
         let mut out = vec![];
-        for row in 0..records.count_rows() {
+        for row in skip..records.count_rows() {
             for col in 0..records.count_columns() {
                 let text = records.get_text((row, col));
                 if cond(text) {
@@ -57,10 +84,11 @@ where
 
     fn cells(&self, records: &R) -> Self::Iter {
         // todo: can be optimized by creating Iterator
-        let cond = &self.0;
+        let cond = &self.cond;
+        let skip = !self.header as usize;
 
         let mut out = vec![];
-        for row in 0..records.count_rows() {
+        for row in skip..records.count_rows() {
             for col in 0..records.count_columns() {
                 let text = records.get_text((row, col));
                 if cond(text) {
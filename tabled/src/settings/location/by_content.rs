@@ -4,13 +4,63 @@ use crate::{
         config::Position,
         records::{ExactRecords, PeekableRecords, Records},
     },
-    settings::location::Location,
+    settings::location::{ByCondition, ByRowCondition, Location},
     settings::object::Object,
 };
 
 /// The structure is an implementation of [`Location`] to search for cells with a given content.
-#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct ByContent<S>(S);
+///
+/// Cells are matched against the content records have at the moment a setting is applied,
+/// so it takes into account changes made by previous settings.
+///
+/// It also has constructors of other content searchers,
+/// like [`ByContent::matches`] and [`ByContent::row_where`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{
+///     settings::{location::ByContent, Modify},
+///     Table,
+/// };
+///
+/// let data = [["build", "OK"], ["test", "FAILED"], ["lint", "FAILED"]];
+///
+/// let mut table = Table::new(data);
+/// table
+///     .with(Modify::new(ByContent::eq("FAILED")).with("FAIL"))
+///     .with(Modify::new(ByContent::row_where(|text| text == "FAIL").header(false)).with("-"));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+-------+----+\n\
+///      | 0     | 1  |\n\
+///      +-------+----+\n\
+///      | build | OK |\n\
+///      +-------+----+\n\
+///      | -     | -  |\n\
+///      +-------+----+\n\
+///      | -     | -  |\n\
+///      +-------+----+"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ByContent<S> {
+    text: S,
+    header: bool,
+}
+
+impl<S> Default for ByContent<S>
+where
+    S: Default,
+{
+    fn default() -> Self {
+        Self {
+            text: S::default(),
+            header: true,
+        }
+    }
+}
 
 impl<S> ByContent<S> {
     /// Constructs a new object of the structure.
@@ -18,7 +68,55 @@ impl<S> ByContent<S> {
     where
         S: AsRef<str>,
     {
-        Self(text)
+        Self { text, header: true }
+    }
+
+    /// Constructs a searcher for cells which are equal to a given text.
+    ///
+    /// The same as [`ByContent::new`].
+    pub fn eq(text: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        Self::new(text)
+    }
+
+    /// Sets whether the first row (a header) is searched through.
+    ///
+    /// It's searched by default.
+    pub fn header(mut self, on: bool) -> Self {
+        self.header = on;
+        self
+    }
+}
+
+impl ByContent<&'static str> {
+    /// Constructs a searcher for cells which satisfy a given predicate.
+    ///
+    /// See [`ByCondition`].
+    pub fn matches<F>(predicate: F) -> ByCondition<F>
+    where
+        F: Fn(&str) -> bool,
+    {
+        ByCondition::new(predicate)
+    }
+
+    /// Constructs a searcher for cells which start with a given prefix.
+    pub fn starts_with<P>(prefix: P) -> ByCondition<impl Fn(&str) -> bool>
+    where
+        P: AsRef<str>,
+    {
+        ByCondition::new(move |text: &str| text.starts_with(prefix.as_ref()))
+    }
+
+    /// Constructs a searcher for whole rows which have a cell satisfying a given predicate.
+    ///
+    /// See [`ByRowCondition`].
+    pub fn row_where<F>(predicate: F) -> ByRowCondition<F>
+    where
+        F: Fn(&str) -> bool,
+    {
+        ByRowCondition::new(predicate)
     }
 }
 
@@ -32,10 +130,11 @@ where
 
     fn locate(&mut self, records: &R) -> Self::IntoIter {
         // todo: can be optimized by creating Iterator
-        let text = self.0.as_ref();
+        let text = self.text.as_ref();
+        let skip = !self.header as usize;
 
         let mut out = vec![];
-        for row in 0..records.count_rows() {
+        for row in skip..records.count_rows() {
             for col in 0..records.count_columns() {
                 let cell = records.get_text((row, col));
                 if cell.eq(text) {
@@ -57,10 +156,11 @@ where
 
     fn cells(&self, records: &R) -> Self::Iter {
         // todo: can be optimized by creating Iterator
-        let text = self.0.as_ref();
+        let text = self.text.as_ref();
+        let skip = !self.header as usize;
 
         let mut out = vec![];
-        for row in 0..records.count_rows() {
+        for row in skip..records.count_rows() {
             for col in 0..records.count_columns() {
                 let cell = records.get_text((row, col));
                 if cell.eq(text) {
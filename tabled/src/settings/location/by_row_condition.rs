@@ -0,0 +1,83 @@
+use crate::{
+    grid::config::Entity,
+    grid::records::{ExactRecords, PeekableRecords, Records},
+    settings::location::Location,
+    settings::object::Object,
+};
+
+/// The structure is an implementation of [`Location`] to search for rows
+/// which have at least one cell with a specified condition.
+///
+/// As an [`Object`] it selects whole rows.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ByRowCondition<F> {
+    cond: F,
+    header: bool,
+}
+
+impl<F> ByRowCondition<F> {
+    /// Constructs a new object of the structure.
+    pub fn new(search: F) -> Self
+    where
+        F: Fn(&str) -> bool,
+    {
+        Self {
+            cond: search,
+            header: true,
+        }
+    }
+
+    /// Sets whether the first row (a header) is searched through.
+    ///
+    /// It's searched by default.
+    pub fn header(mut self, on: bool) -> Self {
+        self.header = on;
+        self
+    }
+}
+
+impl<F> ByRowCondition<F>
+where
+    F: Fn(&str) -> bool,
+{
+    fn find_rows<R>(&self, records: &R) -> Vec<usize>
+    where
+        R: Records + ExactRecords + PeekableRecords,
+    {
+        let skip = !self.header as usize;
+        let count_columns = records.count_columns();
+
+        (skip..records.count_rows())
+            .filter(|&row| (0..count_columns).any(|col| (self.cond)(records.get_text((row, col)))))
+            .collect()
+    }
+}
+
+impl<F, R> Location<R> for ByRowCondition<F>
+where
+    F: Fn(&str) -> bool,
+    R: Records + ExactRecords + PeekableRecords,
+{
+    type Coordinate = usize;
+    type IntoIter = Vec<usize>;
+
+    fn locate(&mut self, records: &R) -> Self::IntoIter {
+        self.find_rows(records)
+    }
+}
+
+impl<F, R> Object<R> for ByRowCondition<F>
+where
+    F: Fn(&str) -> bool,
+    R: Records + ExactRecords + PeekableRecords,
+{
+    type Iter = std::vec::IntoIter<Entity>;
+
+    fn cells(&self, records: &R) -> Self::Iter {
+        self.find_rows(records)
+            .into_iter()
+            .map(Entity::Row)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
@@ -60,22 +60,27 @@
 mod by_column_name;
 mod by_condition;
 mod by_content;
+mod by_row_condition;
 mod locator;
 
 pub use by_column_name::ByColumnName;
 pub use by_condition::ByCondition;
 pub use by_content::ByContent;
+pub use by_row_condition::ByRowCondition;
 pub use locator::Locator;
 
-use core::ops::Bound;
 use std::{
     iter::{self, Once},
     ops::{Range, RangeBounds},
 };
 
 use crate::{
+    grid::config::Entity,
     grid::records::{ExactRecords, Records},
-    settings::object::{Column, Columns, FirstColumn, FirstRow, LastColumn, LastRow, Row, Rows},
+    settings::object::{
+        Column, Columns, FirstColumn, FirstRow, LastColumn, LastColumnOffset, LastRow,
+        LastRowOffset, Object, Row, Rows,
+    },
 };
 
 /// Location is an interface which searches for a particular thing in the [`Records`],
@@ -100,9 +105,7 @@ where
     type IntoIter = Range<usize>;
 
     fn locate(&mut self, records: &R) -> Self::IntoIter {
-        let range = self.get_range();
-        let max = records.count_columns();
-        let (from, to) = bounds_to_usize(range.start_bound(), range.end_bound(), max);
+        let (from, to) = self.get_bounds(records.count_columns());
 
         from..to
     }
@@ -142,20 +145,31 @@ where
     }
 }
 
+impl<R> Location<R> for LastColumnOffset
+where
+    R: Records + ExactRecords,
+{
+    type Coordinate = usize;
+    type IntoIter = Option<usize>;
+
+    fn locate(&mut self, records: &R) -> Self::IntoIter {
+        self.cells(records).next().map(|entity| match entity {
+            Entity::Column(col) => col,
+            _ => unreachable!(),
+        })
+    }
+}
+
 impl<B, R> Location<R> for Rows<B>
 where
-    R: Records,
+    R: ExactRecords,
     B: RangeBounds<usize>,
 {
     type Coordinate = usize;
     type IntoIter = Range<usize>;
 
     fn locate(&mut self, records: &R) -> Self::IntoIter {
-        let (from, to) = bounds_to_usize(
-            self.get_range().start_bound(),
-            self.get_range().end_bound(),
-            records.count_columns(),
-        );
+        let (from, to) = self.get_bounds(records.count_rows());
 
         from..to
     }
@@ -195,23 +209,18 @@ where
     }
 }
 
-fn bounds_to_usize(
-    left: Bound<&usize>,
-    right: Bound<&usize>,
-    count_elements: usize,
-) -> (usize, usize) {
-    match (left, right) {
-        (Bound::Included(x), Bound::Included(y)) => (*x, y + 1),
-        (Bound::Included(x), Bound::Excluded(y)) => (*x, *y),
-        (Bound::Included(x), Bound::Unbounded) => (*x, count_elements),
-        (Bound::Unbounded, Bound::Unbounded) => (0, count_elements),
-        (Bound::Unbounded, Bound::Included(y)) => (0, y + 1),
-        (Bound::Unbounded, Bound::Excluded(y)) => (0, *y),
-        (Bound::Excluded(_), Bound::Unbounded)
-        | (Bound::Excluded(_), Bound::Included(_))
-        | (Bound::Excluded(_), Bound::Excluded(_)) => {
-            unreachable!("A start bound can't be excluded")
-        }
+impl<R> Location<R> for LastRowOffset
+where
+    R: Records + ExactRecords,
+{
+    type Coordinate = usize;
+    type IntoIter = Option<usize>;
+
+    fn locate(&mut self, records: &R) -> Self::IntoIter {
+        self.cells(records).next().map(|entity| match entity {
+            Entity::Row(row) => row,
+            _ => unreachable!(),
+        })
     }
 }
 
@@ -227,6 +236,61 @@ mod tests {
 
     use Entity::*;
 
+    #[test]
+    fn object_by_content_header_test() {
+        let data = vec![vec![1, 2], vec![2, 1], vec![3, 4]];
+
+        assert_eq!(cells(by_content("1"), &data), [Cell(0, 0), Cell(1, 1)]);
+        assert_eq!(cells(by_content("1").header(false), &data), [Cell(1, 1)]);
+        assert_eq!(cells(by_content("5").header(false), &data), []);
+
+        assert_eq!(
+            cells(ByContent::matches(|text| text != "1"), &data),
+            [Cell(0, 1), Cell(1, 0), Cell(2, 0), Cell(2, 1)]
+        );
+        assert_eq!(
+            cells(ByContent::matches(|text| text != "1").header(false), &data),
+            [Cell(1, 0), Cell(2, 0), Cell(2, 1)]
+        );
+    }
+
+    #[test]
+    fn object_by_content_starts_with_test() {
+        let data = vec![vec![10, 2], vec![21, 1], vec![3, 14]];
+
+        assert_eq!(
+            cells(ByContent::starts_with("1"), &data),
+            [Cell(0, 0), Cell(1, 1), Cell(2, 1)]
+        );
+        assert_eq!(
+            cells(
+                ByContent::starts_with(String::from("2")).header(false),
+                &data
+            ),
+            [Cell(1, 0)]
+        );
+        assert_eq!(cells(ByContent::starts_with("5"), &data), []);
+    }
+
+    #[test]
+    fn object_by_content_row_where_test() {
+        let data = vec![vec![1, 2], vec![2, 3], vec![3, 1], vec![4, 4]];
+
+        assert_eq!(
+            cells(ByContent::row_where(|text| text == "1"), &data),
+            [Row(0), Row(2)]
+        );
+        assert_eq!(
+            cells(
+                ByContent::row_where(|text| text == "1").header(false),
+                &data
+            ),
+            [Row(2)]
+        );
+        assert_eq!(cells(ByContent::row_where(|text| text == "5"), &data), []);
+        assert_eq!(cells(ByContent::row_where(|_| true), &[]), []);
+    }
+
     #[test]
     fn object_by_column_name_test() {
         let data = [
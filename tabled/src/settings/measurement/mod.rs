@@ -4,7 +4,7 @@ use crate::{
     grid::config::SpannedConfig,
     grid::dimension::SpannedGridDimension,
     grid::records::{ExactRecords, IntoRecords, PeekableRecords, Records},
-    grid::util::string::{self, string_width_multiline},
+    grid::util::string::string_width_multiline,
     settings::{Height, Width},
 };
 
@@ -128,7 +128,6 @@ where
     R: Records + ExactRecords + PeekableRecords,
 {
     (0..records.count_rows()).map(move |row| {
-        (0..records.count_columns())
-            .map(move |col| string::count_lines(records.get_text((row, col))))
+        (0..records.count_columns()).map(move |col| records.count_lines((row, col)))
     })
 }
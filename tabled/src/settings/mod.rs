@@ -109,12 +109,15 @@ pub mod span;
 pub mod split;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod summary;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod themes;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod width;
 
-pub use cell_option::CellOption;
+pub use cell_option::{CellOption, DynCellOption};
 pub use settings_list::{EmptySettings, Settings};
 pub use table_option::TableOption;
 
@@ -126,6 +129,7 @@ pub use self::{
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use self::{
+    alignment::{AutoAlign, HeaderAlign},
     color::Color,
     concat::Concat,
     disable::Disable,
@@ -136,6 +140,7 @@ pub use self::{
     merge::Merge,
     modify::{Modify, ModifyList},
     panel::Panel,
+    rotate::RotateCell,
     shadow::Shadow,
     span::Span,
     themes::Theme,
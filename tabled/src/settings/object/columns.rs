@@ -14,6 +14,7 @@ use super::util::bounds_to_usize;
 #[derive(Debug)]
 pub struct Columns<R> {
     range: R,
+    except_last: usize,
 }
 
 impl<R> Columns<R> {
@@ -24,11 +25,38 @@ impl<R> Columns<R> {
     where
         R: RangeBounds<usize>,
     {
-        Self { range }
+        Self {
+            range,
+            except_last: 0,
+        }
     }
 
-    pub(crate) fn get_range(&self) -> &R {
-        &self.range
+    /// Excludes a given amount of last columns from the range.
+    ///
+    /// It's resolved against an actual amount of columns,
+    /// so if there's not enough columns the object will produce no cells.
+    pub fn except_last(mut self, count: usize) -> Self {
+        self.except_last = count;
+        self
+    }
+
+    /// Returns a range of indexes for a given amount of columns.
+    pub(crate) fn get_bounds(&self, count: usize) -> (usize, usize)
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = self.range.start_bound();
+        let end = self.range.end_bound();
+        let (x, y) = bounds_to_usize(start, end, count);
+
+        if self.except_last == 0 {
+            return (x, y);
+        }
+
+        let y = std::cmp::min(y, count).saturating_sub(self.except_last);
+        let x = std::cmp::min(x, y);
+
+        (x, y)
     }
 }
 
@@ -53,6 +81,14 @@ impl Columns<()> {
     pub fn last() -> LastColumn {
         LastColumn
     }
+
+    /// Returns a new instance of [`Columns`] for a column located by an offset from the last column.
+    ///
+    /// `last_nth(0)` is the last column, `last_nth(1)` is the one before it and so on.
+    /// If the boundaries are exceeded the object will produce no cells.
+    pub fn last_nth(offset: usize) -> LastColumnOffset {
+        LastColumnOffset { offset }
+    }
 }
 
 impl<I, R> Object<I> for Columns<R>
@@ -63,10 +99,7 @@ where
     type Iter = ColumnsIter;
 
     fn cells(&self, records: &I) -> Self::Iter {
-        let max = records.count_columns();
-        let start = self.range.start_bound();
-        let end = self.range.end_bound();
-        let (x, y) = bounds_to_usize(start, end, max);
+        let (x, y) = self.get_bounds(records.count_columns());
 
         ColumnsIter::new(x, y)
     }
@@ -152,7 +185,7 @@ impl From<Column> for usize {
 }
 
 /// `LastColumnOffset` represents a single column on a grid indexed via offset from the last column.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct LastColumnOffset {
     offset: usize,
 }
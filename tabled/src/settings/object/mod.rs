@@ -534,6 +534,33 @@ mod tests {
         assert_eq!(vec_cells(Columns::last() - 100, 5, 2), []);
     }
 
+    #[test]
+    fn last_nth_column_test() {
+        assert_eq!(vec_cells(Columns::last_nth(0), 5, 2), [Entity::Column(1)]);
+        assert_eq!(vec_cells(Columns::last_nth(1), 5, 2), [Entity::Column(0)]);
+        assert_eq!(vec_cells(Columns::last_nth(3), 5, 2), []);
+        assert_eq!(vec_cells(Columns::last_nth(1), 0, 0), []);
+    }
+
+    #[test]
+    fn columns_except_last_test() {
+        assert_eq!(
+            vec_cells(Columns::new(1..).except_last(1), 2, 4),
+            [Entity::Column(1), Entity::Column(2)]
+        );
+        assert_eq!(
+            vec_cells(Columns::new(..).except_last(2), 2, 3),
+            [Entity::Column(0)]
+        );
+        assert_eq!(
+            vec_cells(Columns::new(..10).except_last(2), 2, 3),
+            [Entity::Column(0)]
+        );
+        assert_eq!(vec_cells(Columns::new(1..).except_last(2), 2, 3), []);
+        assert_eq!(vec_cells(Columns::new(1..).except_last(10), 2, 3), []);
+        assert_eq!(vec_cells(Columns::new(..).except_last(1), 0, 0), []);
+    }
+
     #[test]
     fn first_column_add_test() {
         assert_eq!(vec_cells(Columns::first(), 5, 2), [Entity::Column(0)]);
@@ -598,6 +625,28 @@ mod tests {
         assert_eq!(vec_cells(Rows::last() - 1, 0, 2), []);
     }
 
+    #[test]
+    fn last_nth_row_test() {
+        assert_eq!(vec_cells(Rows::last_nth(0), 5, 2), [Entity::Row(4)]);
+        assert_eq!(vec_cells(Rows::last_nth(1), 5, 2), [Entity::Row(3)]);
+        assert_eq!(vec_cells(Rows::last_nth(5), 5, 2), []);
+        assert_eq!(vec_cells(Rows::last_nth(1), 0, 0), []);
+    }
+
+    #[test]
+    fn rows_except_last_test() {
+        assert_eq!(
+            vec_cells(Rows::new(1..).except_last(2), 5, 2),
+            [Entity::Row(1), Entity::Row(2)]
+        );
+        assert_eq!(
+            vec_cells(Rows::new(..).except_last(4), 5, 2),
+            [Entity::Row(0)]
+        );
+        assert_eq!(vec_cells(Rows::new(1..).except_last(4), 5, 2), []);
+        assert_eq!(vec_cells(Rows::new(1..).except_last(2), 0, 0), []);
+    }
+
     #[test]
     fn first_row_add_test() {
         assert_eq!(vec_cells(Rows::first(), 5, 2), [Entity::Row(0)]);
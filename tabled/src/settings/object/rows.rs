@@ -14,6 +14,7 @@ use super::util::bounds_to_usize;
 #[derive(Debug)]
 pub struct Rows<R> {
     range: R,
+    except_last: usize,
 }
 
 impl<R> Rows<R> {
@@ -24,11 +25,38 @@ impl<R> Rows<R> {
     where
         R: RangeBounds<usize>,
     {
-        Self { range }
+        Self {
+            range,
+            except_last: 0,
+        }
     }
 
-    pub(crate) const fn get_range(&self) -> &R {
-        &self.range
+    /// Excludes a given amount of last rows from the range.
+    ///
+    /// It's resolved against an actual amount of rows,
+    /// so if there's not enough rows the object will produce no cells.
+    pub fn except_last(mut self, count: usize) -> Self {
+        self.except_last = count;
+        self
+    }
+
+    /// Returns a range of indexes for a given amount of rows.
+    pub(crate) fn get_bounds(&self, count: usize) -> (usize, usize)
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = self.range.start_bound();
+        let end = self.range.end_bound();
+        let (x, y) = bounds_to_usize(start, end, count);
+
+        if self.except_last == 0 {
+            return (x, y);
+        }
+
+        let y = std::cmp::min(y, count).saturating_sub(self.except_last);
+        let x = std::cmp::min(x, y);
+
+        (x, y)
     }
 }
 
@@ -53,6 +81,14 @@ impl Rows<()> {
     pub const fn last() -> LastRow {
         LastRow
     }
+
+    /// Returns a row [`Object`] located by an offset from the last row.
+    ///
+    /// `last_nth(0)` is the last row, `last_nth(1)` is the one before it and so on.
+    /// If the boundaries are exceeded the object will produce no cells.
+    pub const fn last_nth(offset: usize) -> LastRowOffset {
+        LastRowOffset { offset }
+    }
 }
 
 impl<I, R> Object<I> for Rows<R>
@@ -63,10 +99,7 @@ where
     type Iter = RowsIter;
 
     fn cells(&self, records: &I) -> Self::Iter {
-        let start = self.range.start_bound();
-        let end = self.range.end_bound();
-        let max = records.count_rows();
-        let (x, y) = bounds_to_usize(start, end, max);
+        let (x, y) = self.get_bounds(records.count_rows());
 
         RowsIter::new(x, y)
     }
@@ -161,7 +194,7 @@ impl Sub<usize> for LastRow {
 }
 
 /// A row which is located by an offset from the last row.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct LastRowOffset {
     offset: usize,
 }
@@ -0,0 +1,200 @@
+use crate::{
+    grid::{
+        config::{AlignmentHorizontal, ColoredConfig, Entity, SpannedConfig},
+        util::string::{string_width, string_width_multiline},
+    },
+    settings::{width::Wrap, Alignment, TableOption},
+};
+
+/// A position of a [`Caption`] relative to a table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CaptionPosition {
+    /// A caption is placed above the top border.
+    Above,
+    /// A caption is placed below the bottom border.
+    Below,
+}
+
+/// [`Caption`] adds a line of text above or below a table, outside of its borders.
+///
+/// A caption is not a part of a grid, so it doesn't change the amount of rows,
+/// and it's rendered once a table is printed.
+/// A caption longer than the table is wrapped by words to the table width.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{
+///     Table,
+///     settings::{panel::{Caption, CaptionPosition}, Alignment, Style},
+/// };
+///
+/// let data = [("Apple", 3), ("Pineapple", 15)];
+///
+/// let mut table = Table::new(data);
+/// table
+///     .with(Style::modern())
+///     .with(
+///         Caption::new("Table 1. Fruits")
+///             .position(CaptionPosition::Below)
+///             .alignment(Alignment::center()),
+///     );
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "┌───────────┬─────┐\n\
+///      │ &str      │ i32 │\n\
+///      ├───────────┼─────┤\n\
+///      │ Apple     │ 3   │\n\
+///      ├───────────┼─────┤\n\
+///      │ Pineapple │ 15  │\n\
+///      └───────────┴─────┘\n  \
+///       Table 1. Fruits  "
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Caption {
+    text: String,
+    position: CaptionPosition,
+    alignment: Alignment,
+}
+
+impl Caption {
+    /// Creates a [`Caption`] above a table aligned to the left.
+    pub fn new<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            text: text.into(),
+            position: CaptionPosition::Above,
+            alignment: Alignment::left(),
+        }
+    }
+
+    /// Creates a [`Caption`] above a table aligned to the left.
+    ///
+    /// The same as [`Caption::new`].
+    pub fn top<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(text)
+    }
+
+    /// Creates a [`Caption`] below a table aligned to the left.
+    ///
+    /// It's handy for a note like `updated 2024-05-01`.
+    pub fn bottom<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(text).position(CaptionPosition::Below)
+    }
+
+    /// Sets a position of a caption.
+    pub fn position(mut self, position: CaptionPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets a horizontal alignment of a caption.
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for Caption {
+    fn change(self, _: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let _ = cfg.push_caption(self);
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
+
+/// Renders lines of captions above and below a table of a given width.
+///
+/// A caption is aligned within the table borders, and it's surrounded by a margin.
+pub(crate) fn caption_lines(
+    captions: &[Caption],
+    cfg: &SpannedConfig,
+    count_columns: usize,
+    total_width: usize,
+) -> (Vec<String>, Vec<String>) {
+    let margin = cfg.get_margin();
+    let has_left = cfg.has_vertical(0, count_columns);
+    let has_right = cfg.has_vertical(count_columns, count_columns);
+    let width = total_width
+        .saturating_sub(margin.left.size + margin.right.size)
+        .saturating_sub(has_left as usize + has_right as usize);
+
+    let mut prefix = margin.left.fill.to_string().repeat(margin.left.size);
+    if has_left {
+        prefix.push(' ');
+    }
+
+    let mut suffix = margin.right.fill.to_string().repeat(margin.right.size);
+    if has_right {
+        suffix.insert(0, ' ');
+    }
+
+    let mut above = Vec::new();
+    let mut below = Vec::new();
+
+    // a caption which is added later is put further from a table
+    for caption in captions.iter().rev() {
+        if caption.position == CaptionPosition::Above {
+            above.extend(render_caption(caption, width, &prefix, &suffix));
+        }
+    }
+
+    for caption in captions {
+        if caption.position == CaptionPosition::Below {
+            below.extend(render_caption(caption, width, &prefix, &suffix));
+        }
+    }
+
+    (above, below)
+}
+
+fn render_caption(caption: &Caption, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
+    let alignment =
+        Option::<AlignmentHorizontal>::from(caption.alignment).unwrap_or(AlignmentHorizontal::Left);
+
+    wrap_caption(&caption.text, width)
+        .split('\n')
+        .map(|line| {
+            let available = width.saturating_sub(string_width(line));
+            let (left, right) = match alignment {
+                AlignmentHorizontal::Left => (0, available),
+                AlignmentHorizontal::Right => (available, 0),
+                AlignmentHorizontal::Center => (available / 2, available - available / 2),
+            };
+
+            format!(
+                "{}{}{}{}{}",
+                prefix,
+                " ".repeat(left),
+                line,
+                " ".repeat(right),
+                suffix
+            )
+        })
+        .collect()
+}
+
+/// Wraps a caption by words,
+/// trimming the trailing spaces so the alignment could be applied to each line.
+fn wrap_caption(text: &str, width: usize) -> String {
+    if width == 0 || string_width_multiline(text) <= width {
+        return text.to_owned();
+    }
+
+    let text = Wrap::wrap_text(text, width, true);
+    let lines = text.lines().map(str::trim_end).collect::<Vec<_>>();
+
+    lines.join("\n")
+}
@@ -0,0 +1,128 @@
+use std::ops::Range;
+
+use crate::{
+    builder::ColumnGroupError,
+    grid::config::{AlignmentHorizontal, ColoredConfig},
+    grid::records::{ExactRecords, Records, RecordsMut, Resizable},
+    settings::TableOption,
+};
+
+use super::horizontal_panel::{move_row_spans, move_rows_aside};
+
+/// [`ColumnGroups`] inserts a row at the top of a table
+/// with labels which span a range of columns.
+///
+/// Labels are center aligned within their groups.
+/// Columns which are not grouped get an empty cell.
+///
+/// Ranges refer to columns of the table the setting is applied to,
+/// so an index column added by [`Builder::index`] must be counted as well.
+/// Empty ranges, ranges which exceed the amount of columns
+/// and ranges which overlap with a previous one are ignored
+/// (the same ranges which are rejected by [`Builder::set_column_groups`]).
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::panel::ColumnGroups};
+///
+/// let data = [["eth0", "1.2", "0.3", "12", "44"]];
+///
+/// let mut table = Table::new(data);
+/// table.with(ColumnGroups::new([("Network", 1..3), ("Disk", 3..5)]));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+------+-----+-----+----+----+\n\
+///      |      |  Network  |  Disk   |\n\
+///      +------+-----+-----+----+----+\n\
+///      | 0    | 1   | 2   | 3  | 4  |\n\
+///      +------+-----+-----+----+----+\n\
+///      | eth0 | 1.2 | 0.3 | 12 | 44 |\n\
+///      +------+-----+-----+----+----+"
+/// );
+/// ```
+///
+/// [`Builder::index`]: crate::builder::Builder::index
+/// [`Builder::set_column_groups`]: crate::builder::Builder::set_column_groups
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnGroups {
+    groups: Vec<(Range<usize>, String)>,
+}
+
+impl ColumnGroups {
+    /// Creates a new [`ColumnGroups`] from a list of labels and column ranges.
+    pub fn new<I, S>(groups: I) -> Self
+    where
+        I: IntoIterator<Item = (S, Range<usize>)>,
+        S: Into<String>,
+    {
+        let groups = groups
+            .into_iter()
+            .map(|(label, range)| (range, label.into()))
+            .collect();
+
+        Self::from_groups(groups)
+    }
+
+    /// Creates a new [`ColumnGroups`] from groups set by a [`Builder`].
+    ///
+    /// [`Builder`]: crate::builder::Builder
+    pub(crate) fn from_groups(groups: Vec<(Range<usize>, String)>) -> Self {
+        Self { groups }
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for ColumnGroups
+where
+    R: Records + ExactRecords + Resizable + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_columns = records.count_columns();
+        if count_columns == 0 {
+            return;
+        }
+
+        let mut groups = Vec::with_capacity(self.groups.len());
+        for (range, label) in self.groups {
+            if check_column_group(&groups, &range, count_columns).is_ok() {
+                groups.push((range, label));
+            }
+        }
+
+        if groups.is_empty() {
+            return;
+        }
+
+        move_rows_aside(records, 0);
+        move_row_spans(cfg, 0);
+
+        for (range, label) in groups {
+            let pos = (0, range.start);
+            records.set(pos, label);
+            cfg.set_column_span(pos, range.len());
+            cfg.set_alignment_horizontal(pos.into(), AlignmentHorizontal::Center);
+        }
+    }
+}
+
+/// Checks that a range is not empty, fits the amount of columns
+/// and doesn't overlap with any of the previous groups.
+pub(crate) fn check_column_group(
+    groups: &[(Range<usize>, String)],
+    range: &Range<usize>,
+    count_columns: usize,
+) -> Result<(), ColumnGroupError> {
+    if range.start >= range.end || range.end > count_columns {
+        return Err(ColumnGroupError::OutOfBounds(range.clone()));
+    }
+
+    let overlap = groups
+        .iter()
+        .find(|(other, _)| range.start < other.end && other.start < range.end);
+
+    match overlap {
+        Some((other, _)) => Err(ColumnGroupError::Overlap(other.clone(), range.clone())),
+        None => Ok(()),
+    }
+}
@@ -47,7 +47,7 @@ where
     }
 }
 
-fn move_rows_aside<R: ExactRecords + Resizable>(records: &mut R, row: usize) {
+pub(super) fn move_rows_aside<R: ExactRecords + Resizable>(records: &mut R, row: usize) {
     records.push_row();
 
     let count_rows = records.count_rows();
@@ -59,7 +59,7 @@ fn move_rows_aside<R: ExactRecords + Resizable>(records: &mut R, row: usize) {
     }
 }
 
-fn move_row_spans(cfg: &mut SpannedConfig, target_row: usize) {
+pub(super) fn move_row_spans(cfg: &mut SpannedConfig, target_row: usize) {
     for ((row, col), span) in cfg.get_column_spans() {
         if row < target_row {
             continue;
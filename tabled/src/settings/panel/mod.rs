@@ -36,11 +36,18 @@
 //! [`Table`]: crate::Table
 //! [`Span`]: crate::settings::span::Span
 
+mod caption;
+mod column_groups;
 mod footer;
 mod header;
 mod horizontal_panel;
 mod vertical_panel;
 
+pub use caption::{Caption, CaptionPosition};
+pub use column_groups::ColumnGroups;
+
+pub(crate) use caption::caption_lines;
+pub(crate) use column_groups::check_column_group;
 pub use footer::Footer;
 pub use header::Header;
 pub use horizontal_panel::HorizontalPanel;
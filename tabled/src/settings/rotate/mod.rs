@@ -38,6 +38,12 @@ use crate::{
     settings::TableOption,
 };
 
+#[cfg(feature = "std")]
+mod rotate_cell;
+
+#[cfg(feature = "std")]
+pub use rotate_cell::RotateCell;
+
 /// Rotate can be used to rotate a table by 90 degrees.
 #[derive(Debug)]
 pub enum Rotate {
@@ -63,6 +69,19 @@ pub enum Rotate {
     Bottom,
 }
 
+#[cfg(feature = "std")]
+impl Rotate {
+    /// Rotates a text of cells vertically, putting each character on its own line.
+    ///
+    /// Unlike the rest variants it's a cell option,
+    /// which is usually applied to a header.
+    ///
+    /// See [`RotateCell`].
+    pub fn cell_vertical() -> RotateCell {
+        RotateCell::new()
+    }
+}
+
 impl<R, D, C> TableOption<R, C, D> for Rotate
 where
     R: Records + ExactRecords + Resizable,
@@ -0,0 +1,168 @@
+use crate::{
+    grid::{
+        config::Entity,
+        records::{ExactRecords, PeekableRecords, Records, RecordsMut},
+        util::string::string_width,
+    },
+    settings::CellOption,
+};
+
+/// [`RotateCell`] rotates a text of a cell vertically,
+/// so each character is put on its own line, top to bottom.
+///
+/// It's mostly useful for a header of narrow columns with long names.
+/// Characters are kept together with combining marks and joined emoji sequences.
+///
+/// It's supposed to be created by [`Rotate::cell_vertical`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{builder::Builder, settings::{object::Rows, Rotate}};
+///
+/// let mut builder = Builder::default();
+/// builder.push_record(["id", "done"]);
+/// builder.push_record(["1", "+"]);
+///
+/// let mut table = builder.build();
+/// table.modify(Rows::first(), Rotate::cell_vertical());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+---+---+\n\
+///      | i | d |\n\
+///      | d | o |\n\
+///      |   | n |\n\
+///      |   | e |\n\
+///      +---+---+\n\
+///      | 1 | + |\n\
+///      +---+---+"
+/// );
+/// ```
+///
+/// [`Rotate::cell_vertical`]: crate::settings::Rotate::cell_vertical
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RotateCell {
+    per_word: bool,
+}
+
+impl RotateCell {
+    /// Creates a [`RotateCell`] which puts all characters in a single column.
+    pub fn new() -> Self {
+        Self { per_word: false }
+    }
+
+    /// Sets whether each word shall be put in its own column.
+    ///
+    /// Columns are placed side by side, separated by a space.
+    ///
+    /// ```
+    /// use tabled::{builder::Builder, settings::{object::Rows, Rotate}};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["max speed"]);
+    /// builder.push_record(["7"]);
+    ///
+    /// let mut table = builder.build();
+    /// table.modify(Rows::first(), Rotate::cell_vertical().per_word(true));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-----+\n\
+    ///      | m s |\n\
+    ///      | a p |\n\
+    ///      | x e |\n\
+    ///      |   e |\n\
+    ///      |   d |\n\
+    ///      +-----+\n\
+    ///      | 7   |\n\
+    ///      +-----+"
+    /// );
+    /// ```
+    pub fn per_word(mut self, on: bool) -> Self {
+        self.per_word = on;
+        self
+    }
+}
+
+impl<R, C> CellOption<R, C> for RotateCell
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, _: &mut C, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+        for pos in entity.iter(count_rows, count_cols) {
+            let text = records.get_text(pos);
+            let text = if self.per_word {
+                rotate_words(text)
+            } else {
+                rotate_text(text)
+            };
+
+            records.set(pos, text);
+        }
+    }
+}
+
+fn rotate_text(text: &str) -> String {
+    let chars = split_graphemes(text.trim())
+        .into_iter()
+        .map(|c| if c.trim().is_empty() { "" } else { c })
+        .collect::<Vec<_>>();
+
+    chars.join("\n")
+}
+
+fn rotate_words(text: &str) -> String {
+    let words = text
+        .split_whitespace()
+        .map(split_graphemes)
+        .collect::<Vec<_>>();
+    let widths = words
+        .iter()
+        .map(|word| word.iter().map(|c| string_width(c)).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+    let height = words.iter().map(Vec::len).max().unwrap_or(0);
+
+    let mut lines = Vec::with_capacity(height);
+    for i in 0..height {
+        let mut line = String::new();
+        for (j, (word, width)) in words.iter().zip(&widths).enumerate() {
+            if j > 0 {
+                line.push(' ');
+            }
+
+            let c = word.get(i).copied().unwrap_or("");
+            line.push_str(c);
+            line.push_str(&" ".repeat(width - string_width(c)));
+        }
+
+        lines.push(line.trim_end().to_owned());
+    }
+
+    lines.join("\n")
+}
+
+/// Splits a text by characters,
+/// keeping zero width characters and characters after a zero width joiner with a preceding one.
+fn split_graphemes(text: &str) -> Vec<&str> {
+    let mut list = Vec::new();
+    let mut start = 0;
+    let mut joined = false;
+    for (i, c) in text.char_indices() {
+        let is_zero_width = unicode_width::UnicodeWidthChar::width(c) == Some(0);
+        if i > 0 && !is_zero_width && !joined {
+            list.push(&text[start..i]);
+            start = i;
+        }
+
+        joined = c == '\u{200D}';
+    }
+
+    if start < text.len() {
+        list.push(&text[start..]);
+    }
+
+    list
+}
@@ -1,27 +1,65 @@
 use crate::{
     grid::{
-        config::{ColoredConfig, Entity, Position, SpannedConfig},
+        config::{AlignmentVertical, ColoredConfig, Entity, Position, SpannedConfig},
         records::{ExactRecords, Records},
     },
     settings::CellOption,
 };
 
 /// Row (vertical) span.
+///
+/// If a content of a spanned cell is taller than the rows it spans,
+/// the missing height is distributed evenly across these rows,
+/// with the leftover lines going to the top rows.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct RowSpan {
     size: usize,
+    alignment: Option<AlignmentVertical>,
 }
 
 impl RowSpan {
     /// Creates a new row (vertical) span.
     pub const fn new(size: usize) -> Self {
-        Self { size }
+        Self {
+            size,
+            alignment: None,
+        }
     }
 
     /// Creates a new row (vertical) span with a maximux value possible.
     pub const fn max() -> Self {
         Self::new(usize::MAX)
     }
+
+    /// Sets a vertical alignment of a content within the merged area.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Table, grid::config::AlignmentVertical, settings::{Modify, Span}};
+    ///
+    /// let data = [["Rust", "1"], ["", "2"], ["", "3"]];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(Modify::new((1, 0)).with(Span::row(3).alignment(AlignmentVertical::Center)));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+---+\n\
+    ///      | 0    | 1 |\n\
+    ///      +------+---+\n\
+    ///      |      | 1 |\n\
+    ///      +      +---+\n\
+    ///      | Rust | 2 |\n\
+    ///      +      +---+\n\
+    ///      |      | 3 |\n\
+    ///      +------+---+"
+    /// );
+    /// ```
+    pub const fn alignment(mut self, alignment: AlignmentVertical) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
 }
 
 impl<R> CellOption<R, ColoredConfig> for RowSpan
@@ -34,6 +72,10 @@ where
 
         set_row_spans(cfg, self.size, entity, (count_rows, count_cols));
         remove_false_spans(cfg);
+
+        if let Some(alignment) = self.alignment {
+            cfg.set_alignment_vertical(entity, alignment);
+        }
     }
 }
 
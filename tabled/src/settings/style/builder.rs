@@ -1,9 +1,12 @@
 //! This module contains a compile time style builder [`Style`].
 
-use core::marker::PhantomData;
+use core::{fmt, marker::PhantomData};
 
 use crate::{
-    grid::config::{Border as GridBorder, Borders, CompactConfig, CompactMultilineConfig},
+    grid::{
+        config::{Border as GridBorder, Borders, CompactConfig, CompactMultilineConfig},
+        util::string::string_width,
+    },
     settings::{
         style::{HorizontalLine, VerticalLine},
         Border, TableOption,
@@ -78,6 +81,8 @@ pub struct Style<T, B, L, R, H, V, const HSIZE: usize, const VSIZE: usize> {
     borders: Borders<char>,
     horizontals: HArray<HSIZE>,
     verticals: VArray<VSIZE>,
+    // horizontal lines are the ones of a preset style, rather than set by `Style::horizontals`
+    is_preset: bool,
     _top: PhantomData<T>,
     _bottom: PhantomData<B>,
     _left: PhantomData<L>,
@@ -119,7 +124,7 @@ impl Style<(), (), (), (), (), (), 0, 0> {
     ///     .intersection_top('*');
     /// ```
     pub const fn empty() -> Style<(), (), (), (), (), (), 0, 0> {
-        Style::new(Borders::empty(), [], [])
+        Style::preset(Borders::empty(), [], [])
     }
 
     /// This style is analog of `empty` but with a vertical space(' ') line.
@@ -131,7 +136,7 @@ impl Style<(), (), (), (), (), (), 0, 0> {
     ///      3    Endeavouros    https://endeavouros.com/
     /// ```
     pub const fn blank() -> Style<(), (), (), (), (), On, 0, 0> {
-        Style::new(
+        Style::preset(
             create_borders(
                 HLine::empty(),
                 HLine::empty(),
@@ -161,7 +166,7 @@ impl Style<(), (), (), (), (), (), 0, 0> {
     ///     +----+--------------+---------------------------+
     /// ```
     pub const fn ascii() -> Style<On, On, On, On, On, On, 0, 0> {
-        Style::new(
+        Style::preset(
             create_borders(
                 HLine::full('-', '+', '+', '+'),
                 HLine::full('-', '+', '+', '+'),
@@ -188,7 +193,7 @@ impl Style<(), (), (), (), (), (), 0, 0> {
     ///      3  | Endeavouros  | https://endeavouros.com/
     /// ```
     pub const fn psql() -> Style<(), (), (), (), (), On, 1, 0> {
-        Style::new(
+        Style::preset(
             create_borders(
                 HLine::empty(),
                 HLine::empty(),
@@ -212,7 +217,7 @@ impl Style<(), (), (), (), (), (), 0, 0> {
     ///     | 3  | Endeavouros  | https://endeavouros.com/  |
     /// ```
     pub const fn markdown() -> Style<(), (), On, On, (), On, 1, 0> {
-        Style::new(
+        Style::preset(
             create_borders(
                 HLine::empty(),
                 HLine::empty(),
@@ -242,7 +247,7 @@ impl Style<(), (), (), (), (), (), 0, 0> {
     ///     └────┴──────────────┴───────────────────────────┘
     /// ```
     pub const fn modern() -> Style<On, On, On, On, On, On, 0, 0> {
-        Style::new(
+        Style::preset(
             create_borders(
                 HLine::full('─', '┬', '┌', '┐'),
                 HLine::full('─', '┴', '└', '┘'),
@@ -270,7 +275,7 @@ impl Style<(), (), (), (), (), (), 0, 0> {
     ///     └────┴──────────────┴───────────────────────────┘
     /// ```
     pub const fn sharp() -> Style<On, On, On, On, (), On, 1, 0> {
-        Style::new(
+        Style::preset(
             create_borders(
                 HLine::full('─', '┬', '┌', '┐'),
                 HLine::full('─', '┴', '└', '┘'),
@@ -298,7 +303,7 @@ impl Style<(), (), (), (), (), (), 0, 0> {
     ///     ╰────┴──────────────┴───────────────────────────╯
     /// ```
     pub const fn rounded() -> Style<On, On, On, On, (), On, 1, 0> {
-        Style::new(
+        Style::preset(
             create_borders(
                 HLine::full('─', '┬', '╭', '╮'),
                 HLine::full('─', '┴', '╰', '╯'),
@@ -328,7 +333,7 @@ impl Style<(), (), (), (), (), (), 0, 0> {
     ///     ╰────┴──────────────┴───────────────────────────╯
     /// ```
     pub const fn modern_rounded() -> Style<On, On, On, On, On, On, 0, 0> {
-        Style::new(
+        Style::preset(
             create_borders(
                 HLine::full('─', '┬', '╭', '╮'),
                 HLine::full('─', '┴', '╰', '╯'),
@@ -358,7 +363,7 @@ impl Style<(), (), (), (), (), (), 0, 0> {
     ///     ╚════╩══════════════╩═══════════════════════════╝
     /// ```
     pub const fn extended() -> Style<On, On, On, On, On, On, 0, 0> {
-        Style::new(
+        Style::preset(
             create_borders(
                 HLine::full('═', '╦', '╔', '╗'),
                 HLine::full('═', '╩', '╚', '╝'),
@@ -372,6 +377,66 @@ impl Style<(), (), (), (), (), (), 0, 0> {
         )
     }
 
+    /// This style uses heavy lines.
+    ///
+    /// Beware: It uses UTF-8 characters.
+    ///
+    /// ```text
+    ///     ┏━━━━┳━━━━━━━━━━━━━━┳━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+    ///     ┃ id ┃ distribution ┃           link            ┃
+    ///     ┣━━━━╋━━━━━━━━━━━━━━╋━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+    ///     ┃ 0  ┃    Fedora    ┃  https://getfedora.org/   ┃
+    ///     ┣━━━━╋━━━━━━━━━━━━━━╋━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+    ///     ┃ 2  ┃   OpenSUSE   ┃ https://www.opensuse.org/ ┃
+    ///     ┣━━━━╋━━━━━━━━━━━━━━╋━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+    ///     ┃ 3  ┃ Endeavouros  ┃ https://endeavouros.com/  ┃
+    ///     ┗━━━━┻━━━━━━━━━━━━━━┻━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+    /// ```
+    pub const fn heavy() -> Style<On, On, On, On, On, On, 0, 0> {
+        Style::preset(
+            create_borders(
+                HLine::full('━', '┳', '┏', '┓'),
+                HLine::full('━', '┻', '┗', '┛'),
+                HLine::full('━', '╋', '┣', '┫'),
+                Some('┃'),
+                Some('┃'),
+                Some('┃'),
+            ),
+            [],
+            [],
+        )
+    }
+
+    /// This style has a heavy frame and light inner lines.
+    ///
+    /// Beware: It uses UTF-8 characters.
+    ///
+    /// ```text
+    ///     ┏━━━━┯━━━━━━━━━━━━━━┯━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+    ///     ┃ id │ distribution │           link            ┃
+    ///     ┠────┼──────────────┼───────────────────────────┨
+    ///     ┃ 0  │    Fedora    │  https://getfedora.org/   ┃
+    ///     ┠────┼──────────────┼───────────────────────────┨
+    ///     ┃ 2  │   OpenSUSE   │ https://www.opensuse.org/ ┃
+    ///     ┠────┼──────────────┼───────────────────────────┨
+    ///     ┃ 3  │ Endeavouros  │ https://endeavouros.com/  ┃
+    ///     ┗━━━━┷━━━━━━━━━━━━━━┷━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+    /// ```
+    pub const fn heavy_light() -> Style<On, On, On, On, On, On, 0, 0> {
+        Style::preset(
+            create_borders(
+                HLine::full('━', '┯', '┏', '┓'),
+                HLine::full('━', '┷', '┗', '┛'),
+                HLine::full('─', '┼', '┠', '┨'),
+                Some('┃'),
+                Some('┃'),
+                Some('│'),
+            ),
+            [],
+            [],
+        )
+    }
+
     /// This is a style uses only '.' and ':' chars.
     /// It has a vertical and horizontal split lines.
     ///
@@ -387,7 +452,7 @@ impl Style<(), (), (), (), (), (), 0, 0> {
     ///     :....:..............:...........................:
     /// ```
     pub const fn dots() -> Style<On, On, On, On, On, On, 0, 0> {
-        Style::new(
+        Style::preset(
             create_borders(
                 HLine::full('.', '.', '.', '.'),
                 HLine::full('.', ':', ':', ':'),
@@ -413,7 +478,7 @@ impl Style<(), (), (), (), (), (), 0, 0> {
     ///     ==== ============== ===========================
     /// ```
     pub const fn re_structured_text() -> Style<On, On, (), (), (), On, 1, 0> {
-        Style::new(
+        Style::preset(
             create_borders(
                 HLine::new(Some('='), Some(' '), None, None),
                 HLine::new(Some('='), Some(' '), None, None),
@@ -439,7 +504,7 @@ impl Style<(), (), (), (), (), (), 0, 0> {
     ///     '-----------------------------------------------'
     /// ```
     pub const fn ascii_rounded() -> Style<On, On, On, On, (), On, 0, 0> {
-        Style::new(
+        Style::preset(
             create_borders(
                 HLine::full('-', '-', '.', '.'),
                 HLine::full('-', '-', '\'', '\''),
@@ -452,6 +517,25 @@ impl Style<(), (), (), (), (), (), 0, 0> {
             [],
         )
     }
+
+    /// This is a minimal style which has only 1 horizontal line which splits header.
+    ///
+    /// It has no outer borders and no vertical lines.
+    ///
+    /// ```text
+    ///      id  distribution            link
+    ///     ---------------------------------------------
+    ///      0      Fedora      https://getfedora.org/
+    ///      2     OpenSUSE    https://www.opensuse.org/
+    ///      3   Endeavouros   https://endeavouros.com/
+    /// ```
+    pub const fn header_separator_only() -> Style<(), (), (), (), (), (), 1, 0> {
+        Style::preset(
+            Borders::empty(),
+            [(1, HLine::new(Some('-'), None, None, None))],
+            [],
+        )
+    }
 }
 
 impl<T, B, L, R, H, V, const HSIZE: usize, const VSIZE: usize>
@@ -466,6 +550,7 @@ impl<T, B, L, R, H, V, const HSIZE: usize, const VSIZE: usize>
             borders,
             horizontals,
             verticals,
+            is_preset: false,
             _top: PhantomData,
             _bottom: PhantomData,
             _left: PhantomData,
@@ -475,6 +560,27 @@ impl<T, B, L, R, H, V, const HSIZE: usize, const VSIZE: usize>
         }
     }
 
+    const fn preset(
+        borders: Borders<char>,
+        horizontals: HArray<HSIZE>,
+        verticals: VArray<VSIZE>,
+    ) -> Self {
+        let mut style = Self::new(borders, horizontals, verticals);
+        style.is_preset = true;
+        style
+    }
+
+    const fn rebuild<T2, B2, L2, R2, H2, V2, const HSIZE2: usize, const VSIZE2: usize>(
+        &self,
+        borders: Borders<char>,
+        horizontals: HArray<HSIZE2>,
+        verticals: VArray<VSIZE2>,
+    ) -> Style<T2, B2, L2, R2, H2, V2, HSIZE2, VSIZE2> {
+        let mut style = Style::new(borders, horizontals, verticals);
+        style.is_preset = self.is_preset;
+        style
+    }
+
     pub(crate) const fn get_borders(&self) -> Borders<char> {
         self.borders
     }
@@ -563,7 +669,7 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
         list: [(usize, VerticalLine<T, B, H>); SIZE],
     ) -> Style<T, B, L, R, H, V, HN, SIZE> {
         let list = varr_convert(list);
-        Style::new(self.borders, self.horizontals, list)
+        self.rebuild(self.borders, self.horizontals, list)
     }
 
     /// Removes all horizontal lines set by [`Style::horizontals`]
@@ -573,7 +679,7 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
 
     /// Removes all verticals lines set by [`Style::verticals`]
     pub const fn remove_verticals(self) -> Style<T, B, L, R, H, V, HN, 0> {
-        Style::new(self.borders, self.horizontals, [])
+        self.rebuild(self.borders, self.horizontals, [])
     }
 
     /// Sets a top border.
@@ -601,7 +707,7 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
 
         let verticals = varr_set(self.verticals, VLine::new(None, None, Some(c), None));
 
-        Style::new(self.borders, self.horizontals, verticals)
+        self.rebuild(self.borders, self.horizontals, verticals)
     }
 
     /// Sets a bottom border.
@@ -629,7 +735,7 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
 
         let verticals = varr_set(self.verticals, VLine::new(None, None, None, Some(c)));
 
-        Style::new(self.borders, self.horizontals, verticals)
+        self.rebuild(self.borders, self.horizontals, verticals)
     }
 
     /// Sets a left border.
@@ -657,7 +763,7 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
 
         let horizontals = harr_set(self.horizontals, HLine::new(None, None, Some(c), None));
 
-        Style::new(self.borders, horizontals, self.verticals)
+        self.rebuild(self.borders, horizontals, self.verticals)
     }
 
     /// Sets a right border.
@@ -685,7 +791,7 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
 
         let horizontals = harr_set(self.horizontals, HLine::new(None, None, None, Some(c)));
 
-        Style::new(self.borders, horizontals, self.verticals)
+        self.rebuild(self.borders, horizontals, self.verticals)
     }
 
     /// Sets a horizontal split line.
@@ -713,7 +819,7 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
 
         let verticals = varr_set(self.verticals, VLine::new(None, Some(c), None, None));
 
-        Style::new(self.borders, self.horizontals, verticals)
+        self.rebuild(self.borders, self.horizontals, verticals)
     }
 
     /// Sets a vertical split line.
@@ -741,7 +847,7 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
 
         let horizontals = harr_set(self.horizontals, HLine::new(None, Some(c), None, None));
 
-        Style::new(self.borders, horizontals, self.verticals)
+        self.rebuild(self.borders, horizontals, self.verticals)
     }
 
     /// Set a vertical line.
@@ -809,7 +915,7 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
             VLine::new(None, line.intersection, line.top, line.bottom),
         );
 
-        Style::new(self.borders, horizontals, verticals)
+        self.rebuild(self.borders, horizontals, verticals)
     }
 
     /// Set a horizontal line.
@@ -877,7 +983,7 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
             VLine::new(None, line.intersection, None, None),
         );
 
-        Style::new(self.borders, horizontals, verticals)
+        self.rebuild(self.borders, horizontals, verticals)
     }
 
     /// Set a horizontal line.
@@ -945,7 +1051,7 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
             VLine::new(None, line.intersection, None, None),
         );
 
-        Style::new(self.borders, horizontals, verticals)
+        self.rebuild(self.borders, horizontals, verticals)
     }
 
     /// Set a horizontal line.
@@ -1013,7 +1119,7 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
             VLine::new(None, line.intersection, None, None),
         );
 
-        Style::new(self.borders, horizontals, verticals)
+        self.rebuild(self.borders, horizontals, verticals)
     }
 
     /// Set a vertical line.
@@ -1081,7 +1187,7 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
             VLine::new(None, line.intersection, line.top, line.bottom),
         );
 
-        Style::new(self.borders, horizontals, verticals)
+        self.rebuild(self.borders, horizontals, verticals)
     }
 
     /// Set a vertical line.
@@ -1149,7 +1255,7 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
             VLine::new(None, line.intersection, line.top, line.bottom),
         );
 
-        Style::new(self.borders, horizontals, verticals)
+        self.rebuild(self.borders, horizontals, verticals)
     }
 
     /// Set a frame for a style.
@@ -1216,7 +1322,7 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
         self.borders.top_right = border.right_top_corner;
         self.borders.bottom_right = border.right_bottom_corner;
 
-        Style::new(self.borders, horizontals, verticals)
+        self.rebuild(self.borders, horizontals, verticals)
     }
 }
 
@@ -1225,7 +1331,7 @@ impl<B, R, H, V, const HN: usize, const VN: usize> Style<On, B, On, R, H, V, HN,
     pub const fn corner_top_left(mut self, c: char) -> Self {
         self.borders.top_left = Some(c);
 
-        Style::new(self.borders, self.horizontals, self.verticals)
+        self.rebuild(self.borders, self.horizontals, self.verticals)
     }
 }
 
@@ -1234,7 +1340,7 @@ impl<B, L, H, V, const HN: usize, const VN: usize> Style<On, B, L, On, H, V, HN,
     pub const fn corner_top_right(mut self, c: char) -> Self {
         self.borders.top_right = Some(c);
 
-        Style::new(self.borders, self.horizontals, self.verticals)
+        self.rebuild(self.borders, self.horizontals, self.verticals)
     }
 }
 
@@ -1243,7 +1349,7 @@ impl<T, L, H, V, const HN: usize, const VN: usize> Style<T, On, L, On, H, V, HN,
     pub const fn corner_bottom_right(mut self, c: char) -> Self {
         self.borders.bottom_right = Some(c);
 
-        Style::new(self.borders, self.horizontals, self.verticals)
+        self.rebuild(self.borders, self.horizontals, self.verticals)
     }
 }
 
@@ -1252,7 +1358,7 @@ impl<T, R, H, V, const HN: usize, const VN: usize> Style<T, On, On, R, H, V, HN,
     pub const fn corner_bottom_left(mut self, c: char) -> Self {
         self.borders.bottom_left = Some(c);
 
-        Style::new(self.borders, self.horizontals, self.verticals)
+        self.rebuild(self.borders, self.horizontals, self.verticals)
     }
 }
 
@@ -1261,7 +1367,7 @@ impl<T, B, R, V, const HN: usize, const VN: usize> Style<T, B, On, R, On, V, HN,
     pub const fn intersection_left(mut self, c: char) -> Self {
         self.borders.left_intersection = Some(c);
 
-        Style::new(self.borders, self.horizontals, self.verticals)
+        self.rebuild(self.borders, self.horizontals, self.verticals)
     }
 }
 
@@ -1270,7 +1376,7 @@ impl<T, B, L, V, const HN: usize, const VN: usize> Style<T, B, L, On, On, V, HN,
     pub const fn intersection_right(mut self, c: char) -> Self {
         self.borders.right_intersection = Some(c);
 
-        Style::new(self.borders, self.horizontals, self.verticals)
+        self.rebuild(self.borders, self.horizontals, self.verticals)
     }
 }
 
@@ -1279,7 +1385,7 @@ impl<B, L, R, H, const HN: usize, const VN: usize> Style<On, B, L, R, H, On, HN,
     pub const fn intersection_top(mut self, c: char) -> Self {
         self.borders.top_intersection = Some(c);
 
-        Style::new(self.borders, self.horizontals, self.verticals)
+        self.rebuild(self.borders, self.horizontals, self.verticals)
     }
 }
 
@@ -1288,7 +1394,7 @@ impl<T, L, R, H, const HN: usize, const VN: usize> Style<T, On, L, R, H, On, HN,
     pub const fn intersection_bottom(mut self, c: char) -> Self {
         self.borders.bottom_intersection = Some(c);
 
-        Style::new(self.borders, self.horizontals, self.verticals)
+        self.rebuild(self.borders, self.horizontals, self.verticals)
     }
 }
 
@@ -1307,7 +1413,7 @@ impl<T, B, L, R, const HN: usize, const VN: usize> Style<T, B, L, R, On, On, HN,
         let horizontals = harr_set(self.horizontals, HLine::new(None, Some(c), None, None));
         let verticals = varr_set(self.verticals, VLine::new(None, Some(c), None, None));
 
-        Style::new(self.borders, horizontals, verticals)
+        self.rebuild(self.borders, horizontals, verticals)
     }
 }
 
@@ -1325,7 +1431,7 @@ impl<B, L, R, H, V, const HN: usize, const VN: usize> Style<On, B, L, R, H, V, H
 
         let verticals = varr_unset(self.verticals, VLine::new(None, None, Some(' '), None));
 
-        Style::new(self.borders, self.horizontals, verticals)
+        self.rebuild(self.borders, self.horizontals, verticals)
     }
 }
 
@@ -1343,7 +1449,7 @@ impl<T, L, R, H, V, const HN: usize, const VN: usize> Style<T, On, L, R, H, V, H
 
         let verticals = varr_unset(self.verticals, VLine::new(None, None, None, Some(' ')));
 
-        Style::new(self.borders, self.horizontals, verticals)
+        self.rebuild(self.borders, self.horizontals, verticals)
     }
 }
 
@@ -1361,7 +1467,7 @@ impl<T, B, R, H, V, const HN: usize, const VN: usize> Style<T, B, On, R, H, V, H
 
         let horizontals = harr_unset(self.horizontals, HLine::new(None, None, Some(' '), None));
 
-        Style::new(self.borders, horizontals, self.verticals)
+        self.rebuild(self.borders, horizontals, self.verticals)
     }
 }
 
@@ -1379,7 +1485,7 @@ impl<T, B, L, H, V, const HN: usize, const VN: usize> Style<T, B, L, On, H, V, H
 
         let horizontals = harr_unset(self.horizontals, HLine::new(None, None, None, Some(' ')));
 
-        Style::new(self.borders, horizontals, self.verticals)
+        self.rebuild(self.borders, horizontals, self.verticals)
     }
 }
 
@@ -1401,7 +1507,7 @@ impl<T, B, L, R, V, const HN: usize, const VN: usize> Style<T, B, L, R, On, V, H
         // let lines = linearr_unset(lines, Line::new(None, Some(' '), None, None));
         let verticals = self.verticals;
 
-        Style::new(self.borders, self.horizontals, verticals)
+        self.rebuild(self.borders, self.horizontals, verticals)
     }
 }
 
@@ -1420,7 +1526,7 @@ impl<T, B, L, R, H, const HN: usize, const VN: usize> Style<T, B, L, R, H, On, H
         // let lines = linearr_unset(lines, Line::new(None, Some(' '), None, None));
         let horizontals = self.horizontals;
 
-        Style::new(self.borders, horizontals, self.verticals)
+        self.rebuild(self.borders, horizontals, self.verticals)
     }
 }
 
@@ -1438,14 +1544,396 @@ impl<H, V, const HN: usize, const VN: usize> Style<On, On, On, On, H, V, HN, VN>
     }
 }
 
+impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V, HN, VN> {
+    /// Verifies that each character of the style has a display width of 1.
+    ///
+    /// A table layout assumes that a border character takes exactly 1 column,
+    /// so characters like emojis or CJK symbols would break the alignment.
+    ///
+    /// A style is still applied to a table,
+    /// but such characters are replaced by a space to keep the layout aligned.
+    ///
+    /// ```
+    /// use tabled::settings::style::Style;
+    ///
+    /// assert!(Style::modern().check_chars_width().is_ok());
+    /// assert!(Style::ascii().vertical('🦀').check_chars_width().is_err());
+    /// ```
+    pub fn check_chars_width(&self) -> Result<(), BorderCharWidthError> {
+        let b = &self.borders;
+        let borders = [
+            b.top,
+            b.top_left,
+            b.top_right,
+            b.top_intersection,
+            b.bottom,
+            b.bottom_left,
+            b.bottom_right,
+            b.bottom_intersection,
+            b.horizontal,
+            b.vertical,
+            b.intersection,
+            b.left,
+            b.left_intersection,
+            b.right,
+            b.right_intersection,
+        ];
+
+        let horizontals = self
+            .horizontals
+            .iter()
+            .flat_map(|(_, line)| [line.main, line.intersection, line.left, line.right]);
+        let verticals = self
+            .verticals
+            .iter()
+            .flat_map(|(_, line)| [line.main, line.intersection, line.top, line.bottom]);
+
+        let chars = borders.iter().copied().chain(horizontals).chain(verticals);
+        for c in chars.flatten() {
+            let width = char_width(c);
+            if width != 1 {
+                return Err(BorderCharWidthError { c, width });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that each pair of crossing lines has a character for their intersection.
+    ///
+    /// Otherwise a table is rendered with holes in its borders,
+    /// for example when a top and left lines are set but a top left corner is not.
+    ///
+    /// In debug builds applying such a style to a table panics with the same error.
+    /// The method is `const`, so a custom style can be verified at compile time,
+    /// like `const _: () = assert!(MY_STYLE.verify().is_ok());`.
+    ///
+    /// ```
+    /// use tabled::settings::style::{HorizontalLine, Style};
+    ///
+    /// assert!(Style::modern().verify().is_ok());
+    ///
+    /// let style = Style::ascii().horizontals([(1, HorizontalLine::empty().horizontal('='))]);
+    /// assert_eq!(
+    ///     style.verify().unwrap_err().to_string(),
+    ///     "missing left intersection of a custom horizontal line \
+    ///      while custom horizontal and left lines are set"
+    /// );
+    /// ```
+    pub const fn verify(&self) -> Result<(), StyleError> {
+        let b = &self.borders;
+
+        let checks = [
+            (
+                b.top.is_some(),
+                b.left.is_some(),
+                b.top_left.is_some(),
+                StyleError::new("top-left corner", "top", "left"),
+            ),
+            (
+                b.top.is_some(),
+                b.right.is_some(),
+                b.top_right.is_some(),
+                StyleError::new("top-right corner", "top", "right"),
+            ),
+            (
+                b.top.is_some(),
+                b.vertical.is_some(),
+                b.top_intersection.is_some(),
+                StyleError::new("top intersection", "top", "vertical"),
+            ),
+            (
+                b.bottom.is_some(),
+                b.left.is_some(),
+                b.bottom_left.is_some(),
+                StyleError::new("bottom-left corner", "bottom", "left"),
+            ),
+            (
+                b.bottom.is_some(),
+                b.right.is_some(),
+                b.bottom_right.is_some(),
+                StyleError::new("bottom-right corner", "bottom", "right"),
+            ),
+            (
+                b.bottom.is_some(),
+                b.vertical.is_some(),
+                b.bottom_intersection.is_some(),
+                StyleError::new("bottom intersection", "bottom", "vertical"),
+            ),
+            (
+                b.horizontal.is_some(),
+                b.left.is_some(),
+                b.left_intersection.is_some(),
+                StyleError::new("left intersection", "horizontal", "left"),
+            ),
+            (
+                b.horizontal.is_some(),
+                b.right.is_some(),
+                b.right_intersection.is_some(),
+                StyleError::new("right intersection", "horizontal", "right"),
+            ),
+            (
+                b.horizontal.is_some(),
+                b.vertical.is_some(),
+                b.intersection.is_some(),
+                StyleError::new("intersection", "horizontal", "vertical"),
+            ),
+        ];
+
+        let mut i = 0;
+        while i < checks.len() {
+            let (a, b, c, err) = checks[i];
+            if a && b && !c {
+                return Err(err);
+            }
+
+            i += 1;
+        }
+
+        let mut i = 0;
+        while i < self.horizontals.len() {
+            let line = &self.horizontals[i].1;
+            if line.main.is_some() {
+                let checks = [
+                    (
+                        b.left.is_some(),
+                        line.left.is_some(),
+                        StyleError::new(
+                            "left intersection of a custom horizontal line",
+                            "custom horizontal",
+                            "left",
+                        ),
+                    ),
+                    (
+                        b.right.is_some(),
+                        line.right.is_some(),
+                        StyleError::new(
+                            "right intersection of a custom horizontal line",
+                            "custom horizontal",
+                            "right",
+                        ),
+                    ),
+                    (
+                        b.vertical.is_some(),
+                        line.intersection.is_some(),
+                        StyleError::new(
+                            "intersection of a custom horizontal line",
+                            "custom horizontal",
+                            "vertical",
+                        ),
+                    ),
+                ];
+
+                let mut j = 0;
+                while j < checks.len() {
+                    let (a, c, err) = checks[j];
+                    if a && !c {
+                        return Err(err);
+                    }
+
+                    j += 1;
+                }
+            }
+
+            i += 1;
+        }
+
+        let mut i = 0;
+        while i < self.verticals.len() {
+            let line = &self.verticals[i].1;
+            if line.main.is_some() {
+                let checks = [
+                    (
+                        b.top.is_some(),
+                        line.top.is_some(),
+                        StyleError::new(
+                            "top intersection of a custom vertical line",
+                            "custom vertical",
+                            "top",
+                        ),
+                    ),
+                    (
+                        b.bottom.is_some(),
+                        line.bottom.is_some(),
+                        StyleError::new(
+                            "bottom intersection of a custom vertical line",
+                            "custom vertical",
+                            "bottom",
+                        ),
+                    ),
+                    (
+                        b.horizontal.is_some(),
+                        line.intersection.is_some(),
+                        StyleError::new(
+                            "intersection of a custom vertical line",
+                            "custom vertical",
+                            "horizontal",
+                        ),
+                    ),
+                ];
+
+                let mut j = 0;
+                while j < checks.len() {
+                    let (a, c, err) = checks[j];
+                    if a && !c {
+                        return Err(err);
+                    }
+
+                    j += 1;
+                }
+            }
+
+            i += 1;
+        }
+
+        Ok(())
+    }
+
+    fn assert_valid(&self) {
+        #[cfg(debug_assertions)]
+        if let Err(err) = self.verify() {
+            panic!("{}", err);
+        }
+    }
+
+    /// Replaces characters which width is not 1, see [`Style::check_chars_width`].
+    fn fit_chars_width(mut self) -> Self {
+        let b = &mut self.borders;
+        let borders = [
+            &mut b.top,
+            &mut b.top_left,
+            &mut b.top_right,
+            &mut b.top_intersection,
+            &mut b.bottom,
+            &mut b.bottom_left,
+            &mut b.bottom_right,
+            &mut b.bottom_intersection,
+            &mut b.horizontal,
+            &mut b.vertical,
+            &mut b.intersection,
+            &mut b.left,
+            &mut b.left_intersection,
+            &mut b.right,
+            &mut b.right_intersection,
+        ];
+
+        for c in borders {
+            fit_char_width(c);
+        }
+
+        for (_, line) in &mut self.horizontals {
+            for c in [
+                &mut line.main,
+                &mut line.intersection,
+                &mut line.left,
+                &mut line.right,
+            ] {
+                fit_char_width(c);
+            }
+        }
+
+        for (_, line) in &mut self.verticals {
+            for c in [
+                &mut line.main,
+                &mut line.intersection,
+                &mut line.top,
+                &mut line.bottom,
+            ] {
+                fit_char_width(c);
+            }
+        }
+
+        self
+    }
+}
+
+fn char_width(c: char) -> usize {
+    string_width(c.encode_utf8(&mut [0; 4]))
+}
+
+fn fit_char_width(c: &mut Option<char>) {
+    if matches!(c, Some(c) if char_width(*c) != 1) {
+        *c = Some(' ');
+    }
+}
+
+/// An error which is returned when a [`Style`] has 2 crossing lines
+/// but no character for their intersection.
+///
+/// See [`Style::verify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StyleError {
+    /// A name of a missing position.
+    pub missing: &'static str,
+    /// Names of the crossing lines.
+    pub lines: (&'static str, &'static str),
+}
+
+impl StyleError {
+    const fn new(missing: &'static str, line1: &'static str, line2: &'static str) -> Self {
+        Self {
+            missing,
+            lines: (line1, line2),
+        }
+    }
+}
+
+impl fmt::Display for StyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "missing {} while {} and {} lines are set",
+            self.missing, self.lines.0, self.lines.1
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StyleError {}
+
+/// An error which is returned when a [`Style`] has a character
+/// with a display width different from 1.
+///
+/// See [`Style::check_chars_width`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorderCharWidthError {
+    /// A character.
+    pub c: char,
+    /// A display width of the character.
+    pub width: usize,
+}
+
+impl fmt::Display for BorderCharWidthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "border character {:?} has a display width of {}, but only characters of width 1 are supported",
+            self.c, self.width
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BorderCharWidthError {}
+
 #[cfg(feature = "std")]
 impl<T, B, L, R, H, V, Data, Dims, const HSIZE: usize, const VSIZE: usize>
     TableOption<Data, ColoredConfig, Dims> for Style<T, B, L, R, H, V, HSIZE, VSIZE>
 {
     fn change(self, _: &mut Data, cfg: &mut ColoredConfig, _: &mut Dims) {
+        self.assert_valid();
+        let style = self.fit_chars_width();
         cfg_clear_borders(cfg);
-        cfg_set_custom_lines(cfg, &self.horizontals, &self.verticals);
-        cfg.set_borders(self.borders);
+        cfg_set_custom_lines(cfg, &style.horizontals, &style.verticals);
+        cfg.set_borders(style.borders);
+
+        let header_line = style
+            .horizontals
+            .iter()
+            .find(|(row, _)| *row == 1)
+            .filter(|_| style.is_preset)
+            .map(|(_, line)| *line);
+        cfg.set_preset_header_line(header_line);
     }
 }
 
@@ -1453,7 +1941,9 @@ impl<T, B, L, R, H, V, Data, Dims, const HSIZE: usize, const VSIZE: usize>
     TableOption<Data, CompactConfig, Dims> for Style<T, B, L, R, H, V, HSIZE, VSIZE>
 {
     fn change(self, _: &mut Data, cfg: &mut CompactConfig, _: &mut Dims) {
-        *cfg = cfg.set_borders(self.borders);
+        self.assert_valid();
+        let style = self.fit_chars_width();
+        *cfg = cfg.set_borders(style.borders);
     }
 }
 
@@ -1461,7 +1951,9 @@ impl<T, B, L, R, H, V, Data, Dims, const HSIZE: usize, const VSIZE: usize>
     TableOption<Data, CompactMultilineConfig, Dims> for Style<T, B, L, R, H, V, HSIZE, VSIZE>
 {
     fn change(self, _: &mut Data, cfg: &mut CompactMultilineConfig, _: &mut Dims) {
-        cfg.set_borders(self.borders);
+        self.assert_valid();
+        let style = self.fit_chars_width();
+        cfg.set_borders(style.borders);
     }
 }
 
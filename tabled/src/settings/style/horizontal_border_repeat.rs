@@ -0,0 +1,51 @@
+use crate::{grid::config::ColoredConfig, settings::TableOption};
+
+/// [`HorizontalBorderRepeat`] renders each horizontal line of a [`Table`] `n` times,
+/// so horizontal borders look thicker.
+///
+/// Only horizontal lines are repeated, as a vertical line always takes 1 column.
+/// A value of `0` or `1` keeps the lines as they are.
+///
+/// Notice that only [`Table`] takes it into account.
+///
+/// # Example
+///
+/// ```
+/// use std::iter::FromIterator;
+/// use tabled::{settings::style::{HorizontalBorderRepeat, Style}, Table};
+///
+/// let mut table = Table::from_iter([["name", "age"], ["Alex", "20"]]);
+/// table.with(Style::modern()).with(HorizontalBorderRepeat(2));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "┌──────┬─────┐\n\
+///      ┌──────┬─────┐\n\
+///      │ name │ age │\n\
+///      ├──────┼─────┤\n\
+///      ├──────┼─────┤\n\
+///      │ Alex │ 20  │\n\
+///      └──────┴─────┘\n\
+///      └──────┴─────┘"
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HorizontalBorderRepeat(pub usize);
+
+impl Default for HorizontalBorderRepeat {
+    fn default() -> Self {
+        Self(1)
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for HorizontalBorderRepeat {
+    fn change(self, _: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let _ = cfg.set_horizontal_line_repeat(self.0);
+    }
+
+    fn hint_change(&self) -> Option<crate::grid::config::Entity> {
+        None
+    }
+}
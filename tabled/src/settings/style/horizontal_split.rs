@@ -0,0 +1,89 @@
+use crate::{
+    grid::config::{ColoredConfig, HorizontalLine as Line},
+    grid::records::{ExactRecords, Records},
+    settings::TableOption,
+};
+
+use super::HorizontalLine;
+
+/// [`HorizontalSplit`] inserts a horizontal line right below a given row.
+///
+/// The row index counts the header as row `0`.
+/// Several [`HorizontalSplit`]s can be applied, each of them adds its own line.
+///
+/// By default a line is built from the characters of a horizontal line of current style,
+/// or if the style doesn't have one `-` and `+` are used.
+/// It can be changed by [`HorizontalSplit::line`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::style::{HorizontalLine, HorizontalSplit, Style}};
+///
+/// let data = [("Apple", 3), ("Pear", 5), ("Total", 8)];
+///
+/// let mut table = Table::new(data);
+/// table
+///     .with(Style::psql())
+///     .with(HorizontalSplit::new(2).line(HorizontalLine::new('=').intersection('#')));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     " &str  | i32 \n\
+///      -------+-----\n \
+///       Apple | 3   \n \
+///       Pear  | 5   \n\
+///      =======#=====\n \
+///       Total | 8   "
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HorizontalSplit {
+    row: usize,
+    line: Option<Line<char>>,
+}
+
+impl HorizontalSplit {
+    /// Creates a [`HorizontalSplit`] which inserts a line below a given row.
+    pub const fn new(row: usize) -> Self {
+        Self { row, line: None }
+    }
+
+    /// Sets a line which will be used as a separator.
+    pub const fn line<L, R, I>(mut self, line: HorizontalLine<L, R, I>) -> Self {
+        self.line = Some(line.into_inner());
+        self
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for HorizontalSplit
+where
+    R: Records + ExactRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        if self.row + 1 >= count_rows {
+            return;
+        }
+
+        let line = self.line.unwrap_or_else(|| {
+            let borders = cfg.get_borders();
+            let default_intersection = |border: Option<char>| border.map(|_| '+');
+
+            Line::new(
+                Some(borders.horizontal.unwrap_or('-')),
+                borders
+                    .intersection
+                    .or_else(|| default_intersection(borders.vertical)),
+                borders
+                    .left_intersection
+                    .or_else(|| default_intersection(borders.left)),
+                borders
+                    .right_intersection
+                    .or_else(|| default_intersection(borders.right)),
+            )
+        });
+
+        cfg.insert_horizontal_line(self.row + 1, line);
+    }
+}
@@ -107,20 +107,28 @@ mod border_color;
 #[cfg(feature = "std")]
 mod border_text;
 #[cfg(feature = "std")]
+mod horizontal_border_repeat;
+#[cfg(feature = "std")]
+mod horizontal_split;
+#[cfg(feature = "std")]
 mod line_char;
 #[cfg(feature = "std")]
 mod span_border_correction;
+#[cfg(feature = "std")]
+mod vertical_split;
 
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use self::{
-    border_color::BorderColor, border_text::LineText, line_char::LineChar,
-    span_border_correction::BorderSpanCorrection,
+    border_color::BorderColor, border_text::LineText,
+    horizontal_border_repeat::HorizontalBorderRepeat, horizontal_split::HorizontalSplit,
+    line_char::LineChar, span_border_correction::BorderSpanCorrection,
+    vertical_split::VerticalSplit,
 };
 
 pub use self::{
     border::Border,
-    builder::{On, Style},
+    builder::{BorderCharWidthError, On, Style, StyleError},
     horizontal_line::HorizontalLine,
     offset::Offset,
     vertical_line::VerticalLine,
@@ -0,0 +1,88 @@
+use crate::{
+    grid::config::{ColoredConfig, VerticalLine as Line},
+    grid::records::{ExactRecords, Records},
+    settings::TableOption,
+};
+
+use super::VerticalLine;
+
+/// [`VerticalSplit`] inserts a vertical line right after a given column.
+///
+/// It can be used to separate groups of columns.
+/// Several [`VerticalSplit`]s can be applied, each of them adds its own line.
+///
+/// By default a line is a double line `║`,
+/// with intersections taken from current style, or `+` if the style doesn't have them.
+/// It can be changed by [`VerticalSplit::line`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::style::{VerticalLine, VerticalSplit, Style}};
+///
+/// let data = [("x", 1, 2), ("y", 3, 4)];
+///
+/// let mut table = Table::new(data);
+/// table
+///     .with(Style::psql())
+///     .with(VerticalSplit::new(0))
+///     .with(VerticalSplit::new(1).line(VerticalLine::new('!').intersection('+')));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     " &str ║ i32 ! i32 \n\
+///      ------+-----+-----\n \
+///       x    ║ 1   ! 2   \n \
+///       y    ║ 3   ! 4   "
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VerticalSplit {
+    column: usize,
+    line: Option<Line<char>>,
+}
+
+impl VerticalSplit {
+    /// Creates a [`VerticalSplit`] which inserts a line after a given column.
+    pub const fn new(column: usize) -> Self {
+        Self { column, line: None }
+    }
+
+    /// Sets a line which will be used as a separator.
+    pub const fn line<T, B, I>(mut self, line: VerticalLine<T, B, I>) -> Self {
+        self.line = Some(line.into_inner());
+        self
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for VerticalSplit
+where
+    R: Records + ExactRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_columns = records.count_columns();
+        if self.column + 1 >= count_columns {
+            return;
+        }
+
+        let line = self.line.unwrap_or_else(|| {
+            let borders = cfg.get_borders();
+            let default_intersection = |border: Option<char>| border.map(|_| '+');
+
+            Line::new(
+                Some('║'),
+                borders
+                    .intersection
+                    .or_else(|| default_intersection(borders.horizontal)),
+                borders
+                    .top_intersection
+                    .or_else(|| default_intersection(borders.top)),
+                borders
+                    .bottom_intersection
+                    .or_else(|| default_intersection(borders.bottom)),
+            )
+        });
+
+        cfg.insert_vertical_line(self.column + 1, line);
+    }
+}
@@ -0,0 +1,215 @@
+//! This module contains a [`Summary`] setting which appends a row
+//! with values aggregated from columns.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, settings::summary::{Aggregate, Summary}};
+//!
+//! let data = [("Apple", 3), ("Pear", 5)];
+//!
+//! let mut table = Table::new(data);
+//! table.with(Summary::new().label(0, "Total").col(1, Aggregate::Sum));
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     "+-------+-----+\n\
+//!      | &str  | i32 |\n\
+//!      +-------+-----+\n\
+//!      | Apple | 3   |\n\
+//!      +-------+-----+\n\
+//!      | Pear  | 5   |\n\
+//!      +-------+-----+\n\
+//!      | Total | 8   |\n\
+//!      +-------+-----+"
+//! )
+//! ```
+
+use crate::{
+    grid::config::Position,
+    grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut, Resizable},
+    settings::TableOption,
+    util::string::parse_number,
+};
+
+/// A function which is used to aggregate a column values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Aggregate {
+    /// A sum of numeric values.
+    Sum,
+    /// An arithmetic mean of numeric values.
+    Mean,
+    /// A minimum of numeric values.
+    Min,
+    /// A maximum of numeric values.
+    Max,
+    /// An amount of not empty cells.
+    Count,
+}
+
+/// Summary appends a row with values aggregated from columns.
+///
+/// The first row is considered to be a header, so it's not aggregated.
+/// Cells which can't be parsed as a number are skipped.
+/// Numbers may have a `%` suffix, a currency symbol prefix and `,`/`_` thousands separators,
+/// while `NaN` and infinities are skipped.
+/// Columns which are not targeted are left empty.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{
+///     builder::Builder,
+///     settings::summary::{Aggregate, Summary},
+/// };
+///
+/// let mut builder = Builder::default();
+/// builder.push_record(["item", "price", "weight"]);
+/// builder.push_record(["Apple", "1.5", "0.2"]);
+/// builder.push_record(["Pear", "2", "unknown"]);
+/// builder.push_record(["Plum", "2", "0.1"]);
+///
+/// let summary = Summary::new()
+///     .label(0, "Total")
+///     .col(1, Aggregate::Sum)
+///     .col(2, Aggregate::Mean)
+///     .precision(2);
+///
+/// builder.push_summary(&summary);
+///
+/// let table = builder.build();
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+-------+-------+---------+\n\
+///      | item  | price | weight  |\n\
+///      +-------+-------+---------+\n\
+///      | Apple | 1.5   | 0.2     |\n\
+///      +-------+-------+---------+\n\
+///      | Pear  | 2     | unknown |\n\
+///      +-------+-------+---------+\n\
+///      | Plum  | 2     | 0.1     |\n\
+///      +-------+-------+---------+\n\
+///      | Total | 5.50  | 0.15    |\n\
+///      +-------+-------+---------+"
+/// )
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Summary {
+    columns: Vec<(usize, Aggregate)>,
+    labels: Vec<(usize, String)>,
+    precision: Option<usize>,
+}
+
+impl Summary {
+    /// Creates an empty [`Summary`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets an aggregate function for a column.
+    pub fn col(mut self, column: usize, aggregate: Aggregate) -> Self {
+        self.columns.push((column, aggregate));
+        self
+    }
+
+    /// Sets a text for a column, like `Total`.
+    pub fn label<S>(mut self, column: usize, text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.labels.push((column, text.into()));
+        self
+    }
+
+    /// Sets an amount of digits after a decimal point.
+    ///
+    /// By default a value is printed as is.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    pub(crate) fn create_record<'a, F>(
+        &self,
+        count_rows: usize,
+        count_columns: usize,
+        get_text: F,
+    ) -> Vec<String>
+    where
+        F: Fn(Position) -> &'a str,
+    {
+        let mut record = vec![String::new(); count_columns];
+
+        for (col, text) in &self.labels {
+            if let Some(cell) = record.get_mut(*col) {
+                *cell = text.clone();
+            }
+        }
+
+        for &(col, aggregate) in &self.columns {
+            if col >= count_columns {
+                continue;
+            }
+
+            let cells = (1..count_rows).map(|row| get_text((row, col)));
+            record[col] = aggregate_column(cells, aggregate, self.precision);
+        }
+
+        record
+    }
+}
+
+impl<R, D, C> TableOption<R, C, D> for Summary
+where
+    R: Records + ExactRecords + PeekableRecords + Resizable + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, _: &mut C, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+        if count_columns == 0 {
+            return;
+        }
+
+        let record = self.create_record(count_rows, count_columns, |pos| records.get_text(pos));
+
+        records.push_row();
+
+        for (col, text) in record.into_iter().enumerate() {
+            records.set((count_rows, col), text);
+        }
+    }
+}
+
+fn aggregate_column<'a, I>(cells: I, aggregate: Aggregate, precision: Option<usize>) -> String
+where
+    I: Iterator<Item = &'a str>,
+{
+    let numbers = |cells: I| cells.filter_map(|text| parse_number(text.trim()));
+
+    let value = match aggregate {
+        Aggregate::Sum => numbers(cells).reduce(|acc, value| acc + value),
+        Aggregate::Mean => {
+            let (sum, count) =
+                numbers(cells).fold((0.0, 0), |(sum, count), value| (sum + value, count + 1));
+
+            if count > 0 {
+                Some(sum / count as f64)
+            } else {
+                None
+            }
+        }
+        Aggregate::Min => numbers(cells).reduce(f64::min),
+        Aggregate::Max => numbers(cells).reduce(f64::max),
+        Aggregate::Count => {
+            let count = cells.filter(|text| !text.trim().is_empty()).count();
+            return count.to_string();
+        }
+    };
+
+    match (value, precision) {
+        (Some(value), Some(precision)) => format!("{:.*}", precision, value),
+        (Some(value), None) => value.to_string(),
+        (None, _) => String::new(),
+    }
+}
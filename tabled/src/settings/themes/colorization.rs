@@ -336,7 +336,7 @@ fn colorize_diogonals(
     }
 }
 
-fn colorize_entity(color: &Color, pos: Entity, cfg: &mut ColoredConfig) {
+pub(super) fn colorize_entity(color: &Color, pos: Entity, cfg: &mut ColoredConfig) {
     let ansi_color = ANSIBuf::from(color.clone());
     let _ = cfg.set_color(pos, ansi_color.clone());
     cfg.set_justification_color(pos, Some(ansi_color.clone()));
@@ -0,0 +1,259 @@
+use crate::{
+    grid::{
+        ansi::ANSIBuf,
+        config::{ColoredConfig, Entity},
+        records::{ExactRecords, PeekableRecords, Records},
+    },
+    settings::{themes::colorization::colorize_entity, Color, TableOption},
+};
+
+/// [`Heatmap`] colors cells of a numeric column on a gradient
+/// from one color for a minimum value to another one for a maximum value.
+///
+/// The header (the first row) is not colored.
+/// Data cells are parsed as [`f64`], the ones which are not numbers are left as they are.
+/// By default a minimum and a maximum are taken from the column itself,
+/// see [`Heatmap::min`] and [`Heatmap::max`] to set them explicitly.
+///
+/// By default it sets a background of a cell using a truecolor (24 bit) sequence,
+/// and it goes from green to red.
+///
+/// # Example
+///
+/// ```
+/// use std::iter::FromIterator;
+/// use tabled::{
+///     settings::themes::{Heatmap, Rgb, Scale},
+///     Table,
+/// };
+///
+/// let mut table = Table::from_iter([["host", "load"], ["a", "0.5"], ["b", "2.5"], ["c", "1.5"]]);
+/// table.with(
+///     Heatmap::column(1)
+///         .scale(Scale::linear())
+///         .colors(Rgb(0, 180, 0), Rgb(200, 0, 0))
+///         .foreground(),
+/// );
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+------+------+\n\
+///      | host | load |\n\
+///      +------+------+\n\
+///      | a    | \u{1b}[38;2;0;180;0m0.5\u{1b}[39m  |\n\
+///      +------+------+\n\
+///      | b    | \u{1b}[38;2;200;0;0m2.5\u{1b}[39m  |\n\
+///      +------+------+\n\
+///      | c    | \u{1b}[38;2;100;90;0m1.5\u{1b}[39m  |\n\
+///      +------+------+"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Heatmap {
+    column: usize,
+    scale: Scale,
+    from: Rgb,
+    to: Rgb,
+    min: Option<f64>,
+    max: Option<f64>,
+    foreground: bool,
+    depth: ColorDepth,
+}
+
+impl Heatmap {
+    /// Creates a [`Heatmap`] for a given column.
+    pub fn column(column: usize) -> Self {
+        Self {
+            column,
+            scale: Scale::linear(),
+            from: Rgb(0, 180, 0),
+            to: Rgb(200, 0, 0),
+            min: None,
+            max: None,
+            foreground: false,
+            depth: ColorDepth::TrueColor,
+        }
+    }
+
+    /// Sets a way values are mapped to the gradient.
+    pub fn scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets colors of a minimum and a maximum values.
+    pub fn colors(mut self, min: Rgb, max: Rgb) -> Self {
+        self.from = min;
+        self.to = max;
+        self
+    }
+
+    /// Sets a minimum value, lesser values get the color of the minimum.
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets a maximum value, bigger values get the color of the maximum.
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Colors a text instead of a background.
+    pub fn foreground(mut self) -> Self {
+        self.foreground = true;
+        self
+    }
+
+    /// Sets a kind of color sequences which are used,
+    /// it's supposed to be chosen according to a terminal capability.
+    pub fn depth(mut self, depth: ColorDepth) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    fn color(&self, rgb: Rgb) -> Color {
+        let (layer, reset) = if self.foreground { (38, 39) } else { (48, 49) };
+        let prefix = match self.depth {
+            ColorDepth::TrueColor => format!("\u{1b}[{};2;{};{};{}m", layer, rgb.0, rgb.1, rgb.2),
+            ColorDepth::Ansi256 => format!("\u{1b}[{};5;{}m", layer, rgb.to_ansi256()),
+        };
+
+        Color::new(prefix, format!("\u{1b}[{}m", reset))
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for Heatmap
+where
+    R: Records + ExactRecords + PeekableRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        if self.column >= records.count_columns() {
+            return;
+        }
+
+        let values = (1..records.count_rows())
+            .filter_map(|row| {
+                let text = records.get_text((row, self.column));
+                let value = parse_number(text)?;
+                self.scale.project(value).map(|value| (row, value))
+            })
+            .collect::<Vec<_>>();
+
+        let min = match self.min {
+            Some(min) => self.scale.project(min),
+            None => values.iter().map(|(_, v)| *v).reduce(f64::min),
+        };
+        let max = match self.max {
+            Some(max) => self.scale.project(max),
+            None => values.iter().map(|(_, v)| *v).reduce(f64::max),
+        };
+
+        let (min, max) = match (min, max) {
+            (Some(min), Some(max)) => (min, max),
+            _ => return,
+        };
+
+        for (row, value) in values {
+            let ratio = if max > min {
+                ((value - min) / (max - min)).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+
+            let color = self.color(self.from.interpolate(self.to, ratio));
+            let pos = Entity::Cell(row, self.column);
+            if self.foreground {
+                let _ = cfg.set_color(pos, ANSIBuf::from(color));
+            } else {
+                colorize_entity(&color, pos, cfg);
+            }
+        }
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
+
+/// A way values of a [`Heatmap`] are mapped to a gradient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Scale {
+    log: bool,
+}
+
+impl Scale {
+    /// A ratio of a value is proportional to its distance from a minimum.
+    pub fn linear() -> Self {
+        Self { log: false }
+    }
+
+    /// A ratio of a value is proportional to a distance of its logarithm from a logarithm of a minimum.
+    ///
+    /// Values which are not positive are left uncolored.
+    pub fn log() -> Self {
+        Self { log: true }
+    }
+
+    fn project(&self, value: f64) -> Option<f64> {
+        if !self.log {
+            return Some(value);
+        }
+
+        if value > 0.0 {
+            Some(value.ln())
+        } else {
+            None
+        }
+    }
+}
+
+/// A kind of ANSI color sequences.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorDepth {
+    /// A 24 bit color, like `\x1b[48;2;R;G;Bm`.
+    #[default]
+    TrueColor,
+    /// A color of a 256 colors palette, like `\x1b[48;5;Nm`.
+    ///
+    /// A color is approximated by the 6x6x6 color cube of the palette.
+    Ansi256,
+}
+
+/// A RGB color.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    fn interpolate(self, other: Rgb, ratio: f64) -> Rgb {
+        let channel = |a: u8, b: u8| {
+            let (a, b) = (f64::from(a), f64::from(b));
+            (a + (b - a) * ratio).round() as u8
+        };
+
+        Rgb(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+
+    fn to_ansi256(self) -> u8 {
+        // levels of the cube are 0, 95, 135, 175, 215, 255
+        let level = |c: u8| match c {
+            0..=47 => 0,
+            48..=114 => 1,
+            c => (c - 35) / 40,
+        };
+
+        16 + 36 * level(self.0) + 6 * level(self.1) + level(self.2)
+    }
+}
+
+fn parse_number(text: &str) -> Option<f64> {
+    text.trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+}
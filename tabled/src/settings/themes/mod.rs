@@ -4,8 +4,12 @@
 
 mod colorization;
 mod column_names;
+mod heatmap;
+mod row_style;
 mod theme;
 
 pub use colorization::{Colorization, ExactColorization};
 pub use column_names::ColumnNames;
+pub use heatmap::{ColorDepth, Heatmap, Rgb, Scale};
+pub use row_style::RowStyle;
 pub use theme::Theme;
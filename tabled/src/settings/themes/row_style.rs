@@ -0,0 +1,81 @@
+use crate::{
+    grid::{
+        config::{ColoredConfig, Entity},
+        records::{ExactRecords, PeekableRecords, Records},
+    },
+    settings::{themes::colorization::colorize_entity, Color, TableOption},
+};
+
+/// [`RowStyle`] colorizes whole rows based on their content.
+///
+/// A predicate is called for each row except the header (the first row)
+/// with texts of all its cells and returns a color for the row if any.
+///
+/// The texts are taken at the time the setting is applied,
+/// so it sees the results of previously applied settings like [`Format`].
+/// In the same way it overrides colors which were set earlier,
+/// e.g. by [`Colorization::rows`].
+///
+/// # Example
+///
+/// ```
+/// use std::iter::FromIterator;
+/// use tabled::{
+///     Table,
+///     settings::{themes::RowStyle, Color},
+/// };
+///
+/// let data = [
+///     ["name", "state"],
+///     ["build", "OK"],
+///     ["test", "FAILED"],
+///     ["lint", "SKIPPED"],
+/// ];
+///
+/// let mut table = Table::from_iter(data);
+/// table.with(RowStyle::when(|cells: &[&str]| match cells[1] {
+///     "FAILED" => Some(Color::FG_RED),
+///     "SKIPPED" => Some(Color::FG_BRIGHT_BLACK),
+///     _ => None,
+/// }));
+///
+/// println!("{table}");
+/// ```
+///
+/// [`Format`]: crate::settings::format::Format
+/// [`Colorization::rows`]: crate::settings::themes::Colorization::rows
+#[derive(Debug, Clone)]
+pub struct RowStyle<F> {
+    f: F,
+}
+
+impl<F> RowStyle<F> {
+    /// Creates a [`RowStyle`] with a given predicate.
+    pub fn when(f: F) -> Self
+    where
+        F: Fn(&[&str]) -> Option<Color>,
+    {
+        Self { f }
+    }
+}
+
+impl<R, D, F> TableOption<R, ColoredConfig, D> for RowStyle<F>
+where
+    R: Records + ExactRecords + PeekableRecords,
+    F: Fn(&[&str]) -> Option<Color>,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        let mut cells = Vec::with_capacity(count_columns);
+        for row in 1..count_rows {
+            cells.clear();
+            cells.extend((0..count_columns).map(|col| records.get_text((row, col))));
+
+            if let Some(color) = (self.f)(&cells) {
+                colorize_entity(&color, Entity::Row(row), cfg);
+            }
+        }
+    }
+}
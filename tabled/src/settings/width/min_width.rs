@@ -16,7 +16,7 @@ use crate::{
     },
 };
 
-use super::util::get_table_widths_with_total;
+use super::util::{get_table_widths_with_total, restore_hinted_texts};
 
 /// [`MinWidth`] changes a content in case if it's length is lower then the boundary.
 ///
@@ -109,6 +109,8 @@ where
     for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
 {
     fn change(self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        restore_hinted_texts(records, cfg, entity);
+
         let width = self.width.measure(&*records, cfg);
 
         let count_rows = records.count_rows();
@@ -35,7 +35,7 @@
 mod justify;
 mod min_width;
 mod truncate;
-mod util;
+pub(crate) mod util;
 mod width_list;
 mod wrap;
 
@@ -44,7 +44,7 @@ use crate::settings::measurement::Measurement;
 pub use self::{
     justify::Justify,
     min_width::MinWidth,
-    truncate::{SuffixLimit, Truncate},
+    truncate::{Cut, SuffixLimit, Truncate},
     width_list::WidthList,
     wrap::Wrap,
 };
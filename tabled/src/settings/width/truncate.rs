@@ -18,8 +18,8 @@ use crate::{
     },
 };
 
-use super::util::{get_table_widths, get_table_widths_with_total};
-use crate::util::string::cut_str;
+use super::util::{get_table_widths, get_table_widths_with_total, restore_hinted_texts};
+use crate::util::string::{cut_str, cut_str_left};
 
 /// Truncate cut the string to a given width if its length exceeds it.
 /// Otherwise keeps the content of a cell untouched.
@@ -28,6 +28,10 @@ use crate::util::string::cut_str;
 ///
 /// Be aware that it doesn't consider padding.
 /// So if you want to set a exact width you might need to use [`Padding`] to set it to 0.
+///
+/// Without a [`Truncate::suffix`] a text is just clipped, no indicator is added.
+/// A text is always cut on a character boundary,
+/// a wide character which doesn't fit is replaced by `\u{FFFD}`.
 ///    
 /// ## Example
 ///
@@ -44,6 +48,7 @@ pub struct Truncate<'a, W = usize, P = PriorityNone> {
     width: W,
     suffix: Option<TruncateSuffix<'a>>,
     multiline: bool,
+    cut: Cut,
     _priority: PhantomData<P>,
 }
 
@@ -84,6 +89,24 @@ pub enum SuffixLimit {
     Replace(char),
 }
 
+/// A side of a text which is cut off by [`Truncate`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Cut {
+    /// Cut the end of a text, keeping its beginning.
+    ///
+    /// A suffix is appended to the end.
+    #[default]
+    Right,
+    /// Cut the beginning of a text, keeping its end.
+    ///
+    /// A suffix is prepended to the beginning.
+    Left,
+    /// Cut the middle of a text, keeping both its beginning and its end.
+    ///
+    /// A suffix is put in the middle.
+    Middle,
+}
+
 impl<W> Truncate<'static, W>
 where
     W: Measurement<Width>,
@@ -94,6 +117,7 @@ where
             width,
             multiline: false,
             suffix: None,
+            cut: Cut::Right,
             _priority: PhantomData,
         }
     }
@@ -116,6 +140,7 @@ impl<'a, W, P> Truncate<'a, W, P> {
             width: self.width,
             multiline: self.multiline,
             suffix: Some(suff),
+            cut: self.cut,
             _priority: PhantomData,
         }
     }
@@ -129,6 +154,7 @@ impl<'a, W, P> Truncate<'a, W, P> {
             width: self.width,
             multiline: self.multiline,
             suffix: Some(suff),
+            cut: self.cut,
             _priority: PhantomData,
         }
     }
@@ -139,6 +165,41 @@ impl<'a, W, P> Truncate<'a, W, P> {
             width: self.width,
             multiline: true,
             suffix: self.suffix,
+            cut: self.cut,
+            _priority: self._priority,
+        }
+    }
+
+    /// Sets a side of a text which is cut off.
+    ///
+    /// By default it's [`Cut::Right`].
+    /// The width of a suffix is counted in either case.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{Modify, object::Cell, width::{Cut, Width}}};
+    ///
+    /// let data = [["/var/logs/today.log", "deadbeef0123456789"]];
+    ///
+    /// let mut table = Table::new(data);
+    /// table
+    ///     .with(Modify::new(Cell::new(1, 0)).with(Width::truncate(15).suffix("…").cut(Cut::Left)))
+    ///     .with(Modify::new(Cell::new(1, 1)).with(Width::truncate(11).suffix("…").cut(Cut::Middle)));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-----------------+-------------+\n\
+    ///      | 0               | 1           |\n\
+    ///      +-----------------+-------------+\n\
+    ///      | …logs/today.log | deadb…56789 |\n\
+    ///      +-----------------+-------------+"
+    /// );
+    /// ```
+    pub fn cut(self, cut: Cut) -> Truncate<'a, W, P> {
+        Truncate {
+            width: self.width,
+            multiline: self.multiline,
+            suffix: self.suffix,
+            cut,
             _priority: self._priority,
         }
     }
@@ -153,6 +214,7 @@ impl<'a, W, P> Truncate<'a, W, P> {
             width: self.width,
             multiline: self.multiline,
             suffix: Some(suff),
+            cut: self.cut,
             _priority: PhantomData,
         }
     }
@@ -172,6 +234,7 @@ impl<'a, W, P> Truncate<'a, W, P> {
             width: self.width,
             multiline: self.multiline,
             suffix: self.suffix,
+            cut: self.cut,
             _priority: PhantomData,
         }
     }
@@ -192,6 +255,8 @@ where
     for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
 {
     fn change(self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        restore_hinted_texts(records, cfg, entity);
+
         let available = self.width.measure(&*records, cfg);
 
         let mut width = available;
@@ -221,8 +286,15 @@ where
                 continue;
             }
 
-            let text =
-                truncate_multiline(text, &suffix, width, available, colorize, self.multiline);
+            let text = truncate_multiline(
+                text,
+                &suffix,
+                width,
+                available,
+                colorize,
+                self.multiline,
+                self.cut,
+            );
 
             records.set(pos, text.into_owned());
         }
@@ -236,6 +308,7 @@ fn truncate_multiline<'a>(
     twidth: usize,
     suffix_color: bool,
     multiline: bool,
+    cut: Cut,
 ) -> Cow<'a, str> {
     if multiline {
         let mut buf = String::new();
@@ -244,13 +317,13 @@ fn truncate_multiline<'a>(
                 buf.push('\n');
             }
 
-            let line = make_text_truncated(&line, suffix, width, twidth, suffix_color);
+            let line = make_text_truncated(&line, suffix, width, twidth, suffix_color, cut);
             buf.push_str(&line);
         }
 
         Cow::Owned(buf)
     } else {
-        make_text_truncated(text, suffix, width, twidth, suffix_color)
+        make_text_truncated(text, suffix, width, twidth, suffix_color, cut)
     }
 }
 
@@ -260,6 +333,7 @@ fn make_text_truncated<'a>(
     width: usize,
     twidth: usize,
     suffix_color: bool,
+    cut: Cut,
 ) -> Cow<'a, str> {
     if width == 0 {
         if twidth == 0 {
@@ -268,7 +342,18 @@ fn make_text_truncated<'a>(
             Cow::Borrowed(suffix)
         }
     } else {
-        truncate_text(text, width, suffix, suffix_color)
+        match cut {
+            Cut::Right => truncate_text(text, width, suffix, suffix_color),
+            Cut::Left => {
+                let content = cut_str_left(text, width);
+                Cow::Owned(format!("{}{}", suffix, content))
+            }
+            Cut::Middle => {
+                let head = cut_str(text, width - width / 2);
+                let tail = cut_str_left(text, width / 2);
+                Cow::Owned(format!("{}{}{}", head, suffix, tail))
+            }
+        }
     }
 }
 
@@ -335,8 +420,9 @@ where
 
         let priority = P::create();
         let multiline = self.multiline;
+        let cut = self.cut;
         let widths = truncate_total_width(
-            records, cfg, widths, total, width, priority, suffix, multiline,
+            records, cfg, widths, total, width, priority, suffix, multiline, cut,
         );
 
         dims.set_widths(widths);
@@ -353,6 +439,7 @@ fn truncate_total_width<P, R>(
     priority: P,
     suffix: Option<TruncateSuffix<'_>>,
     multiline: bool,
+    cut: Cut,
 ) -> Vec<usize>
 where
     P: Peaker,
@@ -373,6 +460,7 @@ where
         let mut truncate = Truncate::new(width);
         truncate.suffix.clone_from(&suffix);
         truncate.multiline = multiline;
+        truncate.cut = cut;
         CellOption::change(truncate, records, cfg, (row, col).into());
     }
 
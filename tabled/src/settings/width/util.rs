@@ -1,7 +1,7 @@
 use crate::{
-    grid::config::SpannedConfig,
+    grid::config::{ColoredConfig, Entity, SpannedConfig},
     grid::dimension::SpannedGridDimension,
-    grid::records::{IntoRecords, Records},
+    grid::records::{ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut},
 };
 
 pub(crate) fn get_table_widths<R>(records: R, cfg: &SpannedConfig) -> Vec<usize>
@@ -29,3 +29,24 @@ fn get_table_total_width(list: &[usize], cfg: &SpannedConfig) -> usize {
         + margin.left.size
         + margin.right.size
 }
+
+/// Restores original texts of cells which were changed by a column width hint,
+/// so a width setting is applied to the original text instead.
+///
+/// See [`Tabled::column_hints`].
+///
+/// [`Tabled::column_hints`]: crate::Tabled::column_hints
+pub(crate) fn restore_hinted_texts<R>(records: &mut R, cfg: &mut ColoredConfig, entity: Entity)
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    if !cfg.has_hinted_texts() {
+        return;
+    }
+
+    for pos in entity.iter(records.count_rows(), records.count_columns()) {
+        if let Some(text) = cfg.take_hinted_text(pos, records.get_text(pos)) {
+            records.set(pos, text);
+        }
+    }
+}
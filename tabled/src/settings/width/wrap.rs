@@ -11,7 +11,7 @@ use crate::{
         config::{ColoredConfig, Entity},
         dimension::CompleteDimensionVecRecords,
         records::{EmptyRecords, ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut},
-        util::string::string_width_multiline,
+        util::string::{string_width, string_width_multiline, CharWidth},
     },
     settings::{
         measurement::Measurement,
@@ -21,7 +21,7 @@ use crate::{
     },
 };
 
-use super::util::{get_table_widths, get_table_widths_with_total};
+use super::util::{get_table_widths, get_table_widths_with_total, restore_hinted_texts};
 use crate::util::string::split_at_width;
 
 /// Wrap wraps a string to a new line in case it exceeds the provided max boundary.
@@ -141,6 +141,8 @@ where
     for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
 {
     fn change(self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        restore_hinted_texts(records, cfg, entity);
+
         let width = self.width.measure(&*records, cfg);
 
         let count_rows = records.count_rows();
@@ -251,8 +253,9 @@ fn chunks(s: &str, width: usize) -> Vec<String> {
     let mut buf = String::with_capacity(width);
     let mut list = Vec::new();
     let mut i = 0;
+    let mut char_width = CharWidth::new();
     for c in s.chars() {
-        let c_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or_default();
+        let c_width = char_width.measure(c);
         if i + c_width > width {
             let count_unknowns = width - i;
             buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
@@ -308,7 +311,7 @@ fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
         while !text_slice.is_empty() {
             let available_space = width - line_width;
 
-            let part_width = unicode_width::UnicodeWidthStr::width(text_slice);
+            let part_width = string_width(text_slice);
             if part_width <= available_space {
                 line.push_str(text_slice);
                 line_width += part_width;
@@ -331,7 +334,7 @@ fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
             text_slice = &rhs[split_char..];
 
             line.push_str(lhs);
-            line_width += unicode_width::UnicodeWidthStr::width(lhs);
+            line_width += string_width(lhs);
 
             const REPLACEMENT: char = '\u{FFFD}';
             line.extend(std::iter::repeat(REPLACEMENT).take(unknowns));
@@ -385,7 +388,7 @@ fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
             is_first_word = false;
         }
 
-        let word_width = unicode_width::UnicodeWidthStr::width(word);
+        let word_width = string_width(word);
 
         let line_has_space = line_width + word_width <= width;
         if line_has_space {
@@ -416,7 +419,7 @@ fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
                     split_string_at(word_part, available_space);
 
                 word_part = &rhs[split_char..];
-                line_width += unicode_width::UnicodeWidthStr::width(lhs) + unknowns;
+                line_width += string_width(lhs) + unknowns;
                 is_first_word = false;
 
                 line.push_str(lhs);
@@ -449,6 +452,7 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
     let stripped_text = ansi_str::AnsiStr::ansi_strip(text);
     let mut word_width = 0;
     let mut word_chars = 0;
+    let mut char_width = CharWidth::new();
     let mut blocks = parsing::Blocks::new(ansi_str::get_blocks(text));
     let mut buf = parsing::MultilineBuffer::new(width);
     buf.set_prefix(prefix);
@@ -467,7 +471,7 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
                 word_width = 0;
             }
             _ => {
-                word_width += unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+                word_width += char_width.measure(c);
                 word_chars += 1;
             }
         }
@@ -486,6 +490,8 @@ mod parsing {
     use ansi_str::{AnsiBlock, AnsiBlockIter, Style};
     use std::fmt::Write;
 
+    use crate::grid::util::string::CharWidth;
+
     pub(super) struct Blocks<'a> {
         iter: AnsiBlockIter<'a>,
         current: Option<RelativeBlock<'a>>,
@@ -617,6 +623,7 @@ mod parsing {
         pub(super) fn read_chars(&mut self, block: &RelativeBlock<'_>, n: usize) -> (usize, usize) {
             let mut count_chars = 0;
             let mut count_bytes = 0;
+            let mut char_width = CharWidth::new();
             for c in block.get_text().chars() {
                 if count_chars == n {
                     break;
@@ -625,7 +632,7 @@ mod parsing {
                 count_chars += 1;
                 count_bytes += c.len_utf8();
 
-                let cwidth = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+                let cwidth = char_width.measure(c);
 
                 let available_space = self.width - self.width_last;
                 if available_space == 0 {
@@ -665,6 +672,7 @@ mod parsing {
         ) -> (usize, usize) {
             let mut count_chars = 0;
             let mut count_bytes = 0;
+            let mut char_width = CharWidth::new();
             for c in block.get_text().chars() {
                 if count_chars == n {
                     break;
@@ -673,7 +681,7 @@ mod parsing {
                 count_chars += 1;
                 count_bytes += c.len_utf8();
 
-                let cwidth = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+                let cwidth = char_width.measure(c);
                 self.width_last += cwidth;
 
                 self.buf.push(c);
@@ -12,12 +12,72 @@ pub trait Tabled {
     /// which must be the same.
     const LENGTH: usize;
 
+    /// Whether a type is a transparent wrapper of a single value,
+    /// which is set by `#[tabled(transparent)]` attribute.
+    ///
+    /// An empty header of such a type is replaced by a field name when it's inlined.
+    const TRANSPARENT: bool = false;
+
     /// Fields method must return a list of cells.
     ///
     /// The cells will be placed in the same row, preserving the order.
     fn fields(&self) -> Vec<Cow<'_, str>>;
     /// Headers must return a list of column names.
     fn headers() -> Vec<Cow<'static, str>>;
+
+    /// Returns width hints of columns, which are applied by [`Table::new`].
+    ///
+    /// The derive macro sets them by `#[tabled(width = N)]` attribute.
+    /// By default there are no hints.
+    ///
+    /// [`Table::new`]: crate::Table::new
+    fn column_hints() -> Vec<ColumnHint> {
+        Vec::new()
+    }
+}
+
+/// A width hint of a column, see [`Tabled::column_hints`].
+///
+/// A content of a column is wrapped or truncated to a given width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ColumnHint {
+    /// A column index.
+    pub column: usize,
+    /// A maximum width of a column.
+    pub width: usize,
+    /// Whether a content is wrapped, otherwise it's truncated.
+    pub wrap: bool,
+}
+
+/// A formatter of a field value, which is used by `#[tabled(with = "Formatter")]` attribute.
+///
+/// Unlike a function used by `display_with` it's a type,
+/// so a single formatter can be parametrized and shared by different fields.
+///
+#[cfg_attr(feature = "derive", doc = "```")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// use tabled::{CellFormatter, Tabled};
+///
+/// struct Precision<const N: usize>;
+///
+/// impl<const N: usize> CellFormatter<f64> for Precision<N> {
+///     fn format(value: &f64) -> String {
+///         format!("{:.1$}", value, N)
+///     }
+/// }
+///
+/// #[derive(Tabled)]
+/// struct Measure {
+///     #[tabled(with = "Precision<2>")]
+///     value: f64,
+/// }
+///
+/// let measure = Measure { value: 3.14159 };
+/// assert_eq!(measure.fields(), ["3.14"]);
+/// ```
+pub trait CellFormatter<T: ?Sized> {
+    /// Formats a value of a field.
+    fn format(value: &T) -> String;
 }
 
 impl<T> Tabled for &T
@@ -25,6 +85,7 @@ where
     T: Tabled,
 {
     const LENGTH: usize = T::LENGTH;
+    const TRANSPARENT: bool = T::TRANSPARENT;
 
     fn fields(&self) -> Vec<Cow<'_, str>> {
         T::fields(self)
@@ -32,6 +93,9 @@ where
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+    fn column_hints() -> Vec<ColumnHint> {
+        T::column_hints()
+    }
 }
 
 impl<T> Tabled for Box<T>
@@ -39,6 +103,7 @@ where
     T: Tabled,
 {
     const LENGTH: usize = T::LENGTH;
+    const TRANSPARENT: bool = T::TRANSPARENT;
 
     fn fields(&self) -> Vec<Cow<'_, str>> {
         T::fields(self)
@@ -46,6 +111,63 @@ where
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+    fn column_hints() -> Vec<ColumnHint> {
+        T::column_hints()
+    }
+}
+
+impl<T> Tabled for std::rc::Rc<T>
+where
+    T: Tabled,
+{
+    const LENGTH: usize = T::LENGTH;
+    const TRANSPARENT: bool = T::TRANSPARENT;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        T::fields(self)
+    }
+    fn headers() -> Vec<Cow<'static, str>> {
+        T::headers()
+    }
+    fn column_hints() -> Vec<ColumnHint> {
+        T::column_hints()
+    }
+}
+
+impl<T> Tabled for std::sync::Arc<T>
+where
+    T: Tabled,
+{
+    const LENGTH: usize = T::LENGTH;
+    const TRANSPARENT: bool = T::TRANSPARENT;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        T::fields(self)
+    }
+    fn headers() -> Vec<Cow<'static, str>> {
+        T::headers()
+    }
+    fn column_hints() -> Vec<ColumnHint> {
+        T::column_hints()
+    }
+}
+
+impl<T> Tabled for std::cmp::Reverse<T>
+where
+    T: Tabled,
+{
+    const LENGTH: usize = T::LENGTH;
+    const TRANSPARENT: bool = T::TRANSPARENT;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        T::fields(&self.0)
+    }
+    fn headers() -> Vec<Cow<'static, str>> {
+        T::headers()
+    }
+    fn column_hints() -> Vec<ColumnHint> {
+        T::column_hints()
+    }
 }
 
 macro_rules! tuple_table {
@@ -66,6 +188,18 @@ macro_rules! tuple_table {
                 $(fields.append(&mut $name::headers());)+
                 fields
             }
+
+            #[allow(unused_assignments)]
+            fn column_hints() -> Vec<ColumnHint> {
+                let mut hints = Vec::new();
+                let mut offset = 0;
+                $(
+                    let list = $name::column_hints().into_iter();
+                    hints.extend(list.map(|hint| ColumnHint { column: hint.column + offset, ..hint }));
+                    offset += $name::LENGTH;
+                )+
+                hints
+            }
         }
     };
 }
@@ -148,3 +282,46 @@ where
         (0..N).map(|i| Cow::Owned(format!("{i}"))).collect()
     }
 }
+
+#[cfg(feature = "chrono")]
+impl Tabled for chrono::NaiveDate {
+    const LENGTH: usize = 1;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        vec![Cow::Owned(self.to_string())]
+    }
+
+    fn headers() -> Vec<Cow<'static, str>> {
+        vec![Cow::Borrowed("NaiveDate")]
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Tabled for chrono::NaiveDateTime {
+    const LENGTH: usize = 1;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        vec![Cow::Owned(self.to_string())]
+    }
+
+    fn headers() -> Vec<Cow<'static, str>> {
+        vec![Cow::Borrowed("NaiveDateTime")]
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz> Tabled for chrono::DateTime<Tz>
+where
+    Tz: chrono::TimeZone,
+    Tz::Offset: std::fmt::Display,
+{
+    const LENGTH: usize = 1;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        vec![Cow::Owned(self.to_rfc3339())]
+    }
+
+    fn headers() -> Vec<Cow<'static, str>> {
+        vec![Cow::Borrowed("DateTime")]
+    }
+}
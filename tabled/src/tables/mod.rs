@@ -16,6 +16,10 @@
 //! ## [`PoolTable`]
 //!
 //! A table with a greater control of a layout.
+//!
+//! ## [`Scrollable`]
+//!
+//! A viewport over rows of a [`Table`] for interactive applications.
 
 mod compact;
 mod util;
@@ -25,6 +29,8 @@ mod extended;
 #[cfg(feature = "std")]
 mod iter;
 #[cfg(feature = "std")]
+mod scrollable;
+#[cfg(feature = "std")]
 mod table;
 #[cfg(feature = "std")]
 mod table_pool;
@@ -45,6 +51,10 @@ pub use extended::ExtendedTable;
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use table_pool::{PoolTable, TableValue};
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use scrollable::Scrollable;
+
 pub use compact::CompactTable;
 
 // todo: Create a PoolTable backend in papergrid with generics so it coulb be used differently
@@ -0,0 +1,171 @@
+//! This module contains a [`Scrollable`] viewport over a [`Table`].
+
+use crate::Table;
+
+/// [`Scrollable`] renders a fixed number of [`Table`] rows starting from a scroll position.
+///
+/// The first row of the table is considered to be a header,
+/// it's always rendered at the top of a viewport.
+/// A scroll position is an index of the first visible row, not counting the header.
+///
+/// The table is rendered as a whole and then sliced,
+/// so column widths don't change while scrolling.
+///
+/// It's handy for interactive (TUI) applications.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{tables::Scrollable, Table};
+///
+/// let data = [("Apple", 3), ("Banana", 1), ("Cherry", 20), ("Plum", 7)];
+///
+/// let mut view = Scrollable::new(Table::new(data));
+/// view.scroll_down();
+///
+/// assert_eq!(
+///     view.render(2),
+///     "+--------+-----+\n\
+///      | &str   | i32 |\n\
+///      +--------+-----+\n\
+///      | Banana | 1   |\n\
+///      +--------+-----+\n\
+///      | Cherry | 20  |\n\
+///      +--------+-----+"
+/// );
+///
+/// view.page_down(2);
+///
+/// assert_eq!(
+///     view.render(2),
+///     "+--------+-----+\n\
+///      | &str   | i32 |\n\
+///      +--------+-----+\n\
+///      | Plum   | 7   |\n\
+///      +--------+-----+"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scrollable {
+    table: Table,
+    scroll: usize,
+}
+
+impl Scrollable {
+    /// Creates a [`Scrollable`] scrolled to the first row.
+    pub fn new(table: Table) -> Self {
+        Self { table, scroll: 0 }
+    }
+
+    /// Returns a current scroll position.
+    pub fn scroll(&self) -> usize {
+        self.scroll
+    }
+
+    /// Returns a reference to the [`Table`].
+    pub fn get_table(&self) -> &Table {
+        &self.table
+    }
+
+    /// Returns the [`Table`].
+    pub fn into_table(self) -> Table {
+        self.table
+    }
+
+    /// Scrolls 1 row down.
+    ///
+    /// The last row can't be scrolled out of a viewport.
+    pub fn scroll_down(&mut self) {
+        self.page_down(1);
+    }
+
+    /// Scrolls 1 row up.
+    pub fn scroll_up(&mut self) {
+        self.page_up(1);
+    }
+
+    /// Scrolls `height` rows down.
+    ///
+    /// The last row can't be scrolled out of a viewport.
+    pub fn page_down(&mut self, height: usize) {
+        let max = self.table.count_rows().saturating_sub(2);
+        self.scroll = std::cmp::min(self.scroll.saturating_add(height), max);
+    }
+
+    /// Scrolls `height` rows up.
+    pub fn page_up(&mut self, height: usize) {
+        self.scroll = self.scroll.saturating_sub(height);
+    }
+
+    /// Renders a header and `viewport_height` rows starting from the scroll position.
+    ///
+    /// A line after a header is used as a split line between a header and the rows,
+    /// and the bottom line of the table is kept.
+    /// When `viewport_height` is `0` only a header is rendered, followed by the bottom line.
+    pub fn render(&self, viewport_height: usize) -> String {
+        let count_rows = self.table.count_rows();
+        if count_rows < 2 || self.table.count_columns() == 0 {
+            return self.table.to_string();
+        }
+
+        let cfg = self.table.get_config();
+        let heights = self.table.estimate_heights();
+
+        // an index of a horizontal line above a row, and a range of row lines
+        let mut layout = Vec::with_capacity(count_rows);
+        let margin = cfg.get_margin().top.size;
+        let mut line = margin;
+        for height in &heights {
+            let split = if cfg.has_horizontal(layout.len(), count_rows) {
+                line += 1;
+                Some(line - 1)
+            } else {
+                None
+            };
+
+            layout.push((split, line..line + height));
+            line += height;
+        }
+
+        let table = self.table.to_string();
+        let lines = table.lines().collect::<Vec<_>>();
+
+        let first = std::cmp::min(self.scroll + 1, count_rows - 1);
+        let last = std::cmp::min(first + viewport_height, count_rows);
+
+        let (top, header) = layout[0].clone();
+        let (split, _) = layout[1].clone();
+
+        let mut visible = Vec::new();
+        visible.extend(0..margin);
+        visible.extend(top);
+        visible.extend(header);
+
+        // a header split line must not be rendered right before the bottom line
+        if last > first {
+            visible.extend(split);
+        }
+
+        let rows = layout.into_iter().enumerate().take(last).skip(first);
+        for (row, (split, range)) in rows {
+            if row > first {
+                visible.extend(split);
+            }
+
+            visible.extend(range);
+        }
+
+        visible.extend(line..lines.len());
+
+        let mut buf = String::with_capacity(table.len());
+        for (i, index) in visible.into_iter().enumerate() {
+            if i > 0 {
+                buf.push('\n');
+            }
+
+            buf.push_str(lines[index]);
+        }
+
+        buf
+    }
+}
@@ -1,7 +1,12 @@
 //! This module contains a main table representation [`Table`].
 
 use core::ops::DerefMut;
-use std::{borrow::Cow, fmt, iter::FromIterator};
+use std::{
+    borrow::Cow,
+    cmp, fmt,
+    hash::{Hash, Hasher},
+    iter::FromIterator,
+};
 
 use crate::{
     builder::Builder,
@@ -14,14 +19,24 @@ use crate::{
         dimension::{CompleteDimensionVecRecords, Dimension, Estimate, PeekableDimension},
         records::{
             vec_records::{CellInfo, VecRecords},
-            ExactRecords, Records,
+            ExactRecords, PeekableRecords, Records,
         },
+        util::string::string_width,
         PeekableGrid,
     },
-    settings::{object::Object, CellOption, Style, TableOption},
-    Tabled,
+    settings::{
+        object::{Columns, Object, Rows},
+        panel::{caption_lines, Caption, ColumnGroups},
+        themes::Colorization,
+        Alignment, CellOption, Color, DynCellOption, Panel, Span, Style, TableOption, Width,
+    },
+    tables::util::utf8_writer::UTF8Writer,
+    util::string::is_number,
+    ColumnHint, Tabled,
 };
 
+type BoxedCellOption = Box<dyn DynCellOption<VecRecords<CellInfo<String>>, ColoredConfig>>;
+
 /// The structure provides an interface for building a table for types that implements [`Tabled`].
 ///
 /// To build a string representation of a table you must use a [`std::fmt::Display`].
@@ -67,6 +82,10 @@ impl Table {
     ///
     /// If you use a reference iterator you'd better use [`FromIterator`] instead.
     /// As it has a different lifetime constraints and make less copies therefore.
+    ///
+    /// Width hints of [`Tabled::column_hints`] are applied to the columns right away.
+    /// A width setting applied to a cell afterwards is applied to its original text,
+    /// unless the cell was changed since or rows or columns were added or removed.
     pub fn new<I, T>(iter: I) -> Self
     where
         I: IntoIterator<Item = T>,
@@ -94,11 +113,17 @@ impl Table {
 
         let records = VecRecords::new(records);
 
-        Self {
+        let mut table = Self {
             records,
             config: ColoredConfig::new(configure_grid()),
             dimension: CompleteDimensionVecRecords::default(),
+        };
+
+        for hint in T::column_hints() {
+            apply_column_hint(&mut table, hint);
         }
+
+        table
     }
 
     /// Creates a builder from a data set given.
@@ -166,9 +191,205 @@ impl Table {
         builder
     }
 
+    /// Creates a [`Table`] with a given shape, running a function for each cell.
+    ///
+    /// The function gets a row and a column index,
+    /// and it's called once per cell in a row-major order.
+    ///
+    /// Records are filled directly, without any intermediate allocations.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::from_fn(2, 3, |row, col| ((row + 1) * (col + 1)).to_string());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+---+---+---+\n\
+    ///      | 1 | 2 | 3 |\n\
+    ///      +---+---+---+\n\
+    ///      | 2 | 4 | 6 |\n\
+    ///      +---+---+---+"
+    /// )
+    /// ```
+    pub fn from_fn<F>(count_rows: usize, count_columns: usize, f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> String,
+    {
+        let data = create_records(count_rows, count_columns, None::<fn(usize) -> String>, f);
+
+        Self {
+            records: VecRecords::new(data),
+            config: ColoredConfig::new(configure_grid()),
+            dimension: CompleteDimensionVecRecords::default(),
+        }
+    }
+
+    /// Creates a [`Table`] with a given shape, running a function for each cell,
+    /// and a header which is created by a separate function.
+    ///
+    /// The header function gets a column index.
+    /// The header is not counted in `count_rows`.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::from_fn_with_header(
+    ///     2,
+    ///     2,
+    ///     |col| format!("x{}", col + 1),
+    ///     |row, col| ((row + 1) * (col + 1)).to_string(),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+----+----+\n\
+    ///      | x1 | x2 |\n\
+    ///      +----+----+\n\
+    ///      | 1  | 2  |\n\
+    ///      +----+----+\n\
+    ///      | 2  | 4  |\n\
+    ///      +----+----+"
+    /// )
+    /// ```
+    pub fn from_fn_with_header<H, F>(
+        count_rows: usize,
+        count_columns: usize,
+        header: H,
+        f: F,
+    ) -> Self
+    where
+        H: FnMut(usize) -> String,
+        F: FnMut(usize, usize) -> String,
+    {
+        let data = create_records(count_rows, count_columns, Some(header), f);
+
+        Self {
+            records: VecRecords::new(data),
+            config: ColoredConfig::new(configure_grid()),
+            dimension: CompleteDimensionVecRecords::default(),
+        }
+    }
+
+    /// Creates a [`Table`] from a header and a list of rows.
+    ///
+    /// Rows of different length are padded with empty cells the same way [`Builder`] does it.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::from_rows_and_headers(
+    ///     ["lang", "year"],
+    ///     [["Rust", "2015"], ["Go", "2009"]],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+------+\n\
+    ///      | lang | year |\n\
+    ///      +------+------+\n\
+    ///      | Rust | 2015 |\n\
+    ///      +------+------+\n\
+    ///      | Go   | 2009 |\n\
+    ///      +------+------+"
+    /// )
+    /// ```
+    pub fn from_rows_and_headers<H, I, R>(headers: H, rows: I) -> Self
+    where
+        H: IntoIterator,
+        H::Item: Into<String>,
+        I: IntoIterator<Item = R>,
+        R: IntoIterator,
+        R::Item: Into<String>,
+    {
+        let mut builder = Builder::default();
+        builder.push_record(headers);
+
+        for row in rows {
+            builder.push_record(row);
+        }
+
+        builder.build()
+    }
+
+    /// Creates a [`Table`] with `key` and `value` columns from a list of pairs.
+    ///
+    /// Pairs are kept in the given order, including ones with the same key.
+    /// See [`Builder::from_key_value_pairs`] to change a header or sort pairs.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let port = 8080;
+    /// let table = Table::from_pairs(vec![("host", "localhost".to_string()), ("port", port.to_string())]);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+-----------+\n\
+    ///      | key  | value     |\n\
+    ///      +------+-----------+\n\
+    ///      | host | localhost |\n\
+    ///      +------+-----------+\n\
+    ///      | port | 8080      |\n\
+    ///      +------+-----------+"
+    /// )
+    /// ```
+    pub fn from_pairs<I, K, V>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        Builder::from_key_value_pairs(pairs, "key", "value").build()
+    }
+
+    /// Creates a [`Table`] from dynamic records using a [`Schema`].
+    ///
+    /// A header is made from the schema headers,
+    /// and cells of each record are produced by the schema accessors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SchemaError`] if an amount of accessors is different from an amount of headers.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use tabled::{builder::Schema, Table};
+    ///
+    /// let rows = vec![HashMap::from([("id", 1), ("size", 20)])];
+    ///
+    /// let schema = Schema::new(["ID"]).field(|row: &HashMap<&str, i32>| row["id"].to_string());
+    /// let table = Table::with_schema(&schema, &rows).unwrap();
+    ///
+    /// assert_eq!(table.to_string(), "+----+\n| ID |\n+----+\n| 1  |\n+----+");
+    ///
+    /// let schema = Schema::new(["ID", "Size"]).field(|row: &HashMap<&str, i32>| row["id"].to_string());
+    /// assert!(Table::with_schema(&schema, &rows).is_err());
+    /// ```
+    ///
+    /// [`Schema`]: crate::builder::Schema
+    /// [`SchemaError`]: crate::builder::SchemaError
+    pub fn with_schema<'a, T, I>(
+        schema: &crate::builder::Schema<'_, T>,
+        rows: I,
+    ) -> Result<Self, crate::builder::SchemaError>
+    where
+        I: IntoIterator<Item = &'a T>,
+        T: 'a,
+    {
+        schema.build(rows)
+    }
+
     /// It's a generic function which applies options to the [`Table`].
     ///
     /// It applies settings immediately.
+    /// Yet a layout is not recalculated here,
+    /// it's invalidated and estimated once the table is rendered or measured.
+    ///
+    /// Each content formatting visits all cells it targets,
+    /// see [`FormatContent::then`] to compose a few of them into a single pass.
+    ///
+    /// [`FormatContent::then`]: crate::settings::format::FormatContent::then
     pub fn with<O>(&mut self, option: O) -> &mut Self
     where
         for<'a> O: TableOption<
@@ -179,9 +400,15 @@ impl Table {
     {
         let reastimation_hint = option.hint_change();
         let mut dims = self.dimension.from_origin();
+        let (count_rows, count_columns) = self.shape();
 
         option.change(&mut self.records, &mut self.config, &mut dims);
 
+        // positions of cells changed by width hints are not valid anymore
+        if self.count_columns() != count_columns || self.count_rows() != count_rows {
+            self.config.clear_hinted_texts();
+        }
+
         let (widths, heights) = dims.into_inner();
         dimension_reastimate(&mut self.dimension, widths, heights, reastimation_hint);
 
@@ -212,6 +439,324 @@ impl Table {
         self
     }
 
+    /// Applies an option to a single column of the [`Table`].
+    ///
+    /// It's a shortcut for [`Table::modify`] with [`Columns::single`].
+    /// A few options can be applied at once by combining them with [`Settings`],
+    /// or by passing a [`Vec`] of options of the same type,
+    /// see [`Table::with_settings_list_for_column`] for a list of options of different types.
+    ///
+    /// Only options which can be scoped to cells are accepted,
+    /// table wide ones like [`Style`] have to be applied by [`Table::with`].
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use tabled::{settings::{Alignment, Padding, Settings}, Table};
+    ///
+    /// let mut table = Table::from_iter([["name", "total"], ["Alex", "120"], ["Sam", "7"]]);
+    /// table.with_settings_for_column(1, Settings::new(Alignment::right(), Padding::new(2, 1, 0, 0)));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+--------+\n\
+    ///      | name |  total |\n\
+    ///      +------+--------+\n\
+    ///      | Alex |    120 |\n\
+    ///      +------+--------+\n\
+    ///      | Sam  |      7 |\n\
+    ///      +------+--------+"
+    /// );
+    /// ```
+    ///
+    /// [`Settings`]: crate::settings::Settings
+    pub fn with_settings_for_column<O>(&mut self, col: usize, option: O) -> &mut Self
+    where
+        O: CellOption<VecRecords<CellInfo<String>>, ColoredConfig> + Clone,
+    {
+        self.modify(Columns::single(col), option)
+    }
+
+    /// Applies an option to a single row of the [`Table`].
+    ///
+    /// It's a shortcut for [`Table::modify`] with [`Rows::single`],
+    /// see [`Table::with_settings_for_column`] for details.
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use tabled::{settings::Alignment, Table};
+    ///
+    /// let mut table = Table::from_iter([["name", "total"], ["Alex", "120"]]);
+    /// table.with_settings_for_row(1, vec![Alignment::center(), Alignment::bottom()]);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+-------+\n\
+    ///      | name | total |\n\
+    ///      +------+-------+\n\
+    ///      | Alex |  120  |\n\
+    ///      +------+-------+"
+    /// );
+    /// ```
+    pub fn with_settings_for_row<O>(&mut self, row: usize, option: O) -> &mut Self
+    where
+        O: CellOption<VecRecords<CellInfo<String>>, ColoredConfig> + Clone,
+    {
+        self.modify(Rows::single(row), option)
+    }
+
+    /// Applies a list of options of different types to a single column of the [`Table`],
+    /// in the given order.
+    ///
+    /// See [`Table::with_settings_for_column`].
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use tabled::{
+    ///     grid::{config::ColoredConfig, records::vec_records::{CellInfo, VecRecords}},
+    ///     settings::{Alignment, DynCellOption, Padding, Width},
+    ///     Table,
+    /// };
+    ///
+    /// type Setting = Box<dyn DynCellOption<VecRecords<CellInfo<String>>, ColoredConfig>>;
+    ///
+    /// let settings: Vec<Setting> = vec![
+    ///     Box::new(Width::truncate(3)),
+    ///     Box::new(Alignment::right()),
+    ///     Box::new(Padding::new(2, 1, 0, 0)),
+    /// ];
+    ///
+    /// let mut table = Table::from_iter([["name", "total"], ["Alex", "120"], ["Sam", "7"]]);
+    /// table.with_settings_list_for_column(1, settings);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+------+\n\
+    ///      | name |  tot |\n\
+    ///      +------+------+\n\
+    ///      | Alex |  120 |\n\
+    ///      +------+------+\n\
+    ///      | Sam  |    7 |\n\
+    ///      +------+------+"
+    /// );
+    /// ```
+    pub fn with_settings_list_for_column<I>(&mut self, col: usize, options: I) -> &mut Self
+    where
+        I: IntoIterator<Item = BoxedCellOption>,
+    {
+        self.modify_list(Columns::single(col), options)
+    }
+
+    /// Applies a list of options of different types to a single row of the [`Table`],
+    /// in the given order.
+    ///
+    /// See [`Table::with_settings_list_for_column`].
+    pub fn with_settings_list_for_row<I>(&mut self, row: usize, options: I) -> &mut Self
+    where
+        I: IntoIterator<Item = BoxedCellOption>,
+    {
+        self.modify_list(Rows::single(row), options)
+    }
+
+    fn modify_list<T, I>(&mut self, target: T, options: I) -> &mut Self
+    where
+        T: Object<VecRecords<CellInfo<String>>>,
+        I: IntoIterator<Item = BoxedCellOption>,
+    {
+        let entities = target.cells(&self.records).collect::<Vec<_>>();
+        for option in options {
+            for &entity in &entities {
+                option.change_copy(&mut self.records, &mut self.config, entity);
+            }
+
+            dimension_reastimate_likely(&mut self.dimension, option.hint_change());
+        }
+
+        self
+    }
+
+    /// Colors rows of the [`Table`] one by one with 2 colors.
+    ///
+    /// Rows are indexed from `0`, and a header is considered to be a row `0`,
+    /// so it gets the `even` color.
+    ///
+    /// It's a shortcut for [`Colorization::rows`].
+    ///
+    /// ```
+    /// use tabled::{Table, settings::Color};
+    ///
+    /// let mut table = Table::new([1, 2]);
+    /// table.with_zebra_coloring(Color::BG_WHITE, Color::BG_BLACK);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-----+\n\
+    ///      |\u{1b}[47m \u{1b}[49m\u{1b}[47mi32\u{1b}[49m\u{1b}[47m \u{1b}[49m|\n\
+    ///      +-----+\n\
+    ///      |\u{1b}[40m \u{1b}[49m\u{1b}[40m1\u{1b}[49m\u{1b}[40m  \u{1b}[49m\u{1b}[40m \u{1b}[49m|\n\
+    ///      +-----+\n\
+    ///      |\u{1b}[47m \u{1b}[49m\u{1b}[47m2\u{1b}[49m\u{1b}[47m  \u{1b}[49m\u{1b}[47m \u{1b}[49m|\n\
+    ///      +-----+"
+    /// );
+    /// ```
+    ///
+    /// [`Colorization::rows`]: crate::settings::themes::Colorization::rows
+    pub fn with_zebra_coloring(&mut self, even: Color, odd: Color) -> &mut Self {
+        self.with(Colorization::rows([even, odd]))
+    }
+
+    /// Removes all borders and split lines of the [`Table`],
+    /// so columns are separated by spaces only.
+    ///
+    /// It's a shortcut for [`Style::blank`].
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use tabled::Table;
+    ///
+    /// let mut table = Table::from_iter([["name", "age"], ["Alex", "20"]]);
+    /// table.with_no_borders();
+    ///
+    /// assert_eq!(table.to_string(), " name   age \n Alex   20  ");
+    /// ```
+    pub fn with_no_borders(&mut self) -> &mut Self {
+        self.with(Style::blank())
+    }
+
+    /// Colors a header (the first row) of the [`Table`].
+    ///
+    /// ```
+    /// use tabled::{Table, settings::Color};
+    ///
+    /// let mut table = Table::new([1, 2]);
+    /// table.with_header_color(Color::FG_GREEN);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-----+\n\
+    ///      |\u{1b}[32m \u{1b}[39m\u{1b}[32mi32\u{1b}[39m\u{1b}[32m \u{1b}[39m|\n\
+    ///      +-----+\n\
+    ///      | 1   |\n\
+    ///      +-----+\n\
+    ///      | 2   |\n\
+    ///      +-----+"
+    /// );
+    /// ```
+    pub fn with_header_color(&mut self, color: Color) -> &mut Self {
+        self.with(Colorization::exact([color], Rows::first()))
+    }
+
+    /// Sets an alignment of each column based on its content.
+    ///
+    /// Cells of the first row (a header) are not inspected,
+    /// as well as empty cells.
+    ///
+    /// - A column is right aligned when all its cells are numbers,
+    ///   which are recognized the same way as by [`AutoAlign::numbers`].
+    /// - A column is center aligned when all its cells are either `true` or `false`.
+    /// - Otherwise an alignment of a column is left as it is.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let data = [("Apple", 3, true), ("Pear", 15, false)];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with_auto_alignment();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+-----+-------+\n\
+    ///      | &str  | i32 | bool  |\n\
+    ///      +-------+-----+-------+\n\
+    ///      | Apple |   3 | true  |\n\
+    ///      +-------+-----+-------+\n\
+    ///      | Pear  |  15 | false |\n\
+    ///      +-------+-----+-------+"
+    /// );
+    /// ```
+    ///
+    /// [`AutoAlign::numbers`]: crate::settings::AutoAlign::numbers
+    pub fn with_auto_alignment(&mut self) -> &mut Self {
+        let count_columns = self.count_columns();
+
+        let mut has_values = vec![false; count_columns];
+        let mut is_numeric = vec![true; count_columns];
+        let mut is_bool = vec![true; count_columns];
+
+        for row in self.records.iter().skip(1) {
+            for (col, cell) in row.iter().enumerate() {
+                let text = cell.as_ref().trim();
+                if text.is_empty() {
+                    continue;
+                }
+
+                has_values[col] = true;
+                is_numeric[col] &= is_number(text);
+                is_bool[col] &= text == "true" || text == "false";
+            }
+        }
+
+        for col in 0..count_columns {
+            let alignment = if has_values[col] && is_numeric[col] {
+                Alignment::right()
+            } else if has_values[col] && is_bool[col] {
+                Alignment::center()
+            } else {
+                continue;
+            };
+
+            let _ = self.modify(Columns::single(col), alignment);
+        }
+
+        self
+    }
+
+    /// Adds a column with sequential numbers of rows at the beginning of the [`Table`].
+    ///
+    /// Rows are numbered starting from `start`, skipping the first row (a header),
+    /// which gets the `header` text; usually it's `"#"`.
+    ///
+    /// Unlike [`Builder::index`] it can be used on an already built [`Table`].
+    /// Notice that the indexes of the rest columns are shifted by 1.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let data = [("Apple", 3), ("Pear", 15)];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with_numbered_rows(1, "#");
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+---+-------+-----+\n\
+    ///      | # | &str  | i32 |\n\
+    ///      +---+-------+-----+\n\
+    ///      | 1 | Apple | 3   |\n\
+    ///      +---+-------+-----+\n\
+    ///      | 2 | Pear  | 15  |\n\
+    ///      +---+-------+-----+"
+    /// );
+    /// ```
+    ///
+    /// [`Builder::index`]: crate::builder::Builder::index
+    pub fn with_numbered_rows(&mut self, start: usize, header: &str) -> &mut Self {
+        let count_rows = self.count_rows();
+        if count_rows == 0 {
+            return self;
+        }
+
+        let _ = self.with(Panel::vertical(0, header));
+        let _ = self.modify((0, 0), Span::row(1));
+
+        for row in 1..count_rows {
+            let number = start + row - 1;
+            let _ = self.modify((row, 0), number.to_string());
+        }
+
+        self
+    }
+
     /// Returns a table shape (count rows, count columns).
     pub fn shape(&self) -> (usize, usize) {
         (self.count_rows(), self.count_columns())
@@ -227,13 +772,56 @@ impl Table {
         self.records.count_columns()
     }
 
-    /// Returns a table shape (count rows, count columns).
+    /// Checks whether the table has no cells at all.
+    ///
+    /// An empty table is rendered as an empty string regardless of a [`Style`].
+    /// A table with only a header is not empty, it's rendered as a framed header,
+    /// see [`Table::has_data_rows`] to check it.
+    ///
+    /// ```
+    /// use tabled::{builder::Builder, settings::Style, Table};
+    ///
+    /// let mut table = Builder::default().build();
+    /// table.with(Style::rounded());
+    ///
+    /// assert!(table.is_empty());
+    /// assert_eq!(table.to_string(), "");
+    ///
+    /// let mut table = Table::new(Vec::<(u8, &str)>::new());
+    /// table.with(Style::rounded());
+    ///
+    /// assert!(!table.is_empty());
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "╭────┬──────╮\n\
+    ///      │ u8 │ &str │\n\
+    ///      ╰────┴──────╯"
+    /// );
+    /// ```
     pub fn is_empty(&self) -> bool {
         let (count_rows, count_cols) = self.shape();
         count_rows == 0 || count_cols == 0
     }
 
-    /// Returns total widths of a table, including margin and horizontal lines.
+    /// Checks whether the table has any rows besides a header (the first row).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new(Vec::<(u32, &str)>::new());
+    /// assert!(!table.is_empty());
+    /// assert!(!table.has_data_rows());
+    ///
+    /// let table = Table::new([(1, "build")]);
+    /// assert!(table.has_data_rows());
+    /// ```
+    pub fn has_data_rows(&self) -> bool {
+        self.count_rows() > 1 && self.count_columns() > 0
+    }
+
+    /// Returns total widths of a table, including margin, horizontal lines and captions.
     pub fn total_height(&self) -> usize {
         let mut dims = CompleteDimensionVecRecords::from_origin(&self.dimension);
         dims.estimate(&self.records, self.config.as_ref());
@@ -241,11 +829,13 @@ impl Table {
         let total = (0..self.count_rows())
             .map(|row| dims.get_height(row))
             .sum::<usize>();
-        let counth = self.config.count_horizontal(self.count_rows());
+        let counth = self.config.count_horizontal(self.count_rows())
+            * self.config.get_horizontal_line_repeat();
 
         let margin = self.config.get_margin();
+        let (above, below) = count_caption_lines(self);
 
-        total + counth + margin.top.size + margin.bottom.size
+        total + counth + margin.top.size + margin.bottom.size + above + below
     }
 
     /// Returns total widths of a table, including margin and vertical lines.
@@ -263,6 +853,420 @@ impl Table {
         total + countv + margin.left.size + margin.right.size
     }
 
+    /// Returns a copy of the table with column widths
+    /// which are a maximum of its own and a given table column widths.
+    ///
+    /// It's useful to print related tables aligned with each other.
+    /// Columns which are not present in the `other` table are left as is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use tabled::Table;
+    ///
+    /// let before = Table::from_iter([["id", "name"], ["1", "Alexander"]]);
+    /// let after = Table::from_iter([["identifier", "name"], ["1", "Alex"]]);
+    ///
+    /// assert_eq!(
+    ///     before.apply_width_to_match(&after).to_string(),
+    ///     "+------------+-----------+\n\
+    ///      | id         | name      |\n\
+    ///      +------------+-----------+\n\
+    ///      | 1          | Alexander |\n\
+    ///      +------------+-----------+"
+    /// );
+    /// assert_eq!(
+    ///     after.apply_width_to_match(&before).to_string(),
+    ///     "+------------+-----------+\n\
+    ///      | identifier | name      |\n\
+    ///      +------------+-----------+\n\
+    ///      | 1          | Alex      |\n\
+    ///      +------------+-----------+"
+    /// );
+    /// ```
+    pub fn apply_width_to_match(&self, other: &Table) -> Table {
+        let mut widths = self.column_widths();
+        let other_widths = other.column_widths();
+
+        for (width, other) in widths.iter_mut().zip(other_widths) {
+            *width = cmp::max(*width, other);
+        }
+
+        let mut table = self.clone();
+        let _ = table.with(Width::list(widths));
+
+        table
+    }
+
+    /// Returns widths of columns as they are rendered.
+    ///
+    /// A width includes left and right [`Padding`] of a column,
+    /// but doesn't include vertical lines and margins.
+    /// So it can be used to synchronize columns of different tables.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use tabled::{settings::{object::Columns, Padding}, Table};
+    ///
+    /// let mut table = Table::from_iter([["id", "name"], ["1", "Alexander"]]);
+    /// assert_eq!(table.column_widths(), [4, 11]);
+    ///
+    /// table.modify(Columns::first(), Padding::new(3, 0, 0, 0));
+    /// assert_eq!(table.column_widths(), [5, 11]);
+    /// ```
+    ///
+    /// [`Padding`]: crate::settings::Padding
+    pub fn column_widths(&self) -> Vec<usize> {
+        let mut dims = CompleteDimensionVecRecords::from_origin(&self.dimension);
+        dims.estimate(&self.records, self.config.as_ref());
+
+        (0..self.count_columns())
+            .map(|col| dims.get_width(col))
+            .collect()
+    }
+
+    pub(crate) fn estimate_heights(&self) -> Vec<usize> {
+        let mut dims = CompleteDimensionVecRecords::from_origin(&self.dimension);
+        dims.estimate(&self.records, self.config.as_ref());
+
+        (0..self.count_rows())
+            .map(|row| dims.get_height(row))
+            .collect()
+    }
+
+    /// Format table into [`io::Write`]r.
+    ///
+    /// The output is written directly into the writer,
+    /// without building an intermediate [`String`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use tabled::Table;
+    ///
+    /// let table = Table::from_iter([["a", "b"]]);
+    ///
+    /// let mut buf = Vec::new();
+    /// table.write_to(&mut buf).unwrap();
+    ///
+    /// assert_eq!(buf, table.to_string().into_bytes());
+    /// ```
+    ///
+    /// [`io::Write`]: std::io::Write
+    pub fn write_to<W>(&self, writer: W) -> std::io::Result<()>
+    where
+        W: std::io::Write,
+    {
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        let mut writer = UTF8Writer::new(writer);
+        let config = use_table_configuration(self);
+        print_records(&mut writer, self, &config).map_err(|_| {
+            writer
+                .take_error()
+                .unwrap_or_else(|| std::io::Error::from(std::io::ErrorKind::Other))
+        })
+    }
+
+    /// Format table into a given [`String`] appending it to its content.
+    pub fn fmt_into(&self, buf: &mut String) {
+        fmt::Write::write_fmt(buf, format_args!("{}", self))
+            .expect("according to a doc is safe to fmt() a string");
+    }
+
+    /// Returns an approximate amount of bytes a rendered table takes.
+    ///
+    /// It's accurate for tables with ASCII text and borders,
+    /// while multibyte characters and colors make an output bigger.
+    /// It can be used to pre allocate a buffer.
+    pub fn rendered_size_hint(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+
+        let height = self.total_height();
+        let width = self.total_width();
+
+        (width + 1) * height - 1
+    }
+
+    /// Renders the table without its header (the first row).
+    ///
+    /// Column widths are still calculated using the header,
+    /// so the output is aligned with the output of a whole table.
+    /// It's handy for a paged output where the header printed only once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use tabled::Table;
+    ///
+    /// let table = Table::from_iter([["name", "age"], ["Alex", "20"]]);
+    ///
+    /// assert_eq!(
+    ///     table.to_string_without_header(),
+    ///     "+------+-----+\n\
+    ///      | Alex | 20  |\n\
+    ///      +------+-----+"
+    /// );
+    /// ```
+    pub fn to_string_without_header(&self) -> String {
+        let count_rows = self.count_rows();
+        if count_rows < 2 || self.count_columns() == 0 {
+            return String::new();
+        }
+
+        let mut dims = CompleteDimensionVecRecords::from_origin(&self.dimension);
+        dims.estimate(&self.records, self.config.as_ref());
+
+        let margin = self.config.get_margin().top.size;
+        let has_top = self.config.has_horizontal(0, count_rows);
+        let has_split = self.config.has_horizontal(1, count_rows);
+        let header_height = dims.get_height(0);
+        let repeat = self.config.get_horizontal_line_repeat();
+
+        let (captions, _) = count_caption_lines(self);
+
+        let skip_from = margin + captions + has_top as usize * repeat;
+        let skip_to = skip_from + header_height + has_split as usize * repeat;
+
+        let table = self.to_string();
+        let lines = table
+            .lines()
+            .enumerate()
+            .filter(|(i, _)| *i < skip_from || *i >= skip_to)
+            .map(|(_, line)| line);
+
+        let mut buf = String::with_capacity(table.len());
+        for (i, line) in lines.enumerate() {
+            if i > 0 {
+                buf.push('\n');
+            }
+
+            buf.push_str(line);
+        }
+
+        buf
+    }
+
+    /// Renders the table and removes all ANSI escape sequences from the output.
+    ///
+    /// All settings are applied, including colors,
+    /// so the output has the same layout as a colored one.
+    /// Sequences are recognized the same way they are when a width of a text is measured.
+    ///
+    /// It's handy when an output is written to a log or a file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use tabled::{settings::{object::Rows, Color}, Table};
+    ///
+    /// let mut table = Table::from_iter([["name", "age"], ["Alex", "20"]]);
+    /// table.modify(Rows::first(), Color::FG_RED);
+    ///
+    /// assert_eq!(
+    ///     table.to_ansi_stripped(),
+    ///     "+------+-----+\n\
+    ///      | name | age |\n\
+    ///      +------+-----+\n\
+    ///      | Alex | 20  |\n\
+    ///      +------+-----+"
+    /// );
+    /// ```
+    #[cfg(feature = "ansi")]
+    pub fn to_ansi_stripped(&self) -> String {
+        crate::grid::util::string::strip_ansi(&self.to_string())
+    }
+
+    /// Renders the table with each cell filled with spaces,
+    /// so only borders and split lines are left.
+    ///
+    /// Cells keep their widths and heights,
+    /// so the output has exactly the same layout as the output of the table itself.
+    /// It's handy for debugging a border configuration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use tabled::{settings::Style, Table};
+    ///
+    /// let mut table = Table::from_iter([["name", "age"], ["Alex", "20"]]);
+    /// table.with(Style::modern());
+    ///
+    /// assert_eq!(
+    ///     table.render_borders_only(),
+    ///     "┌──────┬─────┐\n\
+    ///      │      │     │\n\
+    ///      ├──────┼─────┤\n\
+    ///      │      │     │\n\
+    ///      └──────┴─────┘"
+    /// );
+    /// ```
+    pub fn render_borders_only(&self) -> String {
+        if self.is_empty() {
+            return String::new();
+        }
+
+        let mut dims = CompleteDimensionVecRecords::from_origin(&self.dimension);
+        dims.estimate(&self.records, self.config.as_ref());
+
+        let records = self
+            .records
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| CellInfo::new(blank_text(cell.as_ref())))
+                    .collect()
+            })
+            .collect();
+        let records = VecRecords::new(records);
+
+        let mut buf = String::new();
+        print_table(
+            &mut buf,
+            &records,
+            self.config.as_ref(),
+            dims,
+            self.config.get_colors(),
+            self.config.get_horizontal_line_repeat(),
+            &[],
+        )
+        .expect("according to a doc is safe to fmt() a string");
+
+        buf
+    }
+
+    /// Returns a table with a window of columns which fits a given width.
+    ///
+    /// The `frozen` columns are always kept, they're put first.
+    /// The rest of columns are taken starting from `start_col` while they fit the `width_budget`,
+    /// which is a width of a whole table including borders and a margin.
+    /// If columns are cut off on either side, a column of `…` is put in their place.
+    ///
+    /// It's handy for a horizontally scrolled view of a wide table.
+    /// Column widths are measured on the whole table, so a column has the same width in any window.
+    ///
+    /// Settings of the table are kept, yet settings of particular columns or cells
+    /// are bound to positions, so they're not moved together with columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use tabled::Table;
+    ///
+    /// let table = Table::from_iter([
+    ///     ["id", "name", "city", "country", "zip"],
+    ///     ["1", "Alex", "Berlin", "Germany", "10115"],
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     table.columns_window(2, 33, &[0]).to_string(),
+    ///     "+----+---+--------+---------+---+\n\
+    ///      | id | … | city   | country | … |\n\
+    ///      +----+---+--------+---------+---+\n\
+    ///      | 1  | … | Berlin | Germany | … |\n\
+    ///      +----+---+--------+---------+---+"
+    /// );
+    /// ```
+    pub fn columns_window(&self, start_col: usize, width_budget: usize, frozen: &[usize]) -> Table {
+        let count_columns = self.count_columns();
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        let widths = self.column_widths();
+
+        let mut frozen = frozen
+            .iter()
+            .copied()
+            .filter(|&col| col < count_columns)
+            .collect::<Vec<_>>();
+        frozen.sort_unstable();
+        frozen.dedup();
+
+        let scrolled = (0..count_columns)
+            .filter(|col| !frozen.contains(col))
+            .collect::<Vec<_>>();
+        let start = scrolled
+            .iter()
+            .position(|&col| col >= start_col)
+            .unwrap_or(scrolled.len());
+
+        let margin = self.config.get_margin();
+        let margin = margin.left.size + margin.right.size;
+        let padding = self.config.get_padding(Entity::Global);
+        let indicator_width =
+            string_width(COLUMNS_CUT_INDICATOR) + padding.left.size + padding.right.size;
+        let total_width = |count_columns: usize, width: usize| {
+            width + self.config.count_vertical(count_columns) + margin
+        };
+
+        let is_cut_left = start > 0;
+        let mut width = frozen.iter().map(|&col| widths[col]).sum::<usize>();
+        let mut count = frozen.len();
+        if is_cut_left {
+            width += indicator_width;
+            count += 1;
+        }
+
+        let mut end = start;
+        while end < scrolled.len() {
+            let is_last = end + 1 == scrolled.len();
+            let (indicator, indicator_width) = if is_last {
+                (0, 0)
+            } else {
+                (1, indicator_width)
+            };
+
+            let next_width = width + widths[scrolled[end]];
+            if total_width(count + 1 + indicator, next_width + indicator_width) > width_budget {
+                break;
+            }
+
+            width = next_width;
+            count += 1;
+            end += 1;
+        }
+
+        let is_cut_right = end < scrolled.len();
+
+        let records = self
+            .records
+            .iter()
+            .map(|row| {
+                let mut record = Vec::with_capacity(count + 1);
+                record.extend(frozen.iter().map(|&col| row[col].clone()));
+                if is_cut_left {
+                    record.push(CellInfo::new(COLUMNS_CUT_INDICATOR.to_owned()));
+                }
+
+                record.extend(scrolled[start..end].iter().map(|&col| row[col].clone()));
+                if is_cut_right {
+                    record.push(CellInfo::new(COLUMNS_CUT_INDICATOR.to_owned()));
+                }
+
+                record
+            })
+            .collect();
+
+        Table {
+            records: VecRecords::new(records),
+            config: self.config.clone(),
+            dimension: CompleteDimensionVecRecords::default(),
+        }
+    }
+
     /// Returns a table config.
     pub fn get_config(&self) -> &ColoredConfig {
         &self.config
@@ -294,6 +1298,23 @@ impl Default for Table {
     }
 }
 
+/// A hash is calculated only from a text of cells (including a header), row by row.
+///
+/// Styles and other settings are not taken into account.
+impl Hash for Table {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.records.len().hash(state);
+
+        for row in self.records.iter() {
+            row.len().hash(state);
+
+            for cell in row {
+                cell.as_ref().hash(state);
+            }
+        }
+    }
+}
+
 impl fmt::Display for Table {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.is_empty() {
@@ -301,19 +1322,42 @@ impl fmt::Display for Table {
         }
 
         let config = use_format_configuration(f, self);
-        let colors = self.config.get_colors();
+        print_records(f, self, &config)
+    }
+}
 
-        if !self.dimension.is_empty() {
-            let mut dims = self.dimension.clone();
-            dims.estimate(&self.records, config.as_ref());
+/// Returns an amount of caption lines above and below a table.
+fn count_caption_lines(table: &Table) -> (usize, usize) {
+    let captions = table.config.get_captions();
+    if captions.is_empty() {
+        return (0, 0);
+    }
 
-            print_grid(f, &self.records, &config, &dims, colors)
-        } else {
-            let mut dims = PeekableDimension::default();
-            dims.estimate(&self.records, &config);
+    let (above, below) = caption_lines(
+        captions,
+        &table.config,
+        table.count_columns(),
+        table.total_width(),
+    );
 
-            print_grid(f, &self.records, &config, &dims, colors)
-        }
+    (above.len(), below.len())
+}
+
+fn print_records<F: fmt::Write>(f: &mut F, table: &Table, config: &SpannedConfig) -> fmt::Result {
+    let colors = table.config.get_colors();
+    let repeat = table.config.get_horizontal_line_repeat();
+    let captions = table.config.get_captions();
+
+    if !table.dimension.is_empty() {
+        let mut dims = table.dimension.clone();
+        dims.estimate(&table.records, config);
+
+        print_table(f, &table.records, config, &dims, colors, repeat, captions)
+    } else {
+        let mut dims = PeekableDimension::default();
+        dims.estimate(&table.records, config);
+
+        print_table(f, &table.records, config, &dims, colors, repeat, captions)
     }
 }
 
@@ -329,14 +1373,39 @@ where
 
 impl From<Builder> for Table {
     fn from(builder: Builder) -> Self {
+        let (builder, hints) = builder.take_alignment_hints();
+        let (builder, max_widths) = builder.take_column_max_widths();
+        let (builder, groups) = builder.take_column_groups();
+        let count_columns = builder.count_columns();
+
         let data = builder.into();
-        let records = VecRecords::new(data);
+        let mut records = VecRecords::new(data);
 
-        Self {
+        let mut config = ColoredConfig::new(configure_grid());
+        for (col, alignment) in hints {
+            if col < count_columns {
+                CellOption::change(alignment, &mut records, &mut config, Entity::Column(col));
+            }
+        }
+
+        for (col, width, ellipsis) in max_widths {
+            if col < count_columns {
+                let truncate = Width::truncate(width).suffix(ellipsis.as_str());
+                CellOption::change(truncate, &mut records, &mut config, Entity::Column(col));
+            }
+        }
+
+        let mut table = Self {
             records,
-            config: ColoredConfig::new(configure_grid()),
+            config,
             dimension: CompleteDimensionVecRecords::default(),
+        };
+
+        if !groups.is_empty() {
+            let _ = table.with(ColumnGroups::from_groups(groups));
         }
+
+        table
     }
 }
 
@@ -371,6 +1440,63 @@ impl<R, D> TableOption<R, ColoredConfig, D> for &SpannedConfig {
     }
 }
 
+fn create_records<H, F>(
+    count_rows: usize,
+    count_columns: usize,
+    header: Option<H>,
+    mut f: F,
+) -> Vec<Vec<CellInfo<String>>>
+where
+    H: FnMut(usize) -> String,
+    F: FnMut(usize, usize) -> String,
+{
+    let mut data = Vec::with_capacity(count_rows + header.is_some() as usize);
+
+    if let Some(mut header) = header {
+        let row = (0..count_columns).map(|col| CellInfo::new(header(col)));
+        data.push(row.collect());
+    }
+
+    for row in 0..count_rows {
+        let mut list = Vec::with_capacity(count_columns);
+        for col in 0..count_columns {
+            list.push(CellInfo::new(f(row, col)));
+        }
+
+        data.push(list);
+    }
+
+    data
+}
+
+/// Applies a column width hint, remembering original texts of changed cells,
+/// so a width setting applied later is applied to the original texts.
+fn apply_column_hint(table: &mut Table, hint: ColumnHint) {
+    if hint.column >= table.count_columns() {
+        return;
+    }
+
+    let originals = (0..table.count_rows())
+        .map(|row| table.records.get_text((row, hint.column)).to_owned())
+        .collect::<Vec<_>>();
+
+    let column = Columns::single(hint.column);
+    if hint.wrap {
+        let _ = table.modify(column, Width::wrap(hint.width));
+    } else {
+        let _ = table.modify(column, Width::truncate(hint.width));
+    }
+
+    for (row, original) in originals.into_iter().enumerate() {
+        let pos = (row, hint.column);
+        let text = table.records.get_text(pos);
+        if text != original {
+            let text = text.to_owned();
+            table.config.set_hinted_text(pos, original, text);
+        }
+    }
+}
+
 fn convert_fmt_alignment(alignment: fmt::Alignment) -> AlignmentHorizontal {
     match alignment {
         fmt::Alignment::Left => AlignmentHorizontal::Left,
@@ -413,11 +1539,27 @@ fn use_format_configuration<'a>(
     f: &mut fmt::Formatter<'_>,
     table: &'a Table,
 ) -> Cow<'a, SpannedConfig> {
-    if f.align().is_some() || f.width().is_some() {
+    let cfg = use_table_configuration(table);
+    if f.align().is_none() && f.width().is_none() {
+        return cfg;
+    }
+
+    let mut cfg = cfg.into_owned();
+    set_align_table(f, &mut cfg);
+    set_width_table(f, &mut cfg, table);
+
+    Cow::Owned(cfg)
+}
+
+fn use_table_configuration(table: &Table) -> Cow<'_, SpannedConfig> {
+    let is_single_row = table.count_rows() == 1 && table.config.get_borders().has_bottom();
+    let is_bottom_overridden = is_single_row && table.config.is_preset_header_line();
+
+    if is_bottom_overridden {
         let mut cfg = table.config.as_ref().clone();
 
-        set_align_table(f, &mut cfg);
-        set_width_table(f, &mut cfg, table);
+        // a line under a header of a preset style must not replace a bottom border of a header only table
+        cfg.remove_horizontal_line(1, 1);
 
         Cow::Owned(cfg)
     } else {
@@ -467,6 +1609,164 @@ fn set_width_table(f: &fmt::Formatter<'_>, cfg: &mut SpannedConfig, table: &Tabl
     }
 }
 
+const COLUMNS_CUT_INDICATOR: &str = "…";
+
+fn blank_text(text: &str) -> String {
+    let lines = text
+        .split('\n')
+        .map(|line| " ".repeat(string_width(line)))
+        .collect::<Vec<_>>();
+
+    lines.join("\n")
+}
+
+fn print_table<F: fmt::Write, D: Dimension>(
+    f: &mut F,
+    records: &VecRecords<CellInfo<String>>,
+    cfg: &SpannedConfig,
+    dims: D,
+    colors: &ColorMap,
+    repeat: usize,
+    captions: &[Caption],
+) -> fmt::Result {
+    if repeat < 2 && captions.is_empty() {
+        return print_grid(f, records, cfg, dims, colors);
+    }
+
+    let count_rows = records.count_rows();
+    let count_columns = records.count_columns();
+    let margin = cfg.get_margin();
+
+    let mut index = margin.top.size;
+    let mut lines = Vec::with_capacity(count_rows + 1);
+    for row in 0..count_rows + 1 {
+        if cfg.has_horizontal(row, count_rows) {
+            lines.push(index);
+            index += 1;
+        }
+
+        if row < count_rows {
+            index += dims.get_height(row);
+        }
+    }
+
+    let count_lines = index + margin.bottom.size;
+
+    let mut inserts = Vec::new();
+    if !captions.is_empty() {
+        let total_width = (0..count_columns)
+            .map(|col| dims.get_width(col))
+            .sum::<usize>()
+            + cfg.count_vertical(count_columns)
+            + margin.left.size
+            + margin.right.size;
+
+        let (above, below) = caption_lines(captions, cfg, count_columns, total_width);
+        let top = cmp::min(margin.top.size, count_lines);
+        let bottom = count_lines.saturating_sub(margin.bottom.size);
+
+        inserts.push((top, above));
+        inserts.push((bottom, below));
+    }
+
+    let mut writer = LineWriter {
+        f,
+        line: 0,
+        is_started: false,
+        repeat,
+        repeated_lines: lines,
+        repeated_text: String::new(),
+        inserts,
+    };
+
+    writer.start_line()?;
+    print_grid(&mut writer, records, cfg, &dims, colors)?;
+    writer.finish()
+}
+
+/// A writer which repeats given lines of a grid and puts caption lines in between the grid lines,
+/// while passing the grid output straight through.
+struct LineWriter<'a, F> {
+    f: &'a mut F,
+    line: usize,
+    is_started: bool,
+    repeat: usize,
+    repeated_lines: Vec<usize>,
+    repeated_text: String,
+    inserts: Vec<(usize, Vec<String>)>,
+}
+
+impl<F: fmt::Write> LineWriter<'_, F> {
+    fn start_line(&mut self) -> fmt::Result {
+        let index = self.line;
+        for (_, lines) in self.inserts.iter().filter(|(i, _)| *i == index) {
+            for line in lines {
+                if self.is_started {
+                    self.f.write_char('\n')?;
+                }
+
+                self.f.write_str(line)?;
+                self.is_started = true;
+            }
+        }
+
+        if self.is_started {
+            self.f.write_char('\n')?;
+        }
+
+        self.is_started = true;
+
+        Ok(())
+    }
+
+    fn end_line(&mut self) -> fmt::Result {
+        if self.repeat > 1 && self.repeated_lines.contains(&self.line) {
+            for _ in 1..self.repeat {
+                self.f.write_char('\n')?;
+                self.f.write_str(&self.repeated_text)?;
+            }
+
+            self.repeated_text.clear();
+        }
+
+        Ok(())
+    }
+
+    fn finish(mut self) -> fmt::Result {
+        self.end_line()?;
+
+        let index = self.line;
+        for (_, lines) in self.inserts.iter().filter(|(i, _)| *i > index) {
+            for line in lines {
+                self.f.write_char('\n')?;
+                self.f.write_str(line)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<F: fmt::Write> fmt::Write for LineWriter<'_, F> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for (i, part) in s.split('\n').enumerate() {
+            if i > 0 {
+                self.end_line()?;
+                self.line += 1;
+                self.start_line()?;
+            }
+
+            self.f.write_str(part)?;
+
+            if self.repeat > 1 && self.repeated_lines.contains(&self.line) {
+                self.repeated_text.push_str(part);
+            }
+        }
+
+        Ok(())
+    }
+}
+
 fn print_grid<F: fmt::Write, D: Dimension>(
     f: &mut F,
     records: &VecRecords<CellInfo<String>>,
@@ -1,11 +1,22 @@
 use std::fmt;
 use std::io;
 
-pub(crate) struct UTF8Writer<W>(W);
+pub(crate) struct UTF8Writer<W> {
+    writer: W,
+    error: Option<io::Error>,
+}
 
 impl<W> UTF8Writer<W> {
     pub(crate) fn new(writer: W) -> Self {
-        Self(writer)
+        Self {
+            writer,
+            error: None,
+        }
+    }
+
+    /// Returns an [`io::Error`] which made a write fail, if any.
+    pub(crate) fn take_error(&mut self) -> Option<io::Error> {
+        self.error.take()
     }
 }
 
@@ -14,16 +25,9 @@ where
     W: io::Write,
 {
     fn write_str(&mut self, s: &str) -> fmt::Result {
-        let mut buf = s.as_bytes();
-        loop {
-            let n = self.0.write(buf).map_err(|_| fmt::Error)?;
-            if n == buf.len() {
-                break;
-            }
-
-            buf = &buf[n..];
-        }
-
-        Ok(())
+        self.writer.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
     }
 }
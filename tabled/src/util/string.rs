@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::grid::util::string::CharWidth;
+
 /// The function cuts the string to a specific width.
 /// Preserving colors with `ansi` feature on.
 pub(crate) fn split_str(s: &str, width: usize) -> (Cow<'_, str>, Cow<'_, str>) {
@@ -67,6 +69,48 @@ pub(crate) fn cut_str(s: &str, width: usize) -> Cow<'_, str> {
     }
 }
 
+/// The function cuts the string to a specific width, keeping its end.
+/// Preserving colors with `ansi` feature on.
+pub(crate) fn cut_str_left(s: &str, width: usize) -> Cow<'_, str> {
+    const REPLACEMENT: char = '\u{FFFD}';
+
+    #[cfg(feature = "ansi")]
+    let (buf, cutwidth) = {
+        let stripped = ansi_str::AnsiStr::ansi_strip(s);
+        let start = left_cut_position(&stripped, width);
+        let cutwidth = crate::grid::util::string::string_width(&stripped[start..]);
+        (ansi_str::AnsiStr::ansi_cut(s, start..), cutwidth)
+    };
+
+    #[cfg(not(feature = "ansi"))]
+    let (buf, cutwidth) = {
+        let start = left_cut_position(s, width);
+        let cutwidth = crate::grid::util::string::string_width(&s[start..]);
+        (Cow::Borrowed(&s[start..]), cutwidth)
+    };
+
+    if cutwidth >= width {
+        return buf;
+    }
+
+    let mut b = REPLACEMENT.to_string().repeat(width - cutwidth);
+    b.push_str(&buf);
+
+    Cow::Owned(b)
+}
+
+/// Returns a byte position from which a text has a given width or less,
+/// skipping a char which would have to be split.
+fn left_cut_position(text: &str, width: usize) -> usize {
+    let text_width = crate::grid::util::string::string_width(text);
+    if text_width <= width {
+        return 0;
+    }
+
+    let (length, _, csize) = split_at_width(text, text_width - width);
+    length + csize
+}
+
 /// The function cuts the string to a specific width.
 /// While not preserving ansi sequences.
 pub(crate) fn cut_str2(text: &str, width: usize) -> Cow<'_, str> {
@@ -92,14 +136,16 @@ pub(crate) fn cut_str2(text: &str, width: usize) -> Cow<'_, str> {
 pub(crate) fn split_at_width(s: &str, at_width: usize) -> (usize, usize, usize) {
     let mut length = 0;
     let mut width = 0;
+    let mut char_width = CharWidth::new();
     for c in s.chars() {
-        if width == at_width {
+        let c_width = char_width.measure(c);
+        let c_length = c.len_utf8();
+
+        // zero width chars (like a joined emoji) are kept with a previous char
+        if width == at_width && c_width > 0 {
             break;
         };
 
-        let c_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or_default();
-        let c_length = c.len_utf8();
-
         // We cut the chars which takes more then 1 symbol to display,
         // in order to archive the necessary width.
         if width + c_width > at_width {
@@ -176,6 +222,43 @@ pub(crate) fn strip_osc(text: &str) -> (String, Option<String>) {
     }
 }
 
+const CURRENCY_SYMBOLS: [char; 5] = ['$', '€', '£', '¥', '₽'];
+
+/// Checks whether a text is a number.
+///
+/// See [`parse_number`] for a list of recognized formats.
+pub(crate) fn is_number(text: &str) -> bool {
+    parse_number(text).is_some()
+}
+
+/// Parses a number from a text.
+///
+/// Integers and floats are recognized,
+/// as well as the ones with a `%` suffix, a currency symbol prefix
+/// and `,`/`_` thousands separators.
+/// `NaN` and infinities are not considered to be numbers.
+pub(crate) fn parse_number(text: &str) -> Option<f64> {
+    let text = text.strip_suffix('%').unwrap_or(text);
+
+    let (sign, text) = match text.strip_prefix(|c| c == '-' || c == '+') {
+        Some(rest) => (&text[..1], rest),
+        None => ("", text),
+    };
+
+    let text = text.trim_start_matches(&CURRENCY_SYMBOLS[..]);
+    if !text.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+        return None;
+    }
+
+    let number = text.chars().filter(|&c| c != ',' && c != '_');
+    let number = sign.chars().chain(number).collect::<String>();
+
+    number
+        .parse::<f64>()
+        .ok()
+        .filter(|number| number.is_finite())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,6 +268,38 @@ mod tests {
     #[cfg(feature = "ansi")]
     use owo_colors::{colors::Yellow, OwoColorize};
 
+    #[test]
+    fn is_number_test() {
+        assert!(is_number("12"));
+        assert!(is_number("-1.5"));
+        assert!(is_number(".5"));
+        assert!(is_number("1e3"));
+        assert!(is_number("15%"));
+        assert!(is_number("$1,200.50"));
+        assert!(is_number("-€3"));
+        assert!(is_number("1_000"));
+
+        assert!(!is_number(""));
+        assert!(!is_number("abc"));
+        assert!(!is_number("NaN"));
+        assert!(!is_number("inf"));
+        assert!(!is_number("-infinity"));
+        assert!(!is_number("1e999"));
+        assert!(!is_number("$"));
+    }
+
+    #[test]
+    fn parse_number_test() {
+        assert_eq!(parse_number("12"), Some(12.0));
+        assert_eq!(parse_number("-$1,200.50"), Some(-1200.5));
+        assert_eq!(parse_number("15%"), Some(15.0));
+        assert_eq!(parse_number("1_000"), Some(1000.0));
+
+        assert_eq!(parse_number("NaN"), None);
+        assert_eq!(parse_number("inf"), None);
+        assert_eq!(parse_number("abc"), None);
+    }
+
     #[test]
     fn strip_test() {
         assert_eq!(cut_str("123456", 0), "");
@@ -199,8 +314,8 @@ mod tests {
         assert_eq!(cut_str("😳😳😳😳😳", 20), "😳😳😳😳😳");
 
         assert_eq!(cut_str("🏳️🏳️", 0), "");
-        assert_eq!(cut_str("🏳️🏳️", 1), "🏳");
-        assert_eq!(cut_str("🏳️🏳️", 2), "🏳\u{fe0f}🏳");
+        assert_eq!(cut_str("🏳️🏳️", 1), "🏳\u{fe0f}");
+        assert_eq!(cut_str("🏳️🏳️", 2), "🏳\u{fe0f}🏳\u{fe0f}");
         assert_eq!(string_width("🏳️🏳️"), string_width("🏳\u{fe0f}🏳\u{fe0f}"));
 
         assert_eq!(cut_str("🎓", 1), "�");
@@ -251,11 +366,11 @@ mod tests {
         let emojies = "🏳️🏳️".red().on_bright_black().to_string();
 
         assert_eq!(cut_str(&emojies, 0), "\u{1b}[31;100m\u{1b}[39m\u{1b}[49m");
-        assert_eq!(cut_str(&emojies, 1), "\u{1b}[31;100m🏳\u{1b}[39m\u{1b}[49m");
         assert_eq!(
-            cut_str(&emojies, 2),
-            "\u{1b}[31;100m🏳\u{fe0f}🏳\u{1b}[39m\u{1b}[49m"
+            cut_str(&emojies, 1),
+            "\u{1b}[31;100m🏳\u{fe0f}\u{1b}[39m\u{1b}[49m"
         );
+        assert_eq!(cut_str(&emojies, 2), emojies);
         assert_eq!(
             string_width(&emojies),
             string_width("\u{1b}[31;100m🏳\u{fe0f}🏳\u{fe0f}\u{1b}[39m\u{1b}[49m")
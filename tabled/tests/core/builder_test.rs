@@ -1,10 +1,23 @@
 #![cfg(feature = "std")]
 
-use std::iter::FromIterator;
+use std::{collections::HashSet, iter::FromIterator};
 
-use tabled::builder::Builder;
+use tabled::{
+    builder::{
+        Builder, CellDiff, ColumnCountMismatch, ColumnGroupError, JoinType, LatexOptions,
+        ParseError, RaggedRecord, TsvPolicy, WidthHazard,
+    },
+    grid::{
+        colors::NoColors,
+        dimension::{Estimate, PeekableDimension},
+        records::{vec_records::CellInfo, ExactRecords, PeekableRecords, Records},
+        PeekableGrid,
+    },
+    settings::{Alignment, AutoAlign},
+    Table,
+};
 
-use testing_table::test_table;
+use testing_table::{static_table, test_table};
 
 test_table!(
     push_record,
@@ -425,6 +438,61 @@ test_table!(
     "+---+---+---+"
 );
 
+test_table!(
+    push_empty_array_record_0,
+    {
+        let mut b = Builder::default();
+        b.push_record(["a", "b"]);
+        b.push_record([""; 0]);
+        b.build()
+    },
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+    "|   |   |"
+    "+---+---+"
+);
+
+test_table!(
+    push_empty_array_record_1,
+    {
+        let mut b = Builder::default();
+        b.push_record([""; 0]);
+        b.push_record(["a"]);
+        b.push_record([""; 0]);
+        b.build()
+    },
+    "+---+"
+    "|   |"
+    "+---+"
+    "| a |"
+    "+---+"
+    "|   |"
+    "+---+"
+);
+
+#[test]
+fn push_empty_array_record_2() {
+    let mut b = Builder::default();
+    b.push_record([""; 0]);
+    b.push_record([""; 0]);
+
+    assert_eq!(b.count_columns(), 0);
+    assert_eq!(b.count_records(), 2);
+
+    let table = b.build();
+    assert_eq!(table.shape(), (2, 0));
+    assert_eq!(table.to_string(), "");
+}
+
+#[test]
+fn table_from_empty_arrays() {
+    let table = tabled::Table::new([[0u8; 0]; 3]);
+
+    assert_eq!(table.shape(), (4, 0));
+    assert_eq!(table.to_string(), "");
+}
+
 test_table!(
     clean_0,
     clean(Builder::from_iter([["1", "2", "3"], ["a", "b", "c"], ["d", "e", "f"]])),
@@ -871,6 +939,1147 @@ test_table!(
     "+------+---------+-----------+"
 );
 
+test_table!(
+    trim_cells,
+    {
+        let mut b = Builder::default();
+        b.push_record(["  hi  ", "\u{3000}header\u{2003}"]);
+        b.push_record(["  hi  ", "\t data\n"]);
+        b.trim_cells();
+        b.build()
+    },
+    "+----+--------+"
+    "| hi | header |"
+    "+----+--------+"
+    "| hi | data   |"
+    "+----+--------+"
+);
+
+test_table!(
+    move_column_to_end,
+    {
+        let mut b = Builder::default();
+        b.push_record(["id", "name", "age"]);
+        b.push_record(["0", "Alex", "20"]);
+        b.push_record(["1", "Bob", "30"]);
+        assert!(b.move_column(0, 2));
+        b.build()
+    },
+    "+------+-----+----+"
+    "| name | age | id |"
+    "+------+-----+----+"
+    "| Alex | 20  | 0  |"
+    "+------+-----+----+"
+    "| Bob  | 30  | 1  |"
+    "+------+-----+----+"
+);
+
+test_table!(
+    move_column_to_start,
+    {
+        let mut b = Builder::default();
+        b.push_record(["id", "name", "age"]);
+        b.push_record(["0", "Alex", "20"]);
+        assert!(b.move_column(2, 0));
+        assert!(b.move_column(1, 1));
+        assert!(!b.move_column(1, 3));
+        assert!(!b.move_column(3, 1));
+        b.build()
+    },
+    "+-----+----+------+"
+    "| age | id | name |"
+    "+-----+----+------+"
+    "| 20  | 0  | Alex |"
+    "+-----+----+------+"
+);
+
+test_table!(
+    move_record_to_start,
+    {
+        let mut b = Builder::default();
+        b.push_record(["id", "name"]);
+        b.push_record(["0", "Alex"]);
+        b.push_record(["1", "Bob"]);
+        b.push_record(["2", "Tom"]);
+        assert!(b.move_record(2, 0));
+        assert!(!b.move_record(4, 0));
+        assert!(!b.move_record(0, 4));
+        b.build()
+    },
+    "+----+------+"
+    "| 1  | Bob  |"
+    "+----+------+"
+    "| id | name |"
+    "+----+------+"
+    "| 0  | Alex |"
+    "+----+------+"
+    "| 2  | Tom  |"
+    "+----+------+"
+);
+
+test_table!(
+    move_record_to_end,
+    {
+        let mut b = Builder::default();
+        b.push_record(["id", "name"]);
+        b.push_record(["0", "Alex"]);
+        b.push_record(["1", "Bob"]);
+        assert!(b.move_record(1, 2));
+        b.build()
+    },
+    "+----+------+"
+    "| id | name |"
+    "+----+------+"
+    "| 1  | Bob  |"
+    "+----+------+"
+    "| 0  | Alex |"
+    "+----+------+"
+);
+
+test_table!(
+    append_from_tabled_iter,
+    {
+        let mut b = Builder::default();
+        b.push_record(["lang", "year"]);
+        b.push_record(["Rust", "2015"]);
+        b.append_from_tabled_iter([("Go", 2012), ("Zig", 2016)]).unwrap();
+        b.build()
+    },
+    "+------+------+"
+    "| lang | year |"
+    "+------+------+"
+    "| Rust | 2015 |"
+    "+------+------+"
+    "| Go   | 2012 |"
+    "+------+------+"
+    "| Zig  | 2016 |"
+    "+------+------+"
+);
+
+test_table!(
+    append_from_tabled_iter_to_empty,
+    {
+        let mut b = Builder::default();
+        b.append_from_tabled_iter([("Go", 2012)]).unwrap();
+        b.build()
+    },
+    "+----+------+"
+    "| Go | 2012 |"
+    "+----+------+"
+);
+
+#[test]
+fn append_from_tabled_iter_mismatch() {
+    let mut b = Builder::default();
+    b.push_record(["lang", "year"]);
+
+    let err = b
+        .append_from_tabled_iter([("C", 1972, "Dennis Ritchie")])
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ColumnCountMismatch {
+            expected: 2,
+            found: 3
+        }
+    );
+    assert_eq!(err.to_string(), "expected 2 columns but found 3");
+    assert_eq!(b.count_records(), 1);
+    assert_eq!(b.count_columns(), 2);
+}
+
+test_table!(
+    push_tabled,
+    {
+        let mut b = Builder::default();
+        b.push_tabled(&("Rust", 2015)).unwrap();
+        b.push_tabled(&(String::from("Go"), 12u8)).unwrap();
+        b.build()
+    },
+    "+------+------+"
+    "| &str | i32  |"
+    "+------+------+"
+    "| Rust | 2015 |"
+    "+------+------+"
+    "| Go   | 12   |"
+    "+------+------+"
+);
+
+#[test]
+fn push_tabled_mismatch() {
+    let mut b = Builder::default();
+    b.push_tabled(&("Rust", 2015)).unwrap();
+
+    let err = b.push_tabled(&"C").unwrap_err();
+    assert_eq!(
+        err,
+        ColumnCountMismatch {
+            expected: 2,
+            found: 1
+        }
+    );
+    assert_eq!(b.count_records(), 2);
+    assert_eq!(b.count_columns(), 2);
+}
+
+test_table!(
+    from_tabled_iter,
+    {
+        let mut b = Builder::from_tabled_iter([("Rust", 2015), ("Go", 2012)]);
+        b.push_tabled(&("Zig", 2016)).unwrap();
+        b.build()
+    },
+    "+------+------+"
+    "| &str | i32  |"
+    "+------+------+"
+    "| Rust | 2015 |"
+    "+------+------+"
+    "| Go   | 2012 |"
+    "+------+------+"
+    "| Zig  | 2016 |"
+    "+------+------+"
+);
+
+test_table!(
+    from_tabled_iter_empty,
+    Builder::from_tabled_iter(Vec::<(&str, i32)>::new()).build(),
+    "+------+-----+"
+    "| &str | i32 |"
+    "+------+-----+"
+);
+
+test_table!(
+    add_row_numbers_from_1,
+    {
+        let mut b = Builder::default();
+        b.push_record(["name", "age"]);
+        b.push_record(["Alex", "20"]);
+        b.push_record(["Bob", "30"]);
+        b.add_row_numbers("No.", 1);
+        assert_eq!(b.count_columns(), 3);
+        b.build()
+    },
+    "+-----+------+-----+"
+    "| No. | name | age |"
+    "+-----+------+-----+"
+    "| 1   | Alex | 20  |"
+    "+-----+------+-----+"
+    "| 2   | Bob  | 30  |"
+    "+-----+------+-----+"
+);
+
+test_table!(
+    add_row_numbers_from_100,
+    {
+        let mut b = Builder::default();
+        b.push_record(["name"]);
+        b.push_record(["Alex"]);
+        b.push_record(["Bob"]);
+        b.add_row_numbers("#", 100);
+        b.build()
+    },
+    "+-----+------+"
+    "| #   | name |"
+    "+-----+------+"
+    "| 100 | Alex |"
+    "+-----+------+"
+    "| 101 | Bob  |"
+    "+-----+------+"
+);
+
+test_table!(
+    add_row_numbers_empty,
+    {
+        let mut b = Builder::default();
+        b.add_row_numbers("No.", 1);
+        b.build()
+    },
+    "+-----+"
+    "| No. |"
+    "+-----+"
+);
+
+#[test]
+fn diff_single_cell() {
+    let left = Builder::from_iter([["a", "b"], ["c", "d"]]);
+    let right = Builder::from_iter([["a", "b"], ["c", "e"]]);
+
+    assert_eq!(
+        left.diff(&right),
+        vec![CellDiff {
+            row: 1,
+            col: 1,
+            left: Some(String::from("d")),
+            right: Some(String::from("e")),
+        }]
+    );
+    assert!(left.diff(&left.clone()).is_empty());
+}
+
+#[test]
+fn diff_different_shape() {
+    let left = Builder::from_iter([["a", "b"]]);
+    let right = Builder::from_iter(vec![vec!["a"], vec!["c"]]);
+
+    assert_eq!(
+        left.diff(&right),
+        vec![
+            CellDiff {
+                row: 0,
+                col: 1,
+                left: Some(String::from("b")),
+                right: None,
+            },
+            CellDiff {
+                row: 1,
+                col: 0,
+                left: None,
+                right: Some(String::from("c")),
+            },
+        ]
+    );
+}
+
+#[test]
+fn build_latex() {
+    let builder = Builder::from_iter([["a", "b", "c"], ["1", "2", "3"], ["4", "5", "6"]]);
+    let latex = builder.build_latex(LatexOptions::new().column_spec("l|cr"));
+
+    assert_eq!(
+        latex,
+        [
+            r"\begin{tabular}{l|cr}",
+            r"\hline",
+            r"a & b & c \\",
+            r"\hline",
+            r"1 & 2 & 3 \\",
+            r"4 & 5 & 6 \\",
+            r"\hline",
+            r"\end{tabular}",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn build_latex_default_spec() {
+    let builder = Builder::from_iter([["a", "b"]]);
+
+    assert_eq!(
+        builder.build_latex(LatexOptions::new()),
+        [
+            r"\begin{tabular}{ll}",
+            r"\hline",
+            r"a & b \\",
+            r"\hline",
+            r"\end{tabular}",
+        ]
+        .join("\n")
+    );
+
+    assert_eq!(
+        Builder::default().build_latex(LatexOptions::new()),
+        concat!(r"\begin{tabular}{}", "\n", r"\end{tabular}")
+    );
+}
+
+#[test]
+fn build_latex_escape() {
+    let builder = Builder::from_iter([["&%_#", "${}"], ["a\\b", "c\nd"]]);
+
+    assert_eq!(
+        builder.build_latex(LatexOptions::new()),
+        [
+            r"\begin{tabular}{ll}",
+            r"\hline",
+            r"\&\%\_\# & \$\{\} \\",
+            r"\hline",
+            r"a\textbackslash{}b & c d \\",
+            r"\hline",
+            r"\end{tabular}",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn clean_null_values() {
+    let mut b = Builder::default();
+    b.set_null_values(["NA"]);
+    b.push_record(["name", "NA", "age"]);
+    b.push_record(["Alex", "NA", "NA"]);
+    b.push_record(["NA", "NA", "NA"]);
+    b.push_record(["Bob", "NA", "21"]);
+
+    assert_eq!(b.count_empty_cells(), 7);
+
+    b.clean();
+
+    assert_eq!(b.count_empty_cells(), 1);
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+------+-----+"
+            "| name | age |"
+            "+------+-----+"
+            "| Alex | NA  |"
+            "+------+-----+"
+            "| Bob  | 21  |"
+            "+------+-----+"
+        )
+    );
+}
+
+#[test]
+fn collapse_empty_columns() {
+    let mut b = Builder::default();
+    b.set_null_values(["NA"]);
+    b.push_record(["name", "", "age", "NA"]);
+    b.push_record(["Alex", "", "20", "NA"]);
+    b.push_record(["Bob", "", "", ""]);
+
+    assert_eq!(b.collapse_empty_columns(), [1, 3]);
+    assert_eq!(b.count_columns(), 4);
+    assert_eq!(b.count_records(), 3);
+
+    assert_eq!(
+        Builder::default().collapse_empty_columns(),
+        Vec::<usize>::new()
+    );
+}
+
+test_table!(
+    hstack,
+    {
+        let mut b = Builder::from_iter([["name", "age"], ["Alex", "20"], ["Bob", "21"], ["Tom", "30"]]);
+        b.set_empty("-");
+        b.hstack(Builder::from_iter([["city", "country", "zip"], ["Berlin", "DE", "10115"]]));
+        assert_eq!(b.count_columns(), 5);
+        b.build()
+    },
+    "+------+-----+--------+---------+-------+"
+    "| name | age | city   | country | zip   |"
+    "+------+-----+--------+---------+-------+"
+    "| Alex | 20  | Berlin | DE      | 10115 |"
+    "+------+-----+--------+---------+-------+"
+    "| Bob  | 21  | -      | -       | -     |"
+    "+------+-----+--------+---------+-------+"
+    "| Tom  | 30  | -      | -       | -     |"
+    "+------+-----+--------+---------+-------+"
+);
+
+test_table!(
+    hstack_longer_right,
+    {
+        let mut b = Builder::from_iter([["name", "age"], ["Alex", "20"]]);
+        b.set_empty("-");
+        b.hstack(Builder::from_iter([["city", "country", "zip"], ["Berlin", "DE", "10115"], ["Oslo", "NO", "0150"]]));
+        b.build()
+    },
+    "+------+-----+--------+---------+-------+"
+    "| name | age | city   | country | zip   |"
+    "+------+-----+--------+---------+-------+"
+    "| Alex | 20  | Berlin | DE      | 10115 |"
+    "+------+-----+--------+---------+-------+"
+    "| -    | -   | Oslo   | NO      | 0150  |"
+    "+------+-----+--------+---------+-------+"
+);
+
+test_table!(
+    hstack_moves_column_settings,
+    {
+        let mut rhs = Builder::from_iter([["value", "b"], ["1", "2"], ["", ""]]);
+        rhs.set_column_empty(1, "?");
+        rhs.set_column_alignment_hint(0, Alignment::right());
+
+        let mut b = Builder::from_iter([["id"], ["long value"], ["x"], ["y"]]);
+        b.hstack(rhs);
+        b.fill_empty();
+        b.build()
+    },
+    "+------------+-------+---+"
+    "| id         | value | b |"
+    "+------------+-------+---+"
+    "| long value |     1 | 2 |"
+    "+------------+-------+---+"
+    "| x          |       | ? |"
+    "+------------+-------+---+"
+    "| y          |       | ? |"
+    "+------------+-------+---+"
+);
+
+#[test]
+fn replace_null_values() {
+    let mut b = Builder::from_iter([["NULL", "None"], ["", "NULL"]]);
+    b.set_empty("?");
+    b.set_null_values(["NULL", "None"]);
+
+    assert_eq!(b.count_empty_cells(), 4);
+
+    b.replace_null_values();
+
+    assert_eq!(b.count_empty_cells(), 1);
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+---+---+"
+            "| ? | ? |"
+            "+---+---+"
+            "|   | ? |"
+            "+---+---+"
+        )
+    );
+}
+
+test_table!(
+    push_record_with,
+    {
+        let mut b = Builder::default();
+        b.push_record_with(2, |col| format!("c{}", col));
+        b.push_record_with(3, |col| col.to_string());
+        b.build()
+    },
+    "+----+----+---+"
+    "| c0 | c1 |   |"
+    "+----+----+---+"
+    "| 0  | 1  | 2 |"
+    "+----+----+---+"
+);
+
+test_table!(
+    from_key_value_pairs,
+    Builder::from_key_value_pairs(
+        vec![("name", String::from("tabled")), ("license", String::from("MIT"))],
+        "key",
+        "value",
+    )
+    .build(),
+    "+---------+--------+"
+    "| key     | value  |"
+    "+---------+--------+"
+    "| name    | tabled |"
+    "+---------+--------+"
+    "| license | MIT    |"
+    "+---------+--------+"
+);
+
+test_table!(
+    from_key_value_pairs_empty,
+    Builder::from_key_value_pairs(Vec::<(String, String)>::new(), "key", "value").build(),
+    "+-----+-------+"
+    "| key | value |"
+    "+-----+-------+"
+);
+
+test_table!(
+    from_pairs_duplicates,
+    Table::from_pairs([("port", "8080"), ("host", "localhost"), ("port", "8081")]),
+    "+------+-----------+"
+    "| key  | value     |"
+    "+------+-----------+"
+    "| port | 8080      |"
+    "+------+-----------+"
+    "| host | localhost |"
+    "+------+-----------+"
+    "| port | 8081      |"
+    "+------+-----------+"
+);
+
+test_table!(
+    from_key_value_pairs_sorted_duplicates,
+    {
+        let pairs = vec![("b", "1"), ("a", "2"), ("b", "3"), ("a", "4")];
+        let mut b = Builder::from_key_value_pairs(pairs, "name", "n");
+        b.sort_records_by_column(0);
+        b.build()
+    },
+    "+------+---+"
+    "| name | n |"
+    "+------+---+"
+    "| a    | 2 |"
+    "+------+---+"
+    "| a    | 4 |"
+    "+------+---+"
+    "| b    | 1 |"
+    "+------+---+"
+    "| b    | 3 |"
+    "+------+---+"
+);
+
+test_table!(
+    from_key_value_pairs_no_header,
+    {
+        let pairs = vec![("b", String::from("1")), ("a", String::from("2"))];
+        let mut b = Builder::from_key_value_pairs(pairs, "key", "value");
+        b.sort_records_by_column(0);
+        let _ = b.take_header();
+        b.build()
+    },
+    "+---+---+"
+    "| a | 2 |"
+    "+---+---+"
+    "| b | 1 |"
+    "+---+---+"
+);
+
+#[test]
+fn from_pairs_empty() {
+    let table = Table::from_pairs(Vec::<(String, String)>::new());
+    assert_eq!(table.shape(), (1, 2));
+}
+
+#[test]
+fn sort_records_by_column() {
+    let mut b = Builder::default();
+    b.sort_records_by_column(0);
+    assert_eq!(b.count_records(), 0);
+
+    b.push_record(["x", "y"]);
+    b.push_record(["2", "b"]);
+    b.push_record(["1"]);
+    b.push_record(["3", "a"]);
+    b.sort_records_by_column(1);
+
+    let data: Vec<Vec<String>> = b.into();
+    assert_eq!(data, [["x", "y"], ["1", ""], ["3", "a"], ["2", "b"]]);
+}
+
+#[test]
+fn from_env() {
+    std::env::set_var("TABLED_FROM_ENV_TEST_B", "2");
+    std::env::set_var("TABLED_FROM_ENV_TEST_A", "1");
+
+    let records: Vec<Vec<String>> = Builder::from_env().into();
+
+    assert_eq!(records[0], ["Variable", "Value"]);
+    assert!(records[1..].windows(2).all(|w| w[0][0] <= w[1][0]));
+
+    let found = records
+        .iter()
+        .filter(|r| r[0].starts_with("TABLED_FROM_ENV_TEST_"))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        found,
+        [
+            &["TABLED_FROM_ENV_TEST_A", "1"],
+            &["TABLED_FROM_ENV_TEST_B", "2"]
+        ]
+    );
+}
+
+test_table!(
+    column_groups,
+    {
+        let mut b = Builder::from_iter([["a", "b", "c", "d", "e"], ["1", "2", "3", "4", "5"]]);
+        b.set_column_groups(vec![(1..3, String::from("group 1")), (3..5, String::from("2"))])
+            .unwrap();
+        b.build()
+    },
+    "+---+----+----+---+---+"
+    "|   | group 1 |   2   |"
+    "+---+----+----+---+---+"
+    "| a | b  | c  | d | e |"
+    "+---+----+----+---+---+"
+    "| 1 | 2  | 3  | 4 | 5 |"
+    "+---+----+----+---+---+"
+);
+
+test_table!(
+    column_groups_removed_column,
+    {
+        let mut b = Builder::from_iter([["a", "b", "c"], ["1", "2", "3"]]);
+        b.set_column_groups(vec![(0..1, String::from("x")), (1..3, String::from("y"))])
+            .unwrap();
+        b.remove_column(2);
+        b.build()
+    },
+    "+---+---+"
+    "| x | y |"
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+);
+
+test_table!(
+    column_groups_follow_added_row_numbers,
+    {
+        let mut b = Builder::from_iter([["a", "b", "c", "d"], ["1", "2", "", "4"]]);
+        b.set_column_groups(vec![(0..2, String::from("F")), (2..4, String::from("G"))])
+            .unwrap();
+        b.add_row_numbers("#", 1);
+        b.build()
+    },
+    "+---+---+---+---+---+"
+    "|   |   F   |   G   |"
+    "+---+---+---+---+---+"
+    "| # | a | b | c | d |"
+    "+---+---+---+---+---+"
+    "| 1 | 1 | 2 |   | 4 |"
+    "+---+---+---+---+---+"
+);
+
+test_table!(
+    column_groups_follow_inserted_column,
+    {
+        let mut b = Builder::from_iter([["a", "b", "c", "d"], ["1", "2", "", "4"]]);
+        b.set_column_groups(vec![(0..2, String::from("F")), (2..4, String::from("G"))])
+            .unwrap();
+        b.insert_column(1, ["x", "y"]);
+        b.build()
+    },
+    "+---+---+---+---+---+"
+    "|     F     |   G   |"
+    "+---+---+---+---+---+"
+    "| a | x | b | c | d |"
+    "+---+---+---+---+---+"
+    "| 1 | y | 2 |   | 4 |"
+    "+---+---+---+---+---+"
+);
+
+test_table!(
+    column_groups_follow_removed_column,
+    {
+        let mut b = Builder::from_iter([["a", "b", "c", "d"], ["1", "2", "", "4"]]);
+        b.set_column_groups(vec![(0..2, String::from("F")), (2..4, String::from("G"))])
+            .unwrap();
+        b.remove_column(0);
+        b.build()
+    },
+    "+---+---+---+"
+    "| F |   G   |"
+    "+---+---+---+"
+    "| b | c | d |"
+    "+---+---+---+"
+    "| 2 |   | 4 |"
+    "+---+---+---+"
+);
+
+test_table!(
+    column_groups_follow_moved_column,
+    {
+        let mut b = Builder::from_iter([["a", "b", "c", "d"], ["1", "2", "", "4"]]);
+        b.set_column_groups(vec![(0..2, String::from("F")), (2..4, String::from("G"))])
+            .unwrap();
+        b.move_column(3, 0);
+        b.build()
+    },
+    "+---+---+---+---+"
+    "| G |   F   |   |"
+    "+---+---+---+---+"
+    "| d | a | b | c |"
+    "+---+---+---+---+"
+    "| 4 | 1 | 2 |   |"
+    "+---+---+---+---+"
+);
+
+test_table!(
+    column_groups_follow_reversed_columns,
+    {
+        let mut b = Builder::from_iter([["a", "b", "c", "d"], ["1", "2", "", "4"]]);
+        b.set_column_groups(vec![(0..2, String::from("F")), (2..4, String::from("G"))])
+            .unwrap();
+        b.reverse_columns();
+        b.build()
+    },
+    "+---+---+---+---+"
+    "|   G   |   F   |"
+    "+---+---+---+---+"
+    "| d | c | b | a |"
+    "+---+---+---+---+"
+    "| 4 |   | 2 | 1 |"
+    "+---+---+---+---+"
+);
+
+test_table!(
+    column_groups_follow_cleaned_columns,
+    {
+        let mut b = Builder::from_iter([["a", "b", "c", "d"], ["1", "2", "", "4"]]);
+        b.set_column_groups(vec![(0..2, String::from("F")), (2..4, String::from("G"))])
+            .unwrap();
+        b.remove_record(0);
+        b.clean();
+        b.build()
+    },
+    "+---+---+---+"
+    "|   F   | G |"
+    "+---+---+---+"
+    "| 1 | 2 | 4 |"
+    "+---+---+---+"
+);
+
+#[test]
+fn column_groups_invalid() {
+    let mut b = Builder::from_iter([["a", "b", "c", "d"]]);
+
+    assert_eq!(
+        b.set_column_groups(vec![(0..2, String::from("x")), (1..3, String::from("y"))]),
+        Err(ColumnGroupError::Overlap(0..2, 1..3))
+    );
+    assert_eq!(
+        b.set_column_groups(vec![(0..5, String::from("x"))]),
+        Err(ColumnGroupError::OutOfBounds(0..5))
+    );
+    assert_eq!(
+        b.set_column_groups(vec![(2..2, String::from("x"))]),
+        Err(ColumnGroupError::OutOfBounds(2..2))
+    );
+    assert_eq!(
+        ColumnGroupError::Overlap(0..2, 1..3).to_string(),
+        "column groups 0..2 and 1..3 overlap"
+    );
+    assert_eq!(
+        b.set_column_groups(vec![(2..4, String::from("x")), (0..2, String::from("y"))]),
+        Ok(())
+    );
+}
+
+#[test]
+fn to_fixed_width_string() {
+    let mut b1 = Builder::default();
+    b1.push_record(["id", "name"]);
+    b1.push_record(["1", "Alexander"]);
+
+    let mut b2 = Builder::default();
+    b2.push_record(["id", "name", "comment"]);
+    b2.push_record(["12345", "Bob", "-"]);
+
+    let widths = [4, 8];
+
+    assert_eq!(
+        b1.to_fixed_width_string(&widths),
+        static_table!(
+            "+----+--------+"
+            "| id | name   |"
+            "+----+--------+"
+            "| 1  | Alexan |"
+            "+----+--------+"
+        )
+    );
+    assert_eq!(
+        b2.to_fixed_width_string(&widths),
+        static_table!(
+            "+----+--------+---------+"
+            "| id | name   | comment |"
+            "+----+--------+---------+"
+            "| 12 | Bob    | -       |"
+            "+----+--------+---------+"
+        )
+    );
+    assert_eq!(b2.to_fixed_width_string(&[]), b2.build().to_string());
+}
+
+#[test]
+fn infer_numeric_columns() {
+    let mut b = Builder::default();
+    b.set_null_values(["NULL"]);
+    b.push_record(["item", "price", "code", "note"]);
+    b.push_record(["Apple", "1.5", "A1", ""]);
+    b.push_record(["Pear", "NULL", "2", ""]);
+    b.push_record(["Plum", "-12", "3", ""]);
+    b.infer_numeric_columns();
+
+    assert_eq!(b.get_column_alignment_hint(0), None);
+    assert_eq!(b.get_column_alignment_hint(1), Some(Alignment::right()));
+    assert_eq!(b.get_column_alignment_hint(2), None);
+    assert_eq!(b.get_column_alignment_hint(3), None);
+
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+-------+-------+------+------+"
+            "| item  | price | code | note |"
+            "+-------+-------+------+------+"
+            "| Apple |   1.5 | A1   |      |"
+            "+-------+-------+------+------+"
+            "| Pear  |  NULL | 2    |      |"
+            "+-------+-------+------+------+"
+            "| Plum  |   -12 | 3    |      |"
+            "+-------+-------+------+------+"
+        )
+    );
+}
+
+#[test]
+fn infer_numeric_columns_recognizes_formatted_numbers() {
+    let mut b = Builder::from_iter([
+        ["price", "share", "ratio", "limit"],
+        ["$1,200.50", "15%", "NaN", "1_000"],
+        ["-€3", "2.5%", "0.5", "inf"],
+    ]);
+    b.infer_numeric_columns();
+
+    assert_eq!(b.get_column_alignment_hint(0), Some(Alignment::right()));
+    assert_eq!(b.get_column_alignment_hint(1), Some(Alignment::right()));
+    assert_eq!(b.get_column_alignment_hint(2), None);
+    assert_eq!(b.get_column_alignment_hint(3), None);
+
+    let mut table = b.clone().build();
+    table.with(AutoAlign::numbers());
+    assert_eq!(b.build().to_string(), table.to_string());
+}
+
+#[test]
+fn column_alignment_hint_follows_columns() {
+    let mut b = Builder::default();
+    b.push_record(["name", "price", "count"]);
+    b.set_column_alignment_hint(1, Alignment::right());
+    b.set_column_alignment_hint(2, Alignment::center());
+
+    b.add_row_numbers("#", 1);
+    assert_eq!(b.get_column_alignment_hint(1), None);
+    assert_eq!(b.get_column_alignment_hint(2), Some(Alignment::right()));
+    assert_eq!(b.get_column_alignment_hint(3), Some(Alignment::center()));
+
+    b.remove_column(2);
+    assert_eq!(b.get_column_alignment_hint(2), Some(Alignment::center()));
+    assert_eq!(b.get_column_alignment_hint(3), None);
+
+    assert!(b.move_column(2, 0));
+    assert_eq!(b.get_column_alignment_hint(0), Some(Alignment::center()));
+    assert_eq!(b.get_column_alignment_hint(2), None);
+}
+
+#[test]
+fn column_alignment_hint_override() {
+    let mut b = Builder::default();
+    b.push_record(["a", "b"]);
+    b.push_record(["1", "2"]);
+    b.set_column_alignment_hint(0, Alignment::right());
+    b.set_column_alignment_hint(0, Alignment::center());
+    b.set_column_alignment_hint(5, Alignment::right());
+
+    assert_eq!(b.get_column_alignment_hint(0), Some(Alignment::center()));
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+---+---+"
+            "| a | b |"
+            "+---+---+"
+            "| 1 | 2 |"
+            "+---+---+"
+        )
+    );
+}
+
+#[test]
+fn set_column_empty() {
+    let mut b = Builder::default();
+    b.set_empty("-");
+    b.set_column_empty(1, "0");
+    b.set_column_empty(3, "?");
+    b.push_record(["name"]);
+    b.push_record(["Alex", "3", "comment"]);
+    b.push_record(["Bob", "1"]);
+    b.push_column(["extra"]);
+
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+------+---+---------+-------+"
+            "| name | 0 | -       | extra |"
+            "+------+---+---------+-------+"
+            "| Alex | 3 | comment | ?     |"
+            "+------+---+---------+-------+"
+            "| Bob  | 1 | -       | ?     |"
+            "+------+---+---------+-------+"
+        )
+    );
+}
+
+#[test]
+fn set_column_empty_follows_columns() {
+    fn builder() -> Builder {
+        let mut b = Builder::default();
+        b.push_record(["a", "b", "c"]);
+        b.set_column_empty(0, "A");
+        b.set_column_empty(1, "B");
+        b.set_column_empty(2, "C");
+        b
+    }
+
+    fn empty_record(mut b: Builder) -> Vec<String> {
+        b.push_record(Vec::<String>::new());
+        let data: Vec<Vec<String>> = b.into();
+        data.last().unwrap().clone()
+    }
+
+    let mut b = builder();
+    b.insert_column(1, ["x"]);
+    assert_eq!(empty_record(b), ["A", "", "B", "C"]);
+
+    let mut b = builder();
+    b.add_row_numbers("#", 1);
+    assert_eq!(empty_record(b), ["", "A", "B", "C"]);
+
+    let mut b = builder();
+    b.remove_column(1);
+    assert_eq!(empty_record(b), ["A", "C"]);
+
+    let mut b = builder();
+    assert!(b.move_column(0, 2));
+    assert_eq!(empty_record(b), ["B", "C", "A"]);
+
+    let mut b = builder();
+    assert!(b.move_column(2, 0));
+    assert_eq!(empty_record(b), ["C", "A", "B"]);
+
+    let mut b = builder();
+    b.update_header(["a", "", "c"]);
+    b.clean();
+    assert_eq!(empty_record(b), ["A", "C"]);
+
+    let mut b = builder();
+    b.reverse_columns();
+    assert_eq!(empty_record(b), ["C", "B", "A"]);
+}
+
+#[test]
+fn fill_empty() {
+    let mut b = Builder::default();
+    b.push_record(["name", "count", "comment"]);
+    b.push_record(["Alex", "", ""]);
+    b.push_record(["Bob", "2"]);
+
+    b.set_empty("-");
+    b.set_column_empty(1, "0");
+    b.fill_empty();
+
+    assert_eq!(
+        b.clone().build().to_string(),
+        static_table!(
+            "+------+-------+---------+"
+            "| name | count | comment |"
+            "+------+-------+---------+"
+            "| Alex | 0     | -       |"
+            "+------+-------+---------+"
+            "| Bob  | 2     | -       |"
+            "+------+-------+---------+"
+        )
+    );
+
+    b.clean();
+    assert_eq!(b.count_columns(), 3);
+    assert_eq!(b.count_empty_cells(), 0);
+}
+
+#[test]
+fn record_contains() {
+    let mut b = Builder::default();
+    b.push_record(["name", "city", "comment"]);
+    b.push_record(["Anna", "Berlin", "-"]);
+    b.push_record(["Bob", "Paris", "likes Berlin"]);
+    b.push_record(["Chris", "Rome", "-"]);
+
+    assert!(b.record_contains(1, "Berlin"));
+    assert!(b.record_contains(2, "Berlin"));
+    assert!(b.record_contains(2, "ari"));
+    assert!(!b.record_contains(3, "Berlin"));
+    assert!(!b.record_contains(4, "Berlin"));
+    assert!(!b.record_contains(100, ""));
+
+    assert_eq!(b.find_records_containing("Berlin"), [1, 2]);
+    assert_eq!(b.find_records_containing("-"), [1, 3]);
+    assert_eq!(b.find_records_containing("Madrid"), Vec::<usize>::new());
+}
+
+#[test]
+fn find_cells() {
+    let mut b = Builder::default();
+    b.push_record(["x", "y", "z"]);
+    b.push_record(["x", "1", "x"]);
+    b.push_record(["2", "x", "3"]);
+
+    assert_eq!(b.find_cells(|text| text == "x"), [(1, 0), (1, 2), (2, 1)]);
+    assert_eq!(b.find_cells(|text| text == "y"), []);
+    assert_eq!(b.find_cells(|text| text.is_empty()), []);
+}
+
+#[test]
+fn collect_column() {
+    let mut b = Builder::default();
+    b.push_record(["name", "lang"]);
+    b.push_record(["tabled", "rust"]);
+    b.push_record(["serde", "rust"]);
+    b.push_record(["numpy", "python"]);
+
+    let names: Vec<String> = b.collect_column(0);
+    assert_eq!(names, ["tabled", "serde", "numpy"]);
+
+    let langs: HashSet<String> = b.collect_column(1);
+    assert_eq!(langs.len(), 2);
+    assert!(langs.contains("rust"));
+    assert!(langs.contains("python"));
+
+    let missing: Vec<String> = b.collect_column(2);
+    assert!(missing.is_empty());
+
+    let empty: Vec<String> = Builder::default().collect_column(0);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn pad_to_square() {
+    let mut b = Builder::default();
+    b.push_record(["a", "b", "c"]);
+    b.push_record(["d", "e", "f"]);
+
+    assert!(!b.is_square());
+
+    b.pad_to_square();
+
+    assert!(b.is_square());
+    assert_eq!(b.count_records(), 3);
+    assert_eq!(b.count_columns(), 3);
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+---+---+---+"
+            "| a | b | c |"
+            "+---+---+---+"
+            "| d | e | f |"
+            "+---+---+---+"
+            "|   |   |   |"
+            "+---+---+---+"
+        )
+    );
+}
+
+#[test]
+fn pad_to_square_adds_columns() {
+    let mut b = Builder::default();
+    b.set_empty("-");
+    b.push_record(["a"]);
+    b.push_record(["b"]);
+    b.pad_to_square();
+
+    assert!(b.is_square());
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+---+---+"
+            "| a | - |"
+            "+---+---+"
+            "| b | - |"
+            "+---+---+"
+        )
+    );
+}
+
+#[test]
+fn pad_to_square_empty() {
+    let mut b = Builder::default();
+
+    assert!(b.is_square());
+
+    b.pad_to_square();
+
+    assert!(b.is_square());
+    assert_eq!(b.count_records(), 0);
+}
+
 fn clean(mut b: Builder) -> String {
     b.clean();
     b.build().to_string()
@@ -887,3 +2096,1151 @@ where
     b.clean();
     b.build().to_string()
 }
+
+#[test]
+fn repad_with() {
+    let mut b = Builder::default();
+    b.set_empty("?");
+    b.push_record(["a"]);
+    b.push_record(["b", "c"]);
+    b.push_record(["d", "e", "f"]);
+    b.repad_with("-");
+
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+---+---+---+"
+            "| a | - | - |"
+            "+---+---+---+"
+            "| b | c | - |"
+            "+---+---+---+"
+            "| d | e | f |"
+            "+---+---+---+"
+        )
+    );
+}
+
+#[test]
+fn repad_with_affects_new_rows() {
+    let mut b = Builder::default();
+    b.push_record(["a", "b", "c"]);
+    b.push_record(["d"]);
+    b.repad_with("-");
+    b.push_record(["e", "f"]);
+    b.push_record(["g", "h", "i", "j"]);
+
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+---+---+---+---+"
+            "| a | b | c | - |"
+            "+---+---+---+---+"
+            "| d | - | - | - |"
+            "+---+---+---+---+"
+            "| e | f | - | - |"
+            "+---+---+---+---+"
+            "| g | h | i | j |"
+            "+---+---+---+---+"
+        )
+    );
+}
+
+#[test]
+fn repad_with_keeps_column_empty_text() {
+    let mut b = Builder::default();
+    b.set_column_empty(1, "0");
+    b.push_record(["a", "b", "c"]);
+    b.push_record(["d"]);
+    b.repad_with("-");
+
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+---+---+---+"
+            "| a | b | c |"
+            "+---+---+---+"
+            "| d | 0 | - |"
+            "+---+---+---+"
+        )
+    );
+}
+
+#[test]
+fn set_empty_does_not_affect_padded_rows() {
+    let mut b = Builder::default();
+    b.push_record(["a", "b"]);
+    b.push_record(["c"]);
+    b.set_empty("-");
+
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+---+---+"
+            "| a | b |"
+            "+---+---+"
+            "| c |   |"
+            "+---+---+"
+        )
+    );
+}
+
+#[test]
+fn take_header() {
+    let mut b = Builder::default();
+    b.push_record(["a", "b", "c"]);
+    b.push_record(["1", "2", "3"]);
+
+    let header = b.take_header().unwrap();
+    let header = header.iter().map(|cell| cell.as_ref()).collect::<Vec<_>>();
+    assert_eq!(header, ["a", "b", "c"]);
+
+    assert_eq!(b.count_records(), 1);
+    assert_eq!(b.count_columns(), 3);
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+---+---+---+"
+            "| 1 | 2 | 3 |"
+            "+---+---+---+"
+        )
+    );
+}
+
+#[test]
+fn take_header_only_record() {
+    let mut b = Builder::default();
+    b.push_record(["a", "b"]);
+
+    assert!(b.take_header().is_some());
+    assert_eq!(b.count_records(), 0);
+    assert_eq!(b.count_columns(), 0);
+    assert!(b.take_header().is_none());
+}
+
+#[test]
+fn take_header_moves_between_builders() {
+    let mut b1 = Builder::default();
+    b1.push_record(["name", "age"]);
+    b1.push_record(["Alex", "23"]);
+
+    let mut b2 = Builder::default();
+    b2.push_record(["Max", "42"]);
+
+    let header = b1.take_header().unwrap();
+    b2.insert_record(0, header.into_iter().map(|cell| cell.into_inner()));
+
+    assert_eq!(
+        b2.build().to_string(),
+        static_table!(
+            "+------+-----+"
+            "| name | age |"
+            "+------+-----+"
+            "| Max  | 42  |"
+            "+------+-----+"
+        )
+    );
+}
+
+#[test]
+fn set_column_max_width() {
+    let mut b = Builder::default();
+    b.push_record(["name", "description"]);
+    b.push_record(["tabled", "a library for pretty print tables"]);
+    b.push_record(["serde", "a serialization framework"]);
+    b.set_column_max_width(1, 10, "...");
+    b.set_column_max_width(5, 1, "...");
+
+    assert_eq!(
+        b.clone().build().to_string(),
+        static_table!(
+            "+--------+------------+"
+            "| name   | descrip... |"
+            "+--------+------------+"
+            "| tabled | a libra... |"
+            "+--------+------------+"
+            "| serde  | a seria... |"
+            "+--------+------------+"
+        )
+    );
+
+    let descriptions: Vec<String> = b.collect_column(1);
+    assert_eq!(
+        descriptions,
+        [
+            "a library for pretty print tables",
+            "a serialization framework"
+        ]
+    );
+
+    b.set_column_max_width(1, 5, "");
+
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+--------+-------+"
+            "| name   | descr |"
+            "+--------+-------+"
+            "| tabled | a lib |"
+            "+--------+-------+"
+            "| serde  | a ser |"
+            "+--------+-------+"
+        )
+    );
+}
+
+#[test]
+fn set_column_max_width_follows_columns() {
+    let mut b = Builder::default();
+    b.push_record(["name", "description", "license"]);
+    b.push_record(["tabled", "a library for pretty print tables", "MIT"]);
+    b.set_column_max_width(1, 10, "...");
+    b.set_column_max_width(2, 2, "");
+
+    b.add_row_numbers("#", 1);
+    b.remove_column(3);
+
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+---+--------+------------+"
+            "| # | name   | descrip... |"
+            "+---+--------+------------+"
+            "| 1 | tabled | a libra... |"
+            "+---+--------+------------+"
+        )
+    );
+}
+
+#[test]
+fn set_column_max_width_with_column_groups() {
+    let mut b = Builder::default();
+    b.push_record(["name", "description"]);
+    b.push_record(["tabled", "a library for pretty print tables"]);
+    b.set_column_max_width(1, 10, "...");
+    b.set_column_groups(vec![(0..2, String::from("crates which are used"))])
+        .unwrap();
+
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+---------+-------------+"
+            "| crates which are used |"
+            "+---------+-------------+"
+            "| name    | descrip...  |"
+            "+---------+-------------+"
+            "| tabled  | a libra...  |"
+            "+---------+-------------+"
+        )
+    );
+}
+
+#[test]
+fn rotate_records() {
+    let create = || {
+        let mut b = Builder::default();
+        b.push_record(["n"]);
+        for i in 1..=5 {
+            b.push_record([i.to_string()]);
+        }
+
+        b
+    };
+
+    let collect = |b: &Builder| b.collect_column::<Vec<String>>(0).join(" ");
+
+    let mut b = create();
+    b.rotate_records(2);
+    assert_eq!(collect(&b), "3 4 5 1 2");
+
+    let mut b = create();
+    b.rotate_records(-2);
+    assert_eq!(collect(&b), "4 5 1 2 3");
+
+    let mut b = create();
+    b.rotate_records(7);
+    assert_eq!(collect(&b), "3 4 5 1 2");
+
+    let mut b = create();
+    b.rotate_records(-12);
+    assert_eq!(collect(&b), "4 5 1 2 3");
+
+    let mut b = create();
+    b.rotate_records(5).rotate_records(0);
+    assert_eq!(collect(&b), "1 2 3 4 5");
+
+    let data: Vec<Vec<String>> = b.into();
+    assert_eq!(data[0], ["n"]);
+}
+
+#[test]
+fn rotate_records_without_records() {
+    let mut b = Builder::default();
+    b.rotate_records(3);
+    assert_eq!(b.count_records(), 0);
+
+    b.push_record(["header"]);
+    b.rotate_records(-3);
+
+    let data: Vec<Vec<String>> = b.into();
+    assert_eq!(data, [["header"]]);
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn from_ndjson() {
+    let text = r#"{"id": 1, "name": "Ann", "tags": ["a", "b"]}
+
+{"name": "Bob", "active": true}
+{"id": 3, "active": null, "extra": {"k": "v"}}
+"#;
+
+    let table = Builder::from_ndjson(text).unwrap().build().to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+----+------+-----------+--------+-----------+"
+            "| id | name | tags      | active | extra     |"
+            "+----+------+-----------+--------+-----------+"
+            "| 1  | Ann  | [\"a\",\"b\"] |        |           |"
+            "+----+------+-----------+--------+-----------+"
+            "|    | Bob  |           | true   |           |"
+            "+----+------+-----------+--------+-----------+"
+            "| 3  |      |           |        | {\"k\":\"v\"} |"
+            "+----+------+-----------+--------+-----------+"
+        )
+    );
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn from_ndjson_empty() {
+    let b = Builder::from_ndjson("\n  \n").unwrap();
+    assert_eq!(b.count_records(), 0);
+    assert_eq!(b.count_columns(), 0);
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn from_ndjson_invalid_line() {
+    assert!(Builder::from_ndjson("{\"a\": 1}\n{\"a\": ").is_err());
+    assert!(Builder::from_ndjson("{\"a\": 1}\n[1, 2]").is_err());
+}
+
+#[test]
+fn set_cell_unchecked() {
+    let mut b = Builder::from_iter([["a", "b"], ["1", "2"], ["3", "4"]]);
+    b.set_cell_unchecked(0, 1, "B");
+    b.set_cell_unchecked(2, 0, String::from("three"));
+
+    let data: Vec<Vec<String>> = b.into();
+    assert_eq!(data, [["a", "B"], ["1", "2"], ["three", "4"]]);
+}
+
+#[test]
+#[should_panic]
+fn set_cell_unchecked_out_of_bounds() {
+    let mut b = Builder::from_iter([["a", "b"]]);
+    b.set_cell_unchecked(1, 0, "x");
+}
+
+#[test]
+fn ensure_rectangular() {
+    let cells = |row: &[&str]| {
+        row.iter()
+            .map(|text| CellInfo::new(text.to_string()))
+            .collect::<Vec<_>>()
+    };
+
+    let b = Builder::from_iter([["a", "b"], ["1", "2"]]);
+    assert_eq!(b.ensure_rectangular(), Ok(()));
+    b.assert_rectangular();
+
+    let b = Builder::from_vec(vec![cells(&["a", "b"]), cells(&["1", "2"]), cells(&["3"])]);
+    assert_eq!(
+        b.ensure_rectangular(),
+        Err(RaggedRecord {
+            index: 2,
+            found: 1,
+            expected: 2
+        })
+    );
+
+    let b = Builder::from_vec(vec![cells(&["a"]), cells(&["1", "2", "3"]), cells(&["4"])]);
+    let err = b.ensure_rectangular().unwrap_err();
+    assert_eq!((err.index, err.found, err.expected), (1, 3, 1));
+    assert_eq!(err.to_string(), "record 1 has 3 cells but expected 1");
+
+    assert_eq!(Builder::default().ensure_rectangular(), Ok(()));
+}
+
+#[test]
+#[should_panic(expected = "record 1 has 1 cells but expected 2")]
+fn assert_rectangular_ragged() {
+    let b = Builder::from_vec(vec![
+        vec![
+            CellInfo::new(String::from("a")),
+            CellInfo::new(String::from("b")),
+        ],
+        vec![CellInfo::new(String::from("1"))],
+    ]);
+
+    b.assert_rectangular();
+}
+
+#[test]
+fn pop_front_record() {
+    let mut b = Builder::default();
+    b.push_record(["n"]);
+    for i in 1..=3 {
+        b.push_record([i.to_string()]);
+    }
+
+    let mut popped = Vec::new();
+    while let Some(record) = b.pop_front_record() {
+        popped.push(record[0].as_ref().to_owned());
+    }
+
+    assert_eq!(popped, ["1", "2", "3"]);
+    assert_eq!(b.pop_front_record(), None);
+
+    let data: Vec<Vec<String>> = b.into();
+    assert_eq!(data, [["n"]]);
+}
+
+#[test]
+fn pop_front_record_empty() {
+    let mut b = Builder::default();
+    assert_eq!(b.pop_front_record(), None);
+    assert_eq!(b.count_records(), 0);
+}
+
+#[cfg(feature = "toml")]
+mod from_toml {
+    use super::*;
+    use tabled::builder::{ArrayStyle, DocumentError, DocumentOptions};
+
+    fn config() -> toml::Value {
+        toml::from_str(
+            r#"
+            title = "demo"
+            ports = [80, 443]
+
+            [owner]
+            name = "Tom"
+            meta = { admin = true }
+
+            [[servers]]
+            host = "alpha"
+            ip = "10.0.0.1"
+
+            [[servers]]
+            host = "beta"
+            "#,
+        )
+        .unwrap()
+    }
+
+    test_table!(
+        from_toml_inline_arrays,
+        Builder::from_toml(&config(), DocumentOptions::new()).unwrap().build(),
+        "+------------------+------------------------------------------------+"
+        "| key              | value                                          |"
+        "+------------------+------------------------------------------------+"
+        "| owner.meta.admin | true                                           |"
+        "+------------------+------------------------------------------------+"
+        "| owner.name       | Tom                                            |"
+        "+------------------+------------------------------------------------+"
+        "| ports            | [80, 443]                                      |"
+        "+------------------+------------------------------------------------+"
+        "| servers          | [{host = alpha, ip = 10.0.0.1}, {host = beta}] |"
+        "+------------------+------------------------------------------------+"
+        "| title            | demo                                           |"
+        "+------------------+------------------------------------------------+"
+    );
+
+    test_table!(
+        from_toml_indexed_arrays,
+        Builder::from_toml(&config(), DocumentOptions::new().arrays(ArrayStyle::Indexed))
+            .unwrap()
+            .build(),
+        "+------------------+----------+"
+        "| key              | value    |"
+        "+------------------+----------+"
+        "| owner.meta.admin | true     |"
+        "+------------------+----------+"
+        "| owner.name       | Tom      |"
+        "+------------------+----------+"
+        "| ports[0]         | 80       |"
+        "+------------------+----------+"
+        "| ports[1]         | 443      |"
+        "+------------------+----------+"
+        "| servers[0].host  | alpha    |"
+        "+------------------+----------+"
+        "| servers[0].ip    | 10.0.0.1 |"
+        "+------------------+----------+"
+        "| servers[1].host  | beta     |"
+        "+------------------+----------+"
+        "| title            | demo     |"
+        "+------------------+----------+"
+    );
+
+    #[test]
+    fn from_toml_scalar() {
+        let err = Builder::from_toml(&toml::Value::Integer(1), DocumentOptions::new()).unwrap_err();
+        assert_eq!(err, DocumentError::UnsupportedTopLevel("an integer"));
+        assert_eq!(
+            err.to_string(),
+            "expected a table at the top level of a document but found an integer"
+        );
+    }
+}
+
+#[cfg(feature = "serde_yaml")]
+mod from_yaml {
+    use super::*;
+    use tabled::builder::{ArrayStyle, DocumentError, DocumentOptions};
+
+    fn config() -> serde_yaml::Value {
+        serde_yaml::from_str(
+            r#"
+            title: demo
+            ports: [80, 443]
+            owner:
+              name: Tom
+              meta: { admin: true }
+            servers:
+              - host: alpha
+                ip: 10.0.0.1
+              - host: beta
+            "#,
+        )
+        .unwrap()
+    }
+
+    test_table!(
+        from_yaml_inline_arrays,
+        Builder::from_yaml(&config(), DocumentOptions::new()).unwrap().build(),
+        "+------------------+---------------------------------------------+"
+        "| key              | value                                       |"
+        "+------------------+---------------------------------------------+"
+        "| title            | demo                                        |"
+        "+------------------+---------------------------------------------+"
+        "| ports            | [80, 443]                                   |"
+        "+------------------+---------------------------------------------+"
+        "| owner.name       | Tom                                         |"
+        "+------------------+---------------------------------------------+"
+        "| owner.meta.admin | true                                        |"
+        "+------------------+---------------------------------------------+"
+        "| servers          | [{host: alpha, ip: 10.0.0.1}, {host: beta}] |"
+        "+------------------+---------------------------------------------+"
+    );
+
+    test_table!(
+        from_yaml_indexed_arrays,
+        Builder::from_yaml(&config(), DocumentOptions::new().arrays(ArrayStyle::Indexed))
+            .unwrap()
+            .build(),
+        "+------------------+----------+"
+        "| key              | value    |"
+        "+------------------+----------+"
+        "| title            | demo     |"
+        "+------------------+----------+"
+        "| ports[0]         | 80       |"
+        "+------------------+----------+"
+        "| ports[1]         | 443      |"
+        "+------------------+----------+"
+        "| owner.name       | Tom      |"
+        "+------------------+----------+"
+        "| owner.meta.admin | true     |"
+        "+------------------+----------+"
+        "| servers[0].host  | alpha    |"
+        "+------------------+----------+"
+        "| servers[0].ip    | 10.0.0.1 |"
+        "+------------------+----------+"
+        "| servers[1].host  | beta     |"
+        "+------------------+----------+"
+    );
+
+    #[test]
+    fn from_yaml_scalar() {
+        let value = serde_yaml::Value::from(1);
+        let err = Builder::from_yaml(&value, DocumentOptions::new()).unwrap_err();
+        assert_eq!(err, DocumentError::UnsupportedTopLevel("a number"));
+        assert_eq!(
+            err.to_string(),
+            "expected a table at the top level of a document but found a number"
+        );
+
+        let value = serde_yaml::Value::Sequence(Vec::new());
+        let err = Builder::from_yaml(&value, DocumentOptions::new()).unwrap_err();
+        assert_eq!(err, DocumentError::UnsupportedTopLevel("a sequence"));
+    }
+}
+
+fn join_sides() -> (Builder, Builder) {
+    let users = Builder::from_iter([["id", "name"], ["1", "Alex"], ["2", "Maria"], ["3", "John"]]);
+    let orders = Builder::from_iter([["item", "id"], ["book", "2"], ["pen", "4"], ["lamp", "2"]]);
+
+    (users, orders)
+}
+
+test_table!(
+    join_inner,
+    {
+        let (users, orders) = join_sides();
+        users.join(orders, 0, 1, JoinType::Inner).build()
+    },
+    "+----+-------+------+----------+"
+    "| id | name  | item | id_right |"
+    "+----+-------+------+----------+"
+    "| 2  | Maria | book | 2        |"
+    "+----+-------+------+----------+"
+    "| 2  | Maria | lamp | 2        |"
+    "+----+-------+------+----------+"
+);
+
+test_table!(
+    join_left,
+    {
+        let (mut users, orders) = join_sides();
+        users.set_empty("-");
+        users.join(orders, 0, 1, JoinType::Left).build()
+    },
+    "+----+-------+------+----------+"
+    "| id | name  | item | id_right |"
+    "+----+-------+------+----------+"
+    "| 1  | Alex  | -    | -        |"
+    "+----+-------+------+----------+"
+    "| 2  | Maria | book | 2        |"
+    "+----+-------+------+----------+"
+    "| 2  | Maria | lamp | 2        |"
+    "+----+-------+------+----------+"
+    "| 3  | John  | -    | -        |"
+    "+----+-------+------+----------+"
+);
+
+test_table!(
+    join_right,
+    {
+        let (users, orders) = join_sides();
+        users.join(orders, 0, 1, JoinType::Right).build()
+    },
+    "+----+-------+------+----------+"
+    "| id | name  | item | id_right |"
+    "+----+-------+------+----------+"
+    "| 2  | Maria | book | 2        |"
+    "+----+-------+------+----------+"
+    "|    |       | pen  | 4        |"
+    "+----+-------+------+----------+"
+    "| 2  | Maria | lamp | 2        |"
+    "+----+-------+------+----------+"
+);
+
+test_table!(
+    join_full,
+    {
+        let (users, orders) = join_sides();
+        users.join(orders, 0, 1, JoinType::Full).build()
+    },
+    "+----+-------+------+----------+"
+    "| id | name  | item | id_right |"
+    "+----+-------+------+----------+"
+    "| 1  | Alex  |      |          |"
+    "+----+-------+------+----------+"
+    "| 2  | Maria | book | 2        |"
+    "+----+-------+------+----------+"
+    "| 2  | Maria | lamp | 2        |"
+    "+----+-------+------+----------+"
+    "| 3  | John  |      |          |"
+    "+----+-------+------+----------+"
+    "|    |       | pen  | 4        |"
+    "+----+-------+------+----------+"
+);
+
+test_table!(
+    join_with_suffix,
+    {
+        let (users, orders) = join_sides();
+        users
+            .join_with_suffix(orders, 0, 1, JoinType::Inner, " (orders)")
+            .build()
+    },
+    "+----+-------+------+-------------+"
+    "| id | name  | item | id (orders) |"
+    "+----+-------+------+-------------+"
+    "| 2  | Maria | book | 2           |"
+    "+----+-------+------+-------------+"
+    "| 2  | Maria | lamp | 2           |"
+    "+----+-------+------+-------------+"
+);
+
+#[test]
+fn join_empty() {
+    let (users, _) = join_sides();
+    let joined = users
+        .clone()
+        .join(Builder::default(), 0, 0, JoinType::Inner);
+    let data: Vec<Vec<String>> = joined.into();
+    assert_eq!(data, [["id", "name"]]);
+
+    let joined = users.join(Builder::default(), 0, 0, JoinType::Left);
+    assert_eq!(joined.count_records(), 4);
+    assert_eq!(joined.count_columns(), 2);
+}
+
+#[test]
+fn to_records_ref() {
+    let mut b = Builder::from_iter([["name", "age"], ["Alex", "20"], ["Bob\nJr.", "21"]]);
+    b.insert_record(0, ["user", "info"]);
+
+    let records = b.to_records_ref();
+    assert_eq!(records.count_rows(), 4);
+    assert_eq!(records.count_columns(), 2);
+    assert_eq!(records.get_text((0, 0)), "user");
+    assert_eq!(records.get_text((1, 1)), "age");
+    assert_eq!(records.count_lines((3, 0)), 2);
+    assert_eq!(records.get_line((3, 0), 1), "Jr.");
+    assert_eq!(records.get_width((3, 0)), 3);
+
+    let texts = records
+        .iter_rows()
+        .map(|row| {
+            row.iter()
+                .map(|c| c.as_ref().to_owned())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        texts,
+        [
+            ["user", "info"],
+            ["name", "age"],
+            ["Alex", "20"],
+            ["Bob\nJr.", "21"]
+        ]
+    );
+}
+
+#[test]
+fn to_records_ref_render() {
+    let b = Builder::from_iter([["name", "age"], ["Alex", "20"], ["Bob\nJr.", "21"]]);
+
+    let table = Table::default();
+    let cfg = table.get_config().as_ref();
+
+    let records = b.to_records_ref();
+    let mut dims = PeekableDimension::default();
+    dims.estimate(records, cfg);
+
+    let output = PeekableGrid::new(records, cfg, &dims, NoColors).to_string();
+    assert_eq!(output, b.build().to_string());
+}
+
+#[test]
+fn to_records_ref_ragged() {
+    let b = Builder::from_vec(vec![
+        vec![CellInfo::new("a".to_owned()), CellInfo::new("b".to_owned())],
+        vec![CellInfo::new("1".to_owned())],
+    ]);
+
+    let records = b.to_records_ref();
+    assert_eq!(records.get_text((1, 1)), "");
+    assert_eq!(records.count_lines((1, 1)), 1);
+    assert_eq!(records.get_width((1, 1)), 0);
+
+    let table = Table::default();
+    let cfg = table.get_config().as_ref();
+
+    let mut dims = PeekableDimension::default();
+    dims.estimate(records, cfg);
+
+    assert_eq!(
+        PeekableGrid::new(records, cfg, &dims, NoColors).to_string(),
+        static_table!(
+            "+---+---+"
+            "| a | b |"
+            "+---+---+"
+            "| 1 |   |"
+            "+---+---+"
+        )
+    );
+}
+
+#[test]
+fn set_header_if_absent() {
+    let mut b = Builder::default();
+    assert!(b.set_header_if_absent(["name", "age"]));
+    assert!(!b.set_header_if_absent(["user", "info", "extra"]));
+    assert_eq!(b.count_records(), 1);
+    assert_eq!(b.count_columns(), 2);
+
+    b.push_record(["Alex", "20"]);
+    assert!(!b.set_header_if_absent(["user", "info"]));
+
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+------+-----+"
+            "| name | age |"
+            "+------+-----+"
+            "| Alex | 20  |"
+            "+------+-----+"
+        )
+    );
+}
+
+#[test]
+fn from_tsv() {
+    let tsv = "name\tpath\r\nAlex\tC:\\temp\\new\nBob\t\\t\n";
+    let builder = Builder::from_tsv(tsv.as_bytes()).unwrap();
+
+    let data: Vec<Vec<String>> = builder.into();
+    assert_eq!(
+        data,
+        [["name", "path"], ["Alex", "C:\\temp\\new"], ["Bob", "\\t"],]
+    );
+}
+
+#[test]
+fn from_tsv_keeps_blank_lines() {
+    let builder = Builder::from_tsv("name\n\nBob\n".as_bytes()).unwrap();
+
+    let data: Vec<Vec<String>> = builder.into();
+    assert_eq!(data, [["name"], [""], ["Bob"]]);
+
+    let err = Builder::from_tsv("a\tb\n\nc\td\n".as_bytes()).unwrap_err();
+    assert!(matches!(
+        err,
+        ParseError::ColumnCountMismatch {
+            line: 2,
+            expected: 2,
+            found: 1
+        }
+    ));
+}
+
+#[test]
+fn from_tsv_without_header() {
+    let mut builder = Builder::from_tsv("a\tb\tc\nd\te\tf".as_bytes()).unwrap();
+    builder.insert_record(0, ["x", "y", "z"]);
+
+    assert_eq!(
+        builder.build().to_string(),
+        static_table!(
+            "+---+---+---+"
+            "| x | y | z |"
+            "+---+---+---+"
+            "| a | b | c |"
+            "+---+---+---+"
+            "| d | e | f |"
+            "+---+---+---+"
+        )
+    );
+
+    let builder = Builder::from_tsv("".as_bytes()).unwrap();
+    assert_eq!(builder.count_records(), 0);
+}
+
+#[test]
+fn from_tsv_column_count_mismatch() {
+    let err = Builder::from_tsv("a\tb\nc\td\te\n".as_bytes()).unwrap_err();
+
+    assert!(matches!(
+        err,
+        ParseError::ColumnCountMismatch {
+            line: 2,
+            expected: 2,
+            found: 3
+        }
+    ));
+    assert_eq!(
+        err.to_string(),
+        "line 2 has 3 cells but expected 2 (is there an unescaped tab?)"
+    );
+}
+
+#[test]
+fn from_tsv_invalid_utf8() {
+    let err = Builder::from_tsv(&[b'a', b'\t', 0xFF, b'\n'][..]).unwrap_err();
+    assert!(matches!(err, ParseError::Io(_)));
+}
+
+#[test]
+fn into_tsv() {
+    let mut b = Builder::default();
+    b.push_record(["name", "note"]);
+    b.push_record(["Alex", "C:\\temp \\n text"]);
+    b.push_record(["Bob"]);
+
+    let mut buf = Vec::new();
+    b.clone().into_tsv(&mut buf, TsvPolicy::Reject).unwrap();
+    assert_eq!(
+        String::from_utf8(buf.clone()).unwrap(),
+        "name\tnote\nAlex\tC:\\temp \\n text\nBob\t\n"
+    );
+
+    let data: Vec<Vec<String>> = Builder::from_tsv(&buf[..]).unwrap().into();
+    let expected: Vec<Vec<String>> = b.into();
+    assert_eq!(data, expected);
+}
+
+#[test]
+fn into_tsv_with_tabs() {
+    let mut b = Builder::default();
+    b.push_record(["key", "value"]);
+    b.push_record(["a\tb", "c\r\nd"]);
+
+    let mut buf = Vec::new();
+    b.clone()
+        .into_tsv(&mut buf, TsvPolicy::ReplaceWithSpace)
+        .unwrap();
+    assert_eq!(buf, b"key\tvalue\na b\tc  d\n");
+
+    let err = b.into_tsv(Vec::new(), TsvPolicy::Reject).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(
+        err.to_string(),
+        "a cell (1, 0) contains a tab or a line break"
+    );
+}
+
+#[test]
+fn find_column() {
+    let mut b = Builder::default();
+    assert_eq!(b.find_column(|_| true), None);
+
+    b.push_record(["ID", "e-mail", "EMAIL"]);
+    b.push_record(["1", "email", "a@b.c"]);
+
+    assert_eq!(
+        b.find_column(|name| name.eq_ignore_ascii_case("email")),
+        Some(2)
+    );
+    assert_eq!(
+        b.find_column(|name| name.to_lowercase().contains("mail")),
+        Some(1)
+    );
+    assert_eq!(b.find_column(|name| name.is_empty()), None);
+}
+
+#[test]
+fn scan_width_hazards() {
+    let mut b = Builder::default();
+    assert_eq!(b.scan_width_hazards(), []);
+
+    b.push_record(["name", "note", "price"]);
+    b.push_record(["cafe\u{301}", "multi\nline", "10"]);
+    b.push_record(["bell\u{7}", "\u{200B}\u{7}\u{200D}", "\u{3b1}"]);
+
+    assert_eq!(
+        b.scan_width_hazards(),
+        [
+            (1, 0, WidthHazard::ZeroWidth),
+            (2, 0, WidthHazard::ControlChar),
+            (2, 1, WidthHazard::ZeroWidth),
+            (2, 1, WidthHazard::ControlChar),
+            (2, 2, WidthHazard::AmbiguousWidth),
+        ]
+    );
+}
+
+#[test]
+fn scan_width_hazards_uses_table_width() {
+    // a soft hyphen is not rendered by a table, so it's invisible
+    let mut b = Builder::default();
+    b.push_record(["co\u{AD}op", "ok"]);
+
+    assert_eq!(b.scan_width_hazards(), [(0, 0, WidthHazard::ZeroWidth)]);
+}
+
+#[test]
+fn update_record() {
+    let mut b = Builder::default();
+    b.push_record(["a", "b"]);
+    b.push_record(["1", "2"]);
+    b.push_record(["3", "4"]);
+
+    b.update_record(1, ["5"]);
+    let data: Vec<Vec<String>> = b.clone().into();
+    assert_eq!(data, [["a", "b"], ["5", ""], ["3", "4"]]);
+
+    b.update_record(2, ["6", "7", "8"]);
+    assert_eq!(b.count_columns(), 3);
+    assert_eq!(b.count_records(), 3);
+
+    let data: Vec<Vec<String>> = b.into();
+    assert_eq!(data, [["a", "b", ""], ["5", "", ""], ["6", "7", "8"]]);
+}
+
+#[test]
+fn push_record_multiline() {
+    let mut b = Builder::default();
+    b.push_record(["id", "text", "note"]);
+    b.push_record_multiline([vec!["1"], vec!["first", "second", "third"], vec![]]);
+
+    let data: Vec<Vec<String>> = b.clone().into();
+    assert_eq!(data[1], ["1", "first\nsecond\nthird", ""]);
+
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+----+--------+------+"
+            "| id | text   | note |"
+            "+----+--------+------+"
+            "| 1  | first  |      |"
+            "|    | second |      |"
+            "|    | third  |      |"
+            "+----+--------+------+"
+        )
+    );
+}
+
+#[test]
+fn update_header() {
+    let mut b = Builder::default();
+    b.update_header(["a", "b"]);
+    b.push_record(["1", "2"]);
+
+    b.update_header(["c"]);
+    let data: Vec<Vec<String>> = b.clone().into();
+    assert_eq!(data, [["c", ""], ["1", "2"]]);
+
+    b.update_header(["x", "y", "z"]);
+    assert_eq!(b.count_columns(), 3);
+    assert_eq!(b.count_records(), 2);
+
+    let data: Vec<Vec<String>> = b.into();
+    assert_eq!(data, [["x", "y", "z"], ["1", "2", ""]]);
+}
+
+#[test]
+fn append_unit_to_header() {
+    let mut b = Builder::default();
+    b.append_unit_to_header(3, "ms");
+    assert_eq!(b.count_records(), 0);
+
+    b.push_record(["host", "latency", "size"]);
+    b.push_record(["localhost", "12", "1024"]);
+    b.push_record(["remote", "240", ""]);
+
+    b.append_unit_to_header(1, "ms")
+        .append_unit_to_header(2, "B");
+
+    let data: Vec<Vec<String>> = b.into();
+    assert_eq!(
+        data,
+        [
+            ["host", "latency (ms)", "size (B)"],
+            ["localhost", "12", "1024"],
+            ["remote", "240", ""],
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn append_unit_to_header_out_of_range() {
+    let mut b = Builder::default();
+    b.push_record(["host", "latency"]);
+    b.append_unit_to_header(2, "ms");
+}
+
+#[test]
+#[should_panic]
+fn update_record_out_of_range() {
+    let mut b = Builder::default();
+    b.push_record(["a", "b"]);
+    b.update_record(1, ["1", "2"]);
+}
+
+#[test]
+fn set_max_records() {
+    let mut b = Builder::default();
+    b.set_max_records(3);
+
+    for i in 0..3 + 5 {
+        b.push_record([i.to_string(), format!("line {}", i)]);
+        assert!(b.count_records() <= 3);
+    }
+
+    assert_eq!(b.count_records(), 3);
+    assert_eq!(b.count_columns(), 2);
+
+    let data: Vec<Vec<String>> = b.clone().into();
+    assert_eq!(data, [["5", "line 5"], ["6", "line 6"], ["7", "line 7"]]);
+
+    b.set_max_records(1);
+    assert_eq!(b.count_records(), 1);
+
+    b.set_max_records(0);
+    b.push_record(["8", "line 8"]);
+    assert_eq!(b.count_records(), 0);
+}
+
+#[test]
+fn distinct_records() {
+    let mut b = Builder::default();
+    b.distinct_records();
+    assert_eq!(b.count_records(), 0);
+
+    b.push_record(["a", "b"]);
+    b.push_record(["1", "2"]);
+    b.push_record(["a", "b"]);
+    b.push_record(["3", "4"]);
+    b.push_record(["1", "2"]);
+    b.push_record(["1"]);
+    b.push_record(["3", "4"]);
+    b.push_record(["1", ""]);
+    b.push_record(["2", "1"]);
+
+    b.distinct_records();
+
+    let data: Vec<Vec<String>> = b.into();
+    assert_eq!(
+        data,
+        [
+            ["a", "b"],
+            ["1", "2"],
+            ["a", "b"],
+            ["3", "4"],
+            ["1", ""],
+            ["2", "1"],
+        ]
+    );
+}
+
+#[test]
+fn reverse_columns() {
+    let mut b = Builder::default();
+    b.reverse_columns();
+    assert_eq!(b.count_records(), 0);
+
+    b.push_record(["id", "name", "city"]);
+    b.push_record(["1", "Alex"]);
+    b.push_record(["2", "Bob", "Oslo"]);
+    b.set_column_alignment_hint(0, Alignment::right());
+    b.set_column_groups(vec![
+        (0..2, String::from("user")),
+        (2..3, String::from("place")),
+    ])
+    .unwrap();
+
+    b.reverse_columns();
+
+    assert_eq!(b.count_columns(), 3);
+    assert_eq!(
+        b.build().to_string(),
+        static_table!(
+            "+-------+------+----+"
+            "| place |   user    |"
+            "+-------+------+----+"
+            "| city  | name | id |"
+            "+-------+------+----+"
+            "|       | Alex |  1 |"
+            "+-------+------+----+"
+            "| Oslo  | Bob  |  2 |"
+            "+-------+------+----+"
+        )
+    );
+}
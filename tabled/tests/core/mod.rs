@@ -4,4 +4,6 @@ mod extended_table_test;
 mod index_test;
 mod iter_table;
 mod pool_table;
+mod scrollable_test;
+mod table_def_test;
 mod table_test;
@@ -0,0 +1,147 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    settings::{Margin, Settings, Style},
+    tables::Scrollable,
+    Table,
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+fn scrolled(table: Table, scroll: usize) -> Scrollable {
+    let mut view = Scrollable::new(table);
+    view.page_down(scroll);
+    view
+}
+
+test_table!(
+    scrollable_first_page,
+    scrolled(Matrix::new(5, 2).to_table(), 0).render(2),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    scrollable_middle_page,
+    scrolled(Matrix::new(5, 2).to_table(), 2).render(2),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 2 |   2-0    |   2-1    |"
+    "+---+----------+----------+"
+    "| 3 |   3-0    |   3-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    scrollable_viewport_bigger_than_rest,
+    scrolled(Matrix::new(5, 2).to_table(), 3).render(10),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 3 |   3-0    |   3-1    |"
+    "+---+----------+----------+"
+    "| 4 |   4-0    |   4-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    scrollable_empty_viewport,
+    scrolled(Matrix::new(5, 2).to_table(), 1).render(0),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    scrollable_empty_viewport_modern_with_margin,
+    scrolled(
+        Matrix::new(4, 2).with(Settings::new(Style::modern(), Margin::new(1, 1, 1, 1))),
+        1
+    )
+    .render(0),
+    "                             "
+    " ┌───┬──────────┬──────────┐ "
+    " │ N │ column 0 │ column 1 │ "
+    " └───┴──────────┴──────────┘ "
+    "                             "
+);
+
+test_table!(
+    scrollable_modern_with_margin,
+    scrolled(
+        Matrix::new(4, 2).with(Settings::new(Style::modern(), Margin::new(1, 1, 1, 1))),
+        1
+    )
+    .render(2),
+    "                             "
+    " ┌───┬──────────┬──────────┐ "
+    " │ N │ column 0 │ column 1 │ "
+    " ├───┼──────────┼──────────┤ "
+    " │ 1 │   1-0    │   1-1    │ "
+    " ├───┼──────────┼──────────┤ "
+    " │ 2 │   2-0    │   2-1    │ "
+    " └───┴──────────┴──────────┘ "
+    "                             "
+);
+
+test_table!(
+    scrollable_without_horizontal_lines,
+    scrolled(Matrix::new(4, 2).with(Style::psql()), 2).render(1),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 2 |   2-0    |   2-1    "
+);
+
+test_table!(
+    scrollable_multiline_rows,
+    scrolled(
+        Matrix::new(3, 2)
+            .insert((2, 1), "multi\nline")
+            .to_table(),
+        1
+    )
+    .render(1),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 1 |  multi   |   1-1    |"
+    "|   |  line    |          |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    scrollable_header_only,
+    Scrollable::new(Matrix::new(0, 2).to_table()).render(3),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+);
+
+#[test]
+fn scrollable_scroll_position() {
+    let mut view = Scrollable::new(Matrix::new(5, 2).to_table());
+    assert_eq!(view.scroll(), 0);
+
+    view.scroll_up();
+    assert_eq!(view.scroll(), 0);
+
+    view.scroll_down();
+    view.scroll_down();
+    assert_eq!(view.scroll(), 2);
+
+    view.page_down(10);
+    assert_eq!(view.scroll(), 4);
+
+    view.page_up(3);
+    assert_eq!(view.scroll(), 1);
+
+    view.page_up(3);
+    assert_eq!(view.scroll(), 0);
+}
@@ -0,0 +1,174 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    builder::{Schema, SchemaError, TableDef},
+    settings::{Alignment, Style},
+    Table,
+};
+
+use testing_table::test_table;
+
+struct User {
+    name: &'static str,
+    age: u8,
+    email: &'static str,
+}
+
+const USERS: [User; 2] = [
+    User {
+        name: "Alice",
+        age: 7,
+        email: "alice@example.com",
+    },
+    User {
+        name: "Bob",
+        age: 32,
+        email: "bob@example.com",
+    },
+];
+
+test_table!(
+    table_def,
+    TableDef::new()
+        .column("Name", |u: &User| u.name.to_string())
+        .column_with("Age", |u| u.age.to_string(), Alignment::right())
+        .build(&USERS),
+    "+-------+-----+"
+    "| Name  | Age |"
+    "+-------+-----+"
+    "| Alice |   7 |"
+    "+-------+-----+"
+    "| Bob   |  32 |"
+    "+-------+-----+"
+);
+
+test_table!(
+    table_def_different_columns,
+    TableDef::new()
+        .column("Email", |u: &User| u.email.to_string())
+        .column_with("Name", |u| u.name.to_uppercase(), Alignment::center())
+        .build(&USERS)
+        .with(Style::markdown()),
+    "| Email             | Name  |"
+    "|-------------------|-------|"
+    "| alice@example.com | ALICE |"
+    "| bob@example.com   |  BOB  |"
+);
+
+test_table!(
+    table_def_no_data,
+    TableDef::new()
+        .column("Name", |u: &User| u.name.to_string())
+        .build(&[]),
+    "+------+"
+    "| Name |"
+    "+------+"
+);
+
+#[test]
+fn table_def_count_columns() {
+    let def = TableDef::new()
+        .column("Name", |u: &User| u.name.to_string())
+        .column("Age", |u| u.age.to_string());
+
+    assert_eq!(def.count_columns(), 2);
+    assert_eq!(
+        format!("{:?}", def),
+        "TableDef { columns: [\"Name\", \"Age\"] }"
+    );
+}
+
+enum Value {
+    Text(&'static str),
+    Number(i64),
+    Null,
+}
+
+impl Value {
+    fn render(&self) -> String {
+        match self {
+            Value::Text(text) => text.to_string(),
+            Value::Number(n) => n.to_string(),
+            Value::Null => String::from("null"),
+        }
+    }
+}
+
+type Record = Vec<(&'static str, Value)>;
+
+fn get(record: &Record, key: &str) -> String {
+    record
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map_or_else(String::new, |(_, v)| v.render())
+}
+
+fn records() -> Vec<Record> {
+    vec![
+        vec![("name", Value::Text("Alice")), ("age", Value::Number(7))],
+        vec![
+            ("name", Value::Text("Bob")),
+            ("age", Value::Null),
+            ("city", Value::Text("Oslo")),
+        ],
+    ]
+}
+
+test_table!(
+    table_with_schema,
+    Table::with_schema(
+        &Schema::new(["name", "age"])
+            .field(|r: &Record| get(r, "name"))
+            .field(|r| get(r, "age")),
+        &records(),
+    )
+    .unwrap(),
+    "+-------+------+"
+    "| name  | age  |"
+    "+-------+------+"
+    "| Alice | 7    |"
+    "+-------+------+"
+    "| Bob   | null |"
+    "+-------+------+"
+);
+
+test_table!(
+    table_with_another_schema,
+    Table::with_schema(
+        &Schema::new(["City", "Name", "Keys"])
+            .field(|r: &Record| get(r, "city"))
+            .field(|r| get(r, "name").to_uppercase())
+            .field(|r| r.len().to_string()),
+        &records(),
+    )
+    .unwrap(),
+    "+------+-------+------+"
+    "| City | Name  | Keys |"
+    "+------+-------+------+"
+    "|      | ALICE | 2    |"
+    "+------+-------+------+"
+    "| Oslo | BOB   | 3    |"
+    "+------+-------+------+"
+);
+
+#[test]
+fn table_with_schema_mismatch() {
+    let schema = Schema::new(["name", "age"]).field(|r: &Record| get(r, "name"));
+    let err = Table::with_schema(&schema, &records()).unwrap_err();
+    assert_eq!(
+        err,
+        SchemaError {
+            headers: 2,
+            fields: 1
+        }
+    );
+    assert_eq!(err.to_string(), "a schema has 2 headers but 1 fields");
+
+    let schema = Schema::<Record>::new(Vec::<String>::new()).field(|r| get(r, "name"));
+    assert!(Table::with_schema(&schema, &records()).is_err());
+
+    let schema = Schema::<Record>::new(Vec::<String>::new());
+    let table = Table::with_schema(&schema, &records()).unwrap();
+    assert_eq!(table.count_columns(), 0);
+    assert_eq!(schema.headers().len(), 0);
+}
@@ -1,15 +1,30 @@
 #![cfg(feature = "std")]
 
-use std::iter::FromIterator;
+use std::{
+    collections::{hash_map::DefaultHasher, HashSet},
+    hash::{Hash, Hasher},
+    iter::FromIterator,
+};
 
 use tabled::{
     builder::Builder,
-    settings::{formatting::Charset, Height, Modify, Padding, Settings, Style, Width},
+    grid::{
+        config::ColoredConfig,
+        records::vec_records::{CellInfo, VecRecords},
+        util::string::string_width,
+    },
+    settings::{
+        formatting::Charset,
+        panel::Caption,
+        style::{HorizontalBorderRepeat, HorizontalLine},
+        Alignment, Color, DynCellOption, Height, Margin, Modify, Padding, Settings, Span, Style,
+        Width,
+    },
     Table,
 };
 
 use crate::matrix::Matrix;
-use testing_table::test_table;
+use testing_table::{static_table, test_table};
 
 mod default_types {
     use super::*;
@@ -26,6 +41,20 @@ mod default_types {
         "+-------+"
     );
 
+    test_table!(
+        table_reverse_vec,
+        Table::new(vec![std::cmp::Reverse(3u32), std::cmp::Reverse(2), std::cmp::Reverse(1)]),
+        "+-----+"
+        "| u32 |"
+        "+-----+"
+        "| 3   |"
+        "+-----+"
+        "| 2   |"
+        "+-----+"
+        "| 1   |"
+        "+-----+"
+    );
+
     test_table!(
         table_char_vec,
         Table::new(vec!['a', 'b', 'c']),
@@ -293,7 +322,6 @@ test_table!(
     r#"│ This is the 0.19 release of Nushell. If you'd like to read more about it, please check out: https://www.nushell.sh/blog/2020/09/01/nushell_0_19.html                                                                                                                 │"#
     r#"│                                                                                                                                                                                                                                                                      │"#
     r#"│ For convenience, we are providing full builds for Windows, Linux, and macOS. These are the "all extra features" builds, so be sure you have the requirements to enable all capabilities: https://github.com/nushell/book/blob/master/en/installation.md#dependencies │"#
-    r#"│                                                                                                                                                                                                                                                                      │"#
     r#"└──────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────┘"#
 );
 
@@ -313,7 +341,6 @@ test_table!(
     "│ s for Windows, Linux, and macOS. These are the \"all extra features\" builds, so be sure you have  │"
     "│ the requirements to enable all capabilities: https://github.com/nushell/book/blob/master/en/inst │"
     "│ allation.md#dependencies                                                                         │"
-    "│                                                                                                  │"
     "└──────────────────────────────────────────────────────────────────────────────────────────────────┘"
 );
 
@@ -333,10 +360,72 @@ test_table!(
     "│ s for Windows, Linux, and macOS. These are the \"all extra features\" builds, so be sure you have  │"
     "│ the requirements to enable all capabilities: https://github.com/nushell/book/blob/master/en/inst │"
     "│ allation.md#dependencies                                                                         │"
-    "│                                                                                                  │"
     "└──────────────────────────────────────────────────────────────────────────────────────────────────┘"
 );
 
+#[cfg(feature = "chrono")]
+mod chrono_types {
+    use super::*;
+
+    use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2023, 12, 20).unwrap()
+    }
+
+    test_table!(
+        table_naive_date,
+        Table::new([date()]),
+        "+------------+"
+        "| NaiveDate  |"
+        "+------------+"
+        "| 2023-12-20 |"
+        "+------------+"
+    );
+
+    test_table!(
+        table_naive_datetime,
+        Table::new([date().and_hms_opt(8, 5, 30).unwrap()]),
+        "+---------------------+"
+        "| NaiveDateTime       |"
+        "+---------------------+"
+        "| 2023-12-20 08:05:30 |"
+        "+---------------------+"
+    );
+
+    test_table!(
+        table_datetime_utc,
+        Table::new([Utc.with_ymd_and_hms(2023, 12, 20, 8, 5, 30).unwrap()]),
+        "+---------------------------+"
+        "| DateTime                  |"
+        "+---------------------------+"
+        "| 2023-12-20T08:05:30+00:00 |"
+        "+---------------------------+"
+    );
+
+    test_table!(
+        table_datetime_fixed_offset,
+        Table::new([FixedOffset::east_opt(2 * 3600).unwrap().with_ymd_and_hms(2023, 12, 20, 8, 5, 30).unwrap()]),
+        "+---------------------------+"
+        "| DateTime                  |"
+        "+---------------------------+"
+        "| 2023-12-20T08:05:30+02:00 |"
+        "+---------------------------+"
+    );
+
+    #[test]
+    fn datetime_display_with_functions() {
+        let datetime = date().and_hms_milli_opt(8, 5, 30, 250).unwrap();
+
+        assert_eq!(tabled::datetime::date(&date()), "2023-12-20");
+        assert_eq!(tabled::datetime::datetime(&datetime), "2023-12-20 08:05:30");
+        assert_eq!(
+            tabled::datetime::rfc3339(&Utc.from_utc_datetime(&datetime)),
+            "2023-12-20T08:05:30.250+00:00"
+        );
+    }
+}
+
 #[cfg(feature = "derive")]
 mod derived {
     use super::*;
@@ -371,6 +460,34 @@ mod derived {
         "+----+----+"
     );
 
+    test_table!(
+        table_arc_vector_structures,
+        Table::new(vec![
+            std::sync::Arc::new(TestType { f1: 0, f2: "0" }),
+            std::sync::Arc::new(TestType { f1: 1, f2: "1" }),
+        ]),
+        "+----+----+"
+        "| f1 | f2 |"
+        "+----+----+"
+        "| 0  | 0  |"
+        "+----+----+"
+        "| 1  | 1  |"
+        "+----+----+"
+    );
+
+    test_table!(
+        table_rc_and_box_structures,
+        Table::new([(
+            std::rc::Rc::new(TestType { f1: 0, f2: "0" }),
+            Box::new(TestType { f1: 1, f2: "1" }),
+        )]),
+        "+----+----+----+----+"
+        "| f1 | f2 | f1 | f2 |"
+        "+----+----+----+----+"
+        "| 0  | 0  | 1  | 1  |"
+        "+----+----+----+----+"
+    );
+
     test_table!(
         table_option,
         Table::new(Some(TestType { f1: 0, f2: "0" })),
@@ -860,3 +977,816 @@ test_table!(
     "|      1      |      1-0      |   1-1    |   1-2    |"
     "|      2      |      2-0      |   2-1    |   2-2    |"
 );
+
+test_table!(
+    table_zebra_coloring,
+    Table::new([1, 2, 3])
+        .with(Style::psql())
+        .with_zebra_coloring(Color::BG_WHITE, Color::BG_BLACK),
+    "\u{1b}[47m \u{1b}[49m\u{1b}[47mi32\u{1b}[49m\u{1b}[47m \u{1b}[49m"
+    "-----"
+    "\u{1b}[40m \u{1b}[49m\u{1b}[40m1\u{1b}[49m\u{1b}[40m  \u{1b}[49m\u{1b}[40m \u{1b}[49m"
+    "\u{1b}[47m \u{1b}[49m\u{1b}[47m2\u{1b}[49m\u{1b}[47m  \u{1b}[49m\u{1b}[47m \u{1b}[49m"
+    "\u{1b}[40m \u{1b}[49m\u{1b}[40m3\u{1b}[49m\u{1b}[40m  \u{1b}[49m\u{1b}[40m \u{1b}[49m"
+);
+
+test_table!(
+    table_header_color,
+    Table::new([1, 2])
+        .with(Style::psql())
+        .with_zebra_coloring(Color::BG_WHITE, Color::BG_BLACK)
+        .with_header_color(Color::FG_GREEN),
+    "\u{1b}[32m \u{1b}[39m\u{1b}[32mi32\u{1b}[39m\u{1b}[32m \u{1b}[39m"
+    "-----"
+    "\u{1b}[40m \u{1b}[49m\u{1b}[40m1\u{1b}[49m\u{1b}[40m  \u{1b}[49m\u{1b}[40m \u{1b}[49m"
+    "\u{1b}[47m \u{1b}[49m\u{1b}[47m2\u{1b}[49m\u{1b}[47m  \u{1b}[49m\u{1b}[47m \u{1b}[49m"
+);
+
+test_table!(
+    table_from_fn_multiplication,
+    Table::from_fn_with_header(
+        4,
+        4,
+        |col| if col == 0 { String::from("x") } else { col.to_string() },
+        |row, col| match col {
+            0 => (row + 1).to_string(),
+            _ => ((row + 1) * col).to_string(),
+        },
+    )
+    .with(Style::markdown()),
+    "| x | 1 | 2 | 3  |"
+    "|---|---|---|----|"
+    "| 1 | 1 | 2 | 3  |"
+    "| 2 | 2 | 4 | 6  |"
+    "| 3 | 3 | 6 | 9  |"
+    "| 4 | 4 | 8 | 12 |"
+);
+
+test_table!(
+    table_from_rows_and_headers,
+    Table::from_rows_and_headers(
+        ["id", "name"],
+        vec![vec!["1", "Alex"], vec!["2"], vec!["3", "Sam", "admin"]],
+    ),
+    "+----+------+-------+"
+    "| id | name |       |"
+    "+----+------+-------+"
+    "| 1  | Alex |       |"
+    "+----+------+-------+"
+    "| 2  |      |       |"
+    "+----+------+-------+"
+    "| 3  | Sam  | admin |"
+    "+----+------+-------+"
+);
+
+test_table!(
+    table_from_rows_and_headers_no_rows,
+    Table::from_rows_and_headers(["id", "name"], Vec::<Vec<String>>::new()),
+    "+----+------+"
+    "| id | name |"
+    "+----+------+"
+);
+
+test_table!(
+    table_from_fn_empty,
+    Table::from_fn(0, 3, |_, _| unreachable!()),
+    ""
+);
+
+#[test]
+fn table_from_fn_call_order() {
+    let mut calls = Vec::new();
+    let table = Table::from_fn(2, 2, |row, col| {
+        calls.push((row, col));
+        format!("{}{}", row, col)
+    });
+
+    assert_eq!(calls, [(0, 0), (0, 1), (1, 0), (1, 1)]);
+    assert_eq!(table.shape(), (2, 2));
+    assert_eq!(
+        table.to_string(),
+        Builder::from_iter([["00", "01"], ["10", "11"]])
+            .build()
+            .to_string()
+    );
+}
+
+#[test]
+fn table_hash_ignores_style() {
+    let table1 = Table::from_iter([["a", "b"], ["c", "d"]]);
+    let mut table2 = table1.clone();
+    table2.with(Style::modern());
+
+    assert_ne!(table1, table2);
+    assert_eq!(hash(&table1), hash(&table2));
+}
+
+#[test]
+fn table_hash_depends_on_data() {
+    let table1 = Table::from_iter([["a", "b"], ["c", "d"]]);
+    let table2 = Table::from_iter([["a", "b"], ["c", "e"]]);
+    let table3 = Table::from_iter([vec!["a", "b", "c", "d"]]);
+
+    assert_ne!(hash(&table1), hash(&table2));
+    assert_ne!(hash(&table1), hash(&table3));
+}
+
+#[test]
+fn table_hash_set() {
+    let mut set = HashSet::new();
+    set.insert(Table::from_iter([["a", "b"]]));
+    set.insert(Table::from_iter([["a", "b"]]));
+    set.insert(Table::from_iter([["c", "d"]]));
+
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&Table::from_iter([["c", "d"]])));
+}
+
+fn hash(table: &Table) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    table.hash(&mut hasher);
+    hasher.finish()
+}
+
+test_table!(
+    to_string_without_header,
+    Matrix::table(2, 2).to_string_without_header(),
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    to_string_without_header_modern,
+    Matrix::new(2, 2).with(Style::modern()).to_string_without_header(),
+    "┌───┬──────────┬──────────┐"
+    "│ 0 │   0-0    │   0-1    │"
+    "├───┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │"
+    "└───┴──────────┴──────────┘"
+);
+
+test_table!(
+    to_string_without_header_border_repeat,
+    Matrix::new(2, 2)
+        .with(Style::modern())
+        .with(HorizontalBorderRepeat(2))
+        .to_string_without_header(),
+    "┌───┬──────────┬──────────┐"
+    "┌───┬──────────┬──────────┐"
+    "│ 0 │   0-0    │   0-1    │"
+    "├───┼──────────┼──────────┤"
+    "├───┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │"
+    "└───┴──────────┴──────────┘"
+    "└───┴──────────┴──────────┘"
+);
+
+test_table!(
+    to_string_without_header_psql,
+    Matrix::new(2, 2).with(Style::psql()).to_string_without_header(),
+    " 0 |   0-0    |   0-1    "
+    " 1 |   1-0    |   1-1    "
+);
+
+test_table!(
+    to_string_without_header_multiline_header,
+    Table::from_iter([["long\nheader", "b"], ["1", "2"]]).to_string_without_header(),
+    "+--------+---+"
+    "| 1      | 2 |"
+    "+--------+---+"
+);
+
+#[test]
+fn to_string_without_header_only_header() {
+    let table = Table::from_iter([["a", "b"]]);
+    assert_eq!(table.to_string_without_header(), "");
+
+    let table = Table::from_iter(Vec::<Vec<String>>::new());
+    assert_eq!(table.to_string_without_header(), "");
+}
+
+test_table!(
+    render_borders_only,
+    Matrix::new(2, 2).with(Style::modern()).render_borders_only(),
+    "┌───┬──────────┬──────────┐"
+    "│   │          │          │"
+    "├───┼──────────┼──────────┤"
+    "│   │          │          │"
+    "├───┼──────────┼──────────┤"
+    "│   │          │          │"
+    "└───┴──────────┴──────────┘"
+);
+
+test_table!(
+    render_borders_only_multiline_and_span,
+    Table::from_iter([["long\nheader", "b"], ["1", "2"]])
+        .modify((1, 0), Span::column(2))
+        .render_borders_only(),
+    "+--------+---+"
+    "|        |   |"
+    "|        |   |"
+    "+--------+---+"
+    "|            |"
+    "+--------+---+"
+);
+
+#[test]
+fn render_borders_only_keeps_layout() {
+    let table = Matrix::new(3, 3)
+        .with(Settings::new(Style::extended(), Padding::new(2, 1, 1, 0)))
+        .clone();
+
+    let rendered = table.to_string();
+    let borders = table.render_borders_only();
+
+    assert_eq!(rendered.lines().count(), borders.lines().count());
+    for (line, blank) in rendered.lines().zip(borders.lines()) {
+        assert_eq!(line.chars().count(), blank.chars().count());
+    }
+
+    let table = Table::from_iter(Vec::<Vec<String>>::new());
+    assert_eq!(table.render_borders_only(), "");
+}
+
+test_table!(
+    columns_window_start,
+    Matrix::table(2, 12).columns_window(0, 40, &[0]),
+    "+---+----------+----------+---+"
+    "| N | column 0 | column 1 | … |"
+    "+---+----------+----------+---+"
+    "| 0 |   0-0    |   0-1    | … |"
+    "+---+----------+----------+---+"
+    "| 1 |   1-0    |   1-1    | … |"
+    "+---+----------+----------+---+"
+);
+
+test_table!(
+    columns_window_middle,
+    Matrix::table(2, 12).columns_window(6, 40, &[0]),
+    "+---+---+----------+----------+---+"
+    "| N | … | column 5 | column 6 | … |"
+    "+---+---+----------+----------+---+"
+    "| 0 | … |   0-5    |   0-6    | … |"
+    "+---+---+----------+----------+---+"
+    "| 1 | … |   1-5    |   1-6    | … |"
+    "+---+---+----------+----------+---+"
+);
+
+test_table!(
+    columns_window_end,
+    Matrix::table(2, 12).columns_window(11, 40, &[0]),
+    "+---+---+-----------+-----------+"
+    "| N | … | column 10 | column 11 |"
+    "+---+---+-----------+-----------+"
+    "| 0 | … |   0-10    |   0-11    |"
+    "+---+---+-----------+-----------+"
+    "| 1 | … |   1-10    |   1-11    |"
+    "+---+---+-----------+-----------+"
+);
+
+test_table!(
+    columns_window_without_frozen,
+    Matrix::new(2, 12).with(Style::modern()).columns_window(3, 40, &[]),
+    "┌───┬──────────┬──────────┬───┐"
+    "│ … │ column 2 │ column 3 │ … │"
+    "├───┼──────────┼──────────┼───┤"
+    "│ … │   0-2    │   0-3    │ … │"
+    "├───┼──────────┼──────────┼───┤"
+    "│ … │   1-2    │   1-3    │ … │"
+    "└───┴──────────┴──────────┴───┘"
+);
+
+#[test]
+fn columns_window_fits_budget() {
+    let table = Matrix::table(2, 12);
+    for start in 0..=table.count_columns() {
+        for budget in 20..60 {
+            let window = table.columns_window(start, budget, &[0]);
+            let width = window.to_string().lines().next().unwrap().chars().count();
+            assert!(
+                width <= budget,
+                "start={} budget={} width={}",
+                start,
+                budget,
+                width
+            );
+        }
+    }
+
+    let window = table.columns_window(0, 1000, &[0]);
+    assert_eq!(window.to_string(), table.to_string());
+
+    let table = Table::from_iter(Vec::<Vec<String>>::new());
+    assert_eq!(table.columns_window(0, 10, &[0]).to_string(), "");
+}
+
+#[cfg(feature = "ansi")]
+test_table!(
+    to_ansi_stripped,
+    Matrix::new(2, 2)
+        .with(Style::modern())
+        .with(Color::FG_GREEN)
+        .with(tabled::settings::style::BorderColor::filled(Color::BG_BLUE))
+        .to_ansi_stripped(),
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │"
+    "├───┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │"
+    "└───┴──────────┴──────────┘"
+);
+
+#[cfg(feature = "ansi")]
+#[test]
+fn to_ansi_stripped_keeps_layout() {
+    let table = Table::from_iter([[
+        "\u{1b}[31mred\u{1b}[0m",
+        "\u{1b}]8;;https://a.b\u{1b}\\link\u{1b}]8;;\u{1b}\\",
+    ]])
+    .with(Width::wrap(3))
+    .clone();
+
+    let stripped = table.to_ansi_stripped();
+    assert_eq!(stripped, ansi_str::AnsiStr::ansi_strip(&table.to_string()));
+    assert_eq!(
+        table.total_width(),
+        stripped.lines().next().unwrap().chars().count()
+    );
+}
+
+#[test]
+fn table_write_to() {
+    let table = Matrix::new(3, 3).with(Style::modern()).clone();
+
+    let mut buf = Vec::new();
+    table.write_to(&mut buf).unwrap();
+
+    assert_eq!(String::from_utf8(buf).unwrap(), table.to_string());
+}
+
+#[test]
+fn table_write_to_captions_and_repeat() {
+    let table = Matrix::new(3, 3)
+        .with(Style::modern())
+        .with(HorizontalBorderRepeat(2))
+        .with(Margin::new(1, 1, 1, 1))
+        .with(Caption::top("top"))
+        .with(Caption::bottom("bottom"))
+        .clone();
+
+    let mut buf = Vec::new();
+    table.write_to(&mut buf).unwrap();
+
+    assert_eq!(String::from_utf8(buf).unwrap(), table.to_string());
+}
+
+#[test]
+fn table_write_to_error() {
+    struct BrokenWriter;
+
+    impl std::io::Write for BrokenWriter {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::BrokenPipe,
+                "closed",
+            ))
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let table = Matrix::new(3, 3).with(Caption::top("top")).clone();
+
+    let err = table.write_to(BrokenWriter).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::BrokenPipe);
+    assert_eq!(err.to_string(), "closed");
+}
+
+#[test]
+fn table_write_to_empty() {
+    let table = Table::from_iter(Vec::<Vec<String>>::new());
+
+    let mut buf = Vec::new();
+    table.write_to(&mut buf).unwrap();
+
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn table_fmt_into() {
+    let table = Matrix::table(2, 2);
+
+    let mut buf = String::from("> ");
+    table.fmt_into(&mut buf);
+
+    assert_eq!(buf, format!("> {}", table));
+}
+
+#[test]
+fn table_rendered_size_hint() {
+    let table = Matrix::table(3, 3);
+    assert_eq!(table.rendered_size_hint(), table.to_string().len());
+
+    let table = Table::from_iter([["a", "b"]]);
+    assert_eq!(table.rendered_size_hint(), table.to_string().len());
+
+    let table = Table::from_iter(Vec::<Vec<String>>::new());
+    assert_eq!(table.rendered_size_hint(), 0);
+}
+
+#[test]
+fn apply_width_to_match() {
+    let table1 = Table::from_iter([["a", "long header"], ["1", "2"]]);
+    let table2 = Table::from_iter([vec!["long value", "b", "c"], vec!["3", "4", "5"]]);
+
+    let table = table1.apply_width_to_match(&table2);
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            "+------------+-------------+"
+            "| a          | long header |"
+            "+------------+-------------+"
+            "| 1          | 2           |"
+            "+------------+-------------+"
+        )
+    );
+
+    let table = table2.apply_width_to_match(&table1);
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            "+------------+-------------+---+"
+            "| long value | b           | c |"
+            "+------------+-------------+---+"
+            "| 3          | 4           | 5 |"
+            "+------------+-------------+---+"
+        )
+    );
+
+    assert_eq!(
+        table1.apply_width_to_match(&table1),
+        table1.clone().with(Width::list([3, 13])).clone()
+    );
+}
+
+test_table!(
+    with_auto_alignment,
+    Builder::from_iter([
+        ["name", "price", "in stock", "note"],
+        ["Apple", "1.5", "true", ""],
+        ["Pear", "12", "false", "ripe"],
+        ["Plum", "", "true", "3"],
+    ])
+    .build()
+    .with_auto_alignment(),
+    "+-------+-------+----------+------+"
+    "| name  | price | in stock | note |"
+    "+-------+-------+----------+------+"
+    "| Apple |   1.5 |   true   |      |"
+    "+-------+-------+----------+------+"
+    "| Pear  |    12 |  false   | ripe |"
+    "+-------+-------+----------+------+"
+    "| Plum  |       |   true   | 3    |"
+    "+-------+-------+----------+------+"
+);
+
+test_table!(
+    with_auto_alignment_mixed_column_is_left,
+    Table::new([["a", "1"], ["b", "true"], ["c", "x"]]).with_auto_alignment(),
+    "+---+------+"
+    "| 0 | 1    |"
+    "+---+------+"
+    "| a | 1    |"
+    "+---+------+"
+    "| b | true |"
+    "+---+------+"
+    "| c | x    |"
+    "+---+------+"
+);
+
+test_table!(
+    with_auto_alignment_empty_column_is_left,
+    Builder::from_iter([["header", "h"], ["", "1"], ["", "2000"]]).build().with_auto_alignment(),
+    "+--------+------+"
+    "| header |    h |"
+    "+--------+------+"
+    "|        |    1 |"
+    "+--------+------+"
+    "|        | 2000 |"
+    "+--------+------+"
+);
+
+test_table!(
+    with_auto_alignment_keeps_alignment_of_other_columns,
+    Builder::from_iter([["name", "price", "note"], ["Apple", "1.5", "ripe"], ["Fig", "12", "sweet"]])
+        .build()
+        .with(Alignment::center())
+        .with_auto_alignment(),
+    "+-------+-------+-------+"
+    "| name  | price | note  |"
+    "+-------+-------+-------+"
+    "| Apple |   1.5 | ripe  |"
+    "+-------+-------+-------+"
+    "|  Fig  |    12 | sweet |"
+    "+-------+-------+-------+"
+);
+
+test_table!(
+    with_auto_alignment_formatted_numbers,
+    Builder::from_iter([["price", "share", "ratio"], ["$1,200.50", "15%", "NaN"], ["-€3", "2.5%", "inf"]])
+        .build()
+        .with_auto_alignment(),
+    "+-----------+-------+-------+"
+    "|     price | share | ratio |"
+    "+-----------+-------+-------+"
+    "| $1,200.50 |   15% | NaN   |"
+    "+-----------+-------+-------+"
+    "|       -€3 |  2.5% | inf   |"
+    "+-----------+-------+-------+"
+);
+
+test_table!(
+    with_numbered_rows,
+    Matrix::new(3, 2).with(Style::psql()).with_numbered_rows(1, "#"),
+    " # | N | column 0 | column 1 "
+    "---+---+----------+----------"
+    " 1 | 0 |   0-0    |   0-1    "
+    " 2 | 1 |   1-0    |   1-1    "
+    " 3 | 2 |   2-0    |   2-1    "
+);
+
+test_table!(
+    with_numbered_rows_width,
+    Table::new(["a", "b", "c"]).with_numbered_rows(98, "row"),
+    "+-----+------+"
+    "| row | &str |"
+    "+-----+------+"
+    "| 98  | a    |"
+    "+-----+------+"
+    "| 99  | b    |"
+    "+-----+------+"
+    "| 100 | c    |"
+    "+-----+------+"
+);
+
+test_table!(
+    with_numbered_rows_keeps_spans,
+    Matrix::new(2, 2)
+        .with(Modify::new((1, 1)).with(Span::column(2)))
+        .with_numbered_rows(0, "#"),
+    "+---+---+----------+----------+"
+    "| # | N | column 0 | column 1 |"
+    "+---+---+----------+----------+"
+    "| 0 | 0 |         0-0         |"
+    "+---+---+----------+----------+"
+    "| 1 | 1 |   1-0    |   1-1    |"
+    "+---+---+----------+----------+"
+);
+
+test_table!(
+    with_numbered_rows_empty,
+    Builder::default().build().with_numbered_rows(0, "#"),
+    ""
+);
+
+#[test]
+fn column_widths_include_padding() {
+    let mut table = Table::from_iter([["id", "name"], ["1", "Alexander"]]);
+    assert_eq!(table.column_widths(), [4, 11]);
+
+    table.with(Padding::new(2, 3, 1, 1));
+    assert_eq!(table.column_widths(), [7, 14]);
+
+    table.with(Padding::zero());
+    assert_eq!(table.column_widths(), [2, 9]);
+
+    assert!(Table::default().column_widths().is_empty());
+}
+
+test_table!(
+    column_widths_synchronize_tables,
+    {
+        let mut header = Table::from_iter([["id", "name"]]);
+        header.with(Padding::new(3, 3, 0, 0));
+
+        let mut body = Table::from_iter([["1", "Alex"]]);
+        body.with(Width::list(header.column_widths()));
+
+        format!("{}\n{}", header, body)
+    },
+    "+--------+----------+"
+    "|   id   |   name   |"
+    "+--------+----------+"
+    "+--------+----------+"
+    "| 1      | Alex     |"
+    "+--------+----------+"
+);
+
+#[test]
+fn table_has_data_rows() {
+    assert!(!Table::default().has_data_rows());
+    assert!(!Table::new(Vec::<(u8, &str)>::new()).has_data_rows());
+    assert!(!Table::from_iter([["header"]]).has_data_rows());
+    assert!(Table::from_iter([["header"], ["value"]]).has_data_rows());
+    assert!(Matrix::table(1, 0).has_data_rows());
+
+    let mut builder = Builder::default();
+    builder.push_record(Vec::<String>::new());
+    builder.push_record(Vec::<String>::new());
+    assert!(!builder.build().has_data_rows());
+}
+
+#[test]
+fn table_empty_display() {
+    macro_rules! check {
+        ($($style:ident),*) => {$(
+            let mut table = Builder::default().build();
+            table.with(Style::$style());
+            assert!(table.is_empty());
+            assert_eq!(table.to_string(), "", stringify!($style));
+
+            let mut table = Table::new(Vec::<(u8, &str)>::new());
+            table.with(Style::$style());
+            assert!(!table.is_empty());
+
+            let output = table.to_string();
+            let widths = output.lines().map(string_width).collect::<HashSet<_>>();
+            assert_eq!(widths.len(), 1, stringify!($style));
+            assert!(output.contains("u8"), stringify!($style));
+        )*};
+    }
+
+    check!(
+        empty,
+        blank,
+        ascii,
+        psql,
+        markdown,
+        modern,
+        sharp,
+        rounded,
+        modern_rounded,
+        extended,
+        heavy,
+        heavy_light,
+        dots,
+        re_structured_text,
+        ascii_rounded,
+        header_separator_only
+    );
+}
+
+test_table!(
+    table_header_only_ascii,
+    Table::new(Vec::<(u8, &str)>::new()).with(Style::ascii()),
+    "+----+------+"
+    "| u8 | &str |"
+    "+----+------+"
+);
+
+test_table!(
+    table_header_only_modern,
+    Table::new(Vec::<(u8, &str)>::new()).with(Style::modern()),
+    "┌────┬──────┐"
+    "│ u8 │ &str │"
+    "└────┴──────┘"
+);
+
+test_table!(
+    table_header_only_sharp,
+    Table::new(Vec::<(u8, &str)>::new()).with(Style::sharp()),
+    "┌────┬──────┐"
+    "│ u8 │ &str │"
+    "└────┴──────┘"
+);
+
+test_table!(
+    table_header_only_rounded,
+    Table::new(Vec::<(u8, &str)>::new()).with(Style::rounded()),
+    "╭────┬──────╮"
+    "│ u8 │ &str │"
+    "╰────┴──────╯"
+);
+
+test_table!(
+    table_header_only_psql,
+    Table::new(Vec::<(u8, &str)>::new()).with(Style::psql()),
+    " u8 | &str "
+    "----+------"
+);
+
+test_table!(
+    table_header_only_markdown,
+    Table::new(Vec::<(u8, &str)>::new()).with(Style::markdown()),
+    "| u8 | &str |"
+    "|----|------|"
+);
+
+test_table!(
+    table_header_only_keeps_explicit_line,
+    Table::new(Vec::<(u8, &str)>::new())
+        .with(Style::rounded().horizontals([(1, HorizontalLine::full('=', '+', '+', '+'))])),
+    "╭────┬──────╮"
+    "│ u8 │ &str │"
+    "+====+======+"
+);
+
+test_table!(
+    table_header_only_keeps_explicit_preset_line,
+    Table::new(Vec::<(u8, &str)>::new())
+        .with(Style::rounded().horizontals([(1, HorizontalLine::full('─', '┼', '├', '┤'))])),
+    "╭────┬──────╮"
+    "│ u8 │ &str │"
+    "├────┼──────┤"
+);
+
+test_table!(
+    table_with_settings_list_for_row,
+    Matrix::table(2, 2)
+        .with_settings_list_for_row(1, settings_list())
+        .clone(),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "|  0|        0-|        0-|"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    table_with_settings_list_for_column,
+    Matrix::table(2, 2)
+        .with_settings_list_for_column(2, settings_list())
+        .clone(),
+    "+---+----------+----+"
+    "| N | column 0 |  co|"
+    "+---+----------+----+"
+    "| 0 |   0-0    |  0-|"
+    "+---+----------+----+"
+    "| 1 |   1-0    |  1-|"
+    "+---+----------+----+"
+);
+
+fn settings_list() -> Vec<Box<dyn DynCellOption<VecRecords<CellInfo<String>>, ColoredConfig>>> {
+    vec![
+        Box::new(Width::truncate(2)),
+        Box::new(Alignment::right()),
+        Box::new(Padding::new(2, 0, 0, 0)),
+    ]
+}
+
+test_table!(
+    table_with_settings_for_column,
+    Matrix::table(2, 2)
+        .with_settings_for_column(1, Settings::new(Alignment::right(), Padding::new(2, 0, 0, 0)))
+        .clone(),
+    "+---+----------+----------+"
+    "| N |  column 0| column 1 |"
+    "+---+----------+----------+"
+    "| 0 |       0-0|   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |       1-0|   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    table_with_settings_for_row,
+    Matrix::table(2, 2)
+        .with_settings_for_row(2, vec![Alignment::left(), Alignment::right()])
+        .with_settings_for_row(2, Padding::new(1, 1, 1, 0))
+        .clone(),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "|   |          |          |"
+    "| 1 |      1-0 |      1-1 |"
+    "+---+----------+----------+"
+);
+
+#[test]
+fn table_with_no_borders() {
+    let mut table = Matrix::new(2, 3).with(Style::extended()).clone();
+    table.with_no_borders();
+
+    assert_eq!(
+        table.to_string(),
+        Matrix::new(2, 3).with(Style::blank()).to_string()
+    );
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            " N   column 0   column 1   column 2 "
+            " 0     0-0        0-1        0-2    "
+            " 1     1-0        1-1        1-2    "
+        )
+    );
+}
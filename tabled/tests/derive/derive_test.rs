@@ -2,6 +2,7 @@
 #![cfg(feature = "std")]
 
 use tabled::Tabled;
+use testing_table::static_table;
 
 // https://users.rust-lang.org/t/create-a-struct-from-macro-rules/19829
 macro_rules! test_tuple {
@@ -1223,6 +1224,346 @@ fn test_display_with_2() {
     }
 }
 
+#[test]
+fn test_transparent() {
+    #[derive(Tabled)]
+    #[tabled(transparent)]
+    struct UserId(u64);
+
+    #[derive(Tabled)]
+    #[tabled(transparent)]
+    struct Email(String);
+
+    #[derive(Tabled)]
+    #[tabled(transparent)]
+    struct Score {
+        #[tabled(format = "{}%")]
+        value: u8,
+    }
+
+    #[derive(Tabled)]
+    struct User {
+        #[tabled(inline)]
+        id: UserId,
+        #[tabled(inline, rename = "mail")]
+        email: Email,
+        #[tabled(inline("user::"))]
+        score: Score,
+    }
+
+    assert_eq!(<UserId as Tabled>::LENGTH, 1);
+    assert_eq!(UserId::headers(), vec![""]);
+    assert_eq!(UserId(123).fields(), vec!["123"]);
+    assert_eq!(Score { value: 99 }.fields(), vec!["99%"]);
+
+    let user = User {
+        id: UserId(1),
+        email: Email(String::from("user@mail.com")),
+        score: Score { value: 50 },
+    };
+
+    assert_eq!(<User as Tabled>::LENGTH, 3);
+    assert_eq!(User::headers(), vec!["id", "mail", "user::score"]);
+    assert_eq!(user.fields(), vec!["1", "user@mail.com", "50%"]);
+
+    let transparent = [
+        <UserId as Tabled>::TRANSPARENT,
+        <&Email as Tabled>::TRANSPARENT,
+        <User as Tabled>::TRANSPARENT,
+    ];
+    assert_eq!(transparent, [true, true, false]);
+}
+
+#[test]
+fn test_inline_keeps_empty_headers_of_non_transparent_type() {
+    #[derive(Tabled)]
+    struct Money {
+        #[tabled(rename = "")]
+        amount: u64,
+        currency: &'static str,
+    }
+
+    #[derive(Tabled)]
+    struct Order {
+        id: u8,
+        #[tabled(inline)]
+        price: Money,
+        #[tabled(inline("total."))]
+        total: Money,
+    }
+
+    let order = Order {
+        id: 1,
+        price: Money {
+            amount: 10,
+            currency: "EUR",
+        },
+        total: Money {
+            amount: 12,
+            currency: "EUR",
+        },
+    };
+
+    assert_eq!(
+        Order::headers(),
+        vec!["id", "", "currency", "total.", "total.currency"]
+    );
+    assert_eq!(order.fields(), vec!["1", "10", "EUR", "12", "EUR"]);
+}
+
+#[test]
+fn serde_rename() {
+    #[derive(Tabled, serde::Serialize)]
+    #[tabled(serde_rename)]
+    struct User {
+        #[serde(rename = "ID")]
+        id: u8,
+        #[serde(default, rename = "Full Name")]
+        name: &'static str,
+        #[serde(rename(serialize = "Mail", deserialize = "mail"))]
+        email: &'static str,
+        #[serde(skip_serializing_if = "str::is_empty")]
+        comment: &'static str,
+        #[serde(rename = "ignored")]
+        #[tabled(rename = "Age")]
+        age: u8,
+    }
+
+    assert_eq!(
+        User::headers(),
+        vec!["ID", "Full Name", "Mail", "comment", "Age"]
+    );
+}
+
+#[test]
+fn serde_rename_is_ignored_by_default() {
+    #[derive(Tabled, serde::Serialize)]
+    struct User {
+        #[serde(rename = "ID")]
+        id: u8,
+    }
+
+    assert_eq!(User::headers(), vec!["id"]);
+}
+
+#[test]
+fn serde_rename_with_rename_all() {
+    #[derive(Tabled, serde::Serialize)]
+    #[tabled(serde_rename, rename_all = "UPPERCASE")]
+    enum Status {
+        #[serde(rename = "ok")]
+        Ok,
+        Failed,
+    }
+
+    assert_eq!(Status::headers(), vec!["ok", "FAILED"]);
+    assert_eq!(Status::Ok.fields(), vec!["+", ""]);
+    assert_eq!(Status::Failed.fields(), vec!["", "+"]);
+}
+
+#[test]
+fn with_formatter_shared_by_fields() {
+    use tabled::CellFormatter;
+
+    struct Money;
+
+    impl CellFormatter<u64> for Money {
+        fn format(cents: &u64) -> String {
+            format!("${}.{:02}", cents / 100, cents % 100)
+        }
+    }
+
+    struct Precision<const N: usize>;
+
+    impl<const N: usize> CellFormatter<f64> for Precision<N> {
+        fn format(value: &f64) -> String {
+            format!("{:.1$}", value, N)
+        }
+    }
+
+    #[derive(Tabled)]
+    struct Order {
+        id: u8,
+        #[tabled(with = "Money")]
+        price: u64,
+        #[tabled(with = "Money", rename = "off")]
+        discount: u64,
+        #[tabled(with = "Precision<1>")]
+        rate: f64,
+    }
+
+    #[derive(Tabled)]
+    struct Pair(
+        #[tabled(with = "Money")] u64,
+        #[tabled(with = "Precision<3>")] f64,
+    );
+
+    #[derive(Tabled)]
+    enum Payment {
+        Card {
+            #[tabled(with = "Money")]
+            amount: u64,
+        },
+        Cash(#[tabled(with = "Money")] u64),
+    }
+
+    let order = Order {
+        id: 1,
+        price: 1250,
+        discount: 5,
+        rate: 0.25,
+    };
+
+    assert_eq!(Order::headers(), vec!["id", "price", "off", "rate"]);
+    assert_eq!(order.fields(), vec!["1", "$12.50", "$0.05", "0.2"]);
+    assert_eq!(Pair(100, 1.0).fields(), vec!["$1.00", "1.000"]);
+    assert_eq!(Payment::headers(), vec!["Card", "Cash"]);
+    assert_eq!(Payment::Card { amount: 99 }.fields(), vec!["+", ""]);
+    assert_eq!(Payment::Cash(7).fields(), vec!["", "+"]);
+}
+
+#[test]
+fn width_hints() {
+    use tabled::{ColumnHint, Table};
+
+    #[derive(Tabled)]
+    struct Task {
+        id: u8,
+        #[tabled(width = 10, wrap)]
+        description: &'static str,
+        #[tabled(width = 3, truncate)]
+        tag: &'static str,
+    }
+
+    let data = [Task {
+        id: 1,
+        description: "cook dinner for the whole family",
+        tag: "home",
+    }];
+
+    let table = Table::new(data).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+----+------------+-----+"
+            "| id | descriptio | tag |"
+            "|    | n          |     |"
+            "+----+------------+-----+"
+            "| 1  | cook dinne | hom |"
+            "|    | r for the  |     |"
+            "|    | whole fami |     |"
+            "|    | ly         |     |"
+            "+----+------------+-----+"
+        )
+    );
+
+    #[derive(Tabled)]
+    struct Project {
+        #[tabled(width = 5)]
+        name: &'static str,
+        #[tabled(inline)]
+        task: Task,
+        #[tabled(order = 0, width = 2, truncate)]
+        owner: &'static str,
+    }
+
+    assert_eq!(
+        Project::column_hints(),
+        [
+            ColumnHint {
+                column: 0,
+                width: 2,
+                wrap: false
+            },
+            ColumnHint {
+                column: 1,
+                width: 5,
+                wrap: true
+            },
+            ColumnHint {
+                column: 3,
+                width: 10,
+                wrap: true
+            },
+            ColumnHint {
+                column: 4,
+                width: 3,
+                wrap: false
+            },
+        ]
+    );
+    assert_eq!(<(u8, Task)>::column_hints()[0].column, 2);
+}
+
+#[test]
+fn width_hints_are_overridden_by_column_width() {
+    use tabled::{
+        settings::{object::Columns, Width},
+        Table,
+    };
+
+    #[derive(Tabled)]
+    struct Task {
+        id: u8,
+        #[tabled(width = 8)]
+        description: &'static str,
+    }
+
+    let data = [Task {
+        id: 1,
+        description: "cook dinner for the whole family",
+    }];
+
+    let mut table = Table::new(data);
+    table.modify(Columns::single(1), Width::wrap(20));
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            "+----+----------------------+"
+            "| id | description          |"
+            "+----+----------------------+"
+            "| 1  | cook dinner for the  |"
+            "|    | whole family         |"
+            "+----+----------------------+"
+        )
+    );
+}
+
+#[test]
+fn width_hints_are_overridden_by_modify_option() {
+    use tabled::{
+        settings::{object::Columns, Modify, Width},
+        Table,
+    };
+
+    #[derive(Tabled)]
+    struct Friend {
+        #[tabled(width = 8, wrap)]
+        note: &'static str,
+    }
+
+    let data = [Friend {
+        note: "inner width of friends",
+    }];
+
+    let mut table = Table::new(data);
+    table.with(Modify::new(Columns::single(0)).with(Width::wrap(20)));
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            "+----------------------+"
+            "| note                 |"
+            "+----------------------+"
+            "| inner width of frien |"
+            "| ds                   |"
+            "+----------------------+"
+        )
+    );
+}
+
 mod __ {
     #[test]
     fn dont_import_the_trait() {
@@ -1,13 +1,18 @@
 #![cfg(feature = "std")]
 
-use tabled::settings::{
-    location::ByColumnName,
-    object::{Columns, Rows, Segment},
-    Alignment, Modify, Padding, Style,
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    settings::{
+        location::ByColumnName,
+        object::{Columns, Rows, Segment},
+        Alignment, AutoAlign, Modify, Padding, Style,
+    },
 };
 
 use crate::matrix::Matrix;
-use testing_table::test_table;
+use testing_table::{static_table, test_table};
 
 test_table!(
     full_alignment,
@@ -136,3 +141,171 @@ test_table!(
         " 1 |   1-0    |   1-1    |   1-2    "
         " 2 |   2-0    |   2-1    |   2-2    "
 );
+
+#[test]
+fn alignment_last_nth_reused_on_growing_table() {
+    let mut builder = Builder::from_iter([["name", "value"], ["1", "2"]]);
+
+    let target = Columns::last_nth(1);
+
+    let table = builder
+        .clone()
+        .build()
+        .modify(target, Alignment::right())
+        .to_string();
+    assert_eq!(
+        table,
+        static_table!(
+            "+------+-------+"
+            "| name | value |"
+            "+------+-------+"
+            "|    1 | 2     |"
+            "+------+-------+"
+        )
+    );
+
+    builder.push_column(["comment", "3"]);
+    let table = builder
+        .build()
+        .modify(target, Alignment::right())
+        .to_string();
+    assert_eq!(
+        table,
+        static_table!(
+            "+------+-------+---------+"
+            "| name | value | comment |"
+            "+------+-------+---------+"
+            "| 1    |     2 | 3       |"
+            "+------+-------+---------+"
+        )
+    );
+}
+
+test_table!(
+    auto_align_numbers,
+    Builder::from_iter([
+        ["name", "int", "float", "percent", "money", "text"],
+        ["a", "1", "-0.50", "12%", "$1,000", "1"],
+        ["bb", "", "1e3", "7.5%", "€3_500", "x"],
+        ["ccc", "300", ".25", "100%", "-$2", "2"],
+    ])
+    .build()
+    .with(AutoAlign::numbers()),
+    "+------+-----+-------+---------+--------+------+"
+    "| name | int | float | percent |  money | text |"
+    "+------+-----+-------+---------+--------+------+"
+    "| a    |   1 | -0.50 |     12% | $1,000 | 1    |"
+    "+------+-----+-------+---------+--------+------+"
+    "| bb   |     |   1e3 |    7.5% | €3_500 | x    |"
+    "+------+-----+-------+---------+--------+------+"
+    "| ccc  | 300 |   .25 |    100% |    -$2 | 2    |"
+    "+------+-----+-------+---------+--------+------+"
+);
+
+test_table!(
+    auto_align_numbers_header_is_not_inspected,
+    Builder::from_iter([["header", "n"], ["1", "1"], ["22", "inf"]])
+        .build()
+        .with(AutoAlign::numbers()),
+    "+--------+-----+"
+    "| header | n   |"
+    "+--------+-----+"
+    "|      1 | 1   |"
+    "+--------+-----+"
+    "|     22 | inf |"
+    "+--------+-----+"
+);
+
+test_table!(
+    auto_align_numbers_overridden_by_column_alignment,
+    Builder::from_iter([["a", "b"], ["1", "2"], ["30", "40"]])
+        .build()
+        .with(AutoAlign::numbers())
+        .modify(Columns::single(1), Alignment::left()),
+    "+----+----+"
+    "|  a | b  |"
+    "+----+----+"
+    "|  1 | 2  |"
+    "+----+----+"
+    "| 30 | 40 |"
+    "+----+----+"
+);
+
+test_table!(
+    auto_align_numbers_below_threshold,
+    Builder::from_iter([["n"], ["1"], ["2"], ["3"], ["n/a"]])
+        .build()
+        .with(AutoAlign::numbers().threshold(0.8)),
+    "+-----+"
+    "| n   |"
+    "+-----+"
+    "| 1   |"
+    "+-----+"
+    "| 2   |"
+    "+-----+"
+    "| 3   |"
+    "+-----+"
+    "| n/a |"
+    "+-----+"
+);
+
+test_table!(
+    auto_align_numbers_above_threshold,
+    Builder::from_iter([["n"], ["1"], ["2"], ["3"], ["n/a"]])
+        .build()
+        .with(AutoAlign::numbers().threshold(0.7)),
+    "+-----+"
+    "|   n |"
+    "+-----+"
+    "|   1 |"
+    "+-----+"
+    "|   2 |"
+    "+-----+"
+    "|   3 |"
+    "+-----+"
+    "| n/a |"
+    "+-----+"
+);
+
+test_table!(
+    center_headers,
+    Builder::from_iter([["id", "name"], ["1000", "Alexander"], ["2", "Bo"]])
+        .build()
+        .with(Style::psql())
+        .with(Alignment::center_headers()),
+    "  id  |   name    "
+    "------+-----------"
+    " 1000 | Alexander "
+    " 2    | Bo        "
+);
+
+test_table!(
+    center_headers_overridden_by_column,
+    Builder::from_iter([["id", "name"], ["1000", "Alexander"], ["2", "Bo"]])
+        .build()
+        .with(Alignment::center_headers())
+        .with(Modify::new(Columns::first()).with(Alignment::right())),
+    "+------+-----------+"
+    "|   id |   name    |"
+    "+------+-----------+"
+    "| 1000 | Alexander |"
+    "+------+-----------+"
+    "|    2 | Bo        |"
+    "+------+-----------+"
+);
+
+test_table!(
+    center_headers_override_previous_alignment,
+    Builder::from_iter([["id", "name"], ["1000", "Alexander"], ["2", "Bo"]])
+        .build()
+        .with(Modify::new(Rows::first()).with(Alignment::right()))
+        .with(Modify::new(Rows::new(1..)).with(Alignment::right()))
+        .with(Alignment::center_headers()),
+    "+------+-----------+"
+    "|  id  |   name    |"
+    "+------+-----------+"
+    "| 1000 | Alexander |"
+    "+------+-----------+"
+    "| 2    | Bo        |"
+    "+------+-----------+"
+);
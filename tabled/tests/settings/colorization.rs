@@ -1,9 +1,15 @@
 #![cfg(feature = "std")]
 
-use tabled::settings::{
-    object::{Cell, Object},
-    themes::Colorization,
-    Color,
+use std::iter::FromIterator;
+
+use tabled::{
+    settings::{
+        format::Format,
+        object::{Cell, Columns, Object},
+        themes::{ColorDepth, Colorization, Heatmap, Rgb, RowStyle, Scale},
+        Color, Style,
+    },
+    Table,
 };
 
 use crate::matrix::Matrix;
@@ -51,6 +57,80 @@ test_table!(
     "+---+----------+----------+----------+\n|\u{1b}[41m \u{1b}[49m\u{1b}[41mN\u{1b}[49m\u{1b}[41m \u{1b}[49m| column 0 | column 1 | column 2 |\n+---+----------+----------+----------+\n| 0 |\u{1b}[106m \u{1b}[49m\u{1b}[106m  \u{1b}[49m\u{1b}[106m0-0\u{1b}[49m\u{1b}[106m   \u{1b}[49m\u{1b}[106m \u{1b}[49m|   0-1    |   0-2    |\n+---+----------+----------+----------+\n| 1 |   1-0    |\u{1b}[1m \u{1b}[22m\u{1b}[1m  \u{1b}[22m\u{1b}[1m1-1\u{1b}[22m\u{1b}[1m   \u{1b}[22m\u{1b}[1m \u{1b}[22m|   1-2    |\n+---+----------+----------+----------+"
 );
 
+test_table!(
+    row_style_multiple_matches,
+    status_table().with(RowStyle::when(state_color)),
+    " name    state   "
+    " build   OK      "
+    "\u{1b}[31m \u{1b}[39m\u{1b}[31mtest\u{1b}[39m\u{1b}[31m \u{1b}[39m\u{1b}[31m \u{1b}[39m \u{1b}[31m \u{1b}[39m\u{1b}[31mFAILED\u{1b}[39m\u{1b}[31m \u{1b}[39m\u{1b}[31m \u{1b}[39m"
+    "\u{1b}[90m \u{1b}[39m\u{1b}[90mlint\u{1b}[39m\u{1b}[90m \u{1b}[39m\u{1b}[90m \u{1b}[39m \u{1b}[90m \u{1b}[39m\u{1b}[90mSKIPPED\u{1b}[39m\u{1b}[90m \u{1b}[39m"
+);
+
+test_table!(
+    row_style_no_matches,
+    status_table().with(RowStyle::when(|_: &[&str]| None)),
+    " name    state   "
+    " build   OK      "
+    " test    FAILED  "
+    " lint    SKIPPED "
+);
+
+test_table!(
+    row_style_ignores_header,
+    status_table().with(RowStyle::when(|_: &[&str]| Some(Color::FG_RED))),
+    " name    state   "
+    "\u{1b}[31m \u{1b}[39m\u{1b}[31mbuild\u{1b}[39m\u{1b}[31m \u{1b}[39m \u{1b}[31m \u{1b}[39m\u{1b}[31mOK\u{1b}[39m\u{1b}[31m     \u{1b}[39m\u{1b}[31m \u{1b}[39m"
+    "\u{1b}[31m \u{1b}[39m\u{1b}[31mtest\u{1b}[39m\u{1b}[31m \u{1b}[39m\u{1b}[31m \u{1b}[39m \u{1b}[31m \u{1b}[39m\u{1b}[31mFAILED\u{1b}[39m\u{1b}[31m \u{1b}[39m\u{1b}[31m \u{1b}[39m"
+    "\u{1b}[31m \u{1b}[39m\u{1b}[31mlint\u{1b}[39m\u{1b}[31m \u{1b}[39m\u{1b}[31m \u{1b}[39m \u{1b}[31m \u{1b}[39m\u{1b}[31mSKIPPED\u{1b}[39m\u{1b}[31m \u{1b}[39m"
+);
+
+test_table!(
+    row_style_overrides_rows_colorization,
+    status_table()
+        .with(Colorization::rows([Color::BG_WHITE, Color::BG_BLACK]))
+        .with(RowStyle::when(state_color)),
+    "\u{1b}[47m \u{1b}[49m\u{1b}[47mname\u{1b}[49m\u{1b}[47m \u{1b}[49m\u{1b}[47m \u{1b}[49m \u{1b}[47m \u{1b}[49m\u{1b}[47mstate\u{1b}[49m\u{1b}[47m  \u{1b}[49m\u{1b}[47m \u{1b}[49m"
+    "\u{1b}[40m \u{1b}[49m\u{1b}[40mbuild\u{1b}[49m\u{1b}[40m \u{1b}[49m \u{1b}[40m \u{1b}[49m\u{1b}[40mOK\u{1b}[49m\u{1b}[40m     \u{1b}[49m\u{1b}[40m \u{1b}[49m"
+    "\u{1b}[31m \u{1b}[39m\u{1b}[31mtest\u{1b}[39m\u{1b}[31m \u{1b}[39m\u{1b}[31m \u{1b}[39m \u{1b}[31m \u{1b}[39m\u{1b}[31mFAILED\u{1b}[39m\u{1b}[31m \u{1b}[39m\u{1b}[31m \u{1b}[39m"
+    "\u{1b}[90m \u{1b}[39m\u{1b}[90mlint\u{1b}[39m\u{1b}[90m \u{1b}[39m\u{1b}[90m \u{1b}[39m \u{1b}[90m \u{1b}[39m\u{1b}[90mSKIPPED\u{1b}[39m\u{1b}[90m \u{1b}[39m"
+);
+
+test_table!(
+    row_style_sees_formatted_text,
+    status_table()
+        .modify(Columns::single(1), Format::content(|s| s.to_lowercase()))
+        .with(RowStyle::when(|cells: &[&str]| {
+            if cells[1] == "ok" {
+                Some(Color::FG_GREEN)
+            } else {
+                None
+            }
+        })),
+    " name    state   "
+    "\u{1b}[32m \u{1b}[39m\u{1b}[32mbuild\u{1b}[39m\u{1b}[32m \u{1b}[39m \u{1b}[32m \u{1b}[39m\u{1b}[32mok\u{1b}[39m\u{1b}[32m     \u{1b}[39m\u{1b}[32m \u{1b}[39m"
+    " test    failed  "
+    " lint    skipped "
+);
+
+fn status_table() -> Table {
+    let mut table = Table::from_iter([
+        ["name", "state"],
+        ["build", "OK"],
+        ["test", "FAILED"],
+        ["lint", "SKIPPED"],
+    ]);
+    table.with(Style::blank().remove_horizontals());
+    table
+}
+
+fn state_color(cells: &[&str]) -> Option<Color> {
+    match cells[1] {
+        "FAILED" => Some(Color::FG_RED),
+        "SKIPPED" => Some(Color::FG_BRIGHT_BLACK),
+        _ => None,
+    }
+}
+
 fn color1() -> Color {
     Color::BG_RED
 }
@@ -62,3 +142,75 @@ fn color2() -> Color {
 fn color3() -> Color {
     Color::BOLD
 }
+
+fn heat_table() -> Table {
+    Table::from_iter([["load"], ["0"], ["10"], ["n/a"], ["5"]])
+}
+
+test_table!(
+    heatmap,
+    heat_table().with(Heatmap::column(0)),
+    "+------+"
+    "| load |"
+    "+------+"
+    "|\u{1b}[48;2;0;180;0m \u{1b}[49m\u{1b}[48;2;0;180;0m0\u{1b}[49m\u{1b}[48;2;0;180;0m   \u{1b}[49m\u{1b}[48;2;0;180;0m \u{1b}[49m|"
+    "+------+"
+    "|\u{1b}[48;2;200;0;0m \u{1b}[49m\u{1b}[48;2;200;0;0m10\u{1b}[49m\u{1b}[48;2;200;0;0m  \u{1b}[49m\u{1b}[48;2;200;0;0m \u{1b}[49m|"
+    "+------+"
+    "| n/a  |"
+    "+------+"
+    "|\u{1b}[48;2;100;90;0m \u{1b}[49m\u{1b}[48;2;100;90;0m5\u{1b}[49m\u{1b}[48;2;100;90;0m   \u{1b}[49m\u{1b}[48;2;100;90;0m \u{1b}[49m|"
+    "+------+"
+);
+
+#[test]
+fn heatmap_truecolor() {
+    let table = heat_table()
+        .with(Heatmap::column(0).colors(Rgb(0, 180, 0), Rgb(200, 0, 0)))
+        .to_string();
+    let lines = table.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines[1], "| load |");
+    assert!(lines[3].contains("\u{1b}[48;2;0;180;0m0\u{1b}[49m"));
+    assert!(lines[5].contains("\u{1b}[48;2;200;0;0m10\u{1b}[49m"));
+    assert_eq!(lines[7], "| n/a  |");
+    assert!(lines[9].contains("\u{1b}[48;2;100;90;0m5\u{1b}[49m"));
+}
+
+#[test]
+fn heatmap_256_colors_foreground() {
+    let table = heat_table()
+        .with(Heatmap::column(0).depth(ColorDepth::Ansi256).foreground())
+        .to_string();
+    let lines = table.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines[3], "| \u{1b}[38;5;34m0\u{1b}[39m    |");
+    assert_eq!(lines[5], "| \u{1b}[38;5;160m10\u{1b}[39m   |");
+    assert_eq!(lines[7], "| n/a  |");
+    assert_eq!(lines[9], "| \u{1b}[38;5;58m5\u{1b}[39m    |");
+}
+
+#[test]
+fn heatmap_log_scale_and_bounds() {
+    let table = Table::from_iter([["size"], ["1"], ["10"], ["100"], ["-1"]])
+        .with(Heatmap::column(0).scale(Scale::log()).foreground())
+        .to_string();
+    let lines = table.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines[3], "| \u{1b}[38;2;0;180;0m1\u{1b}[39m    |");
+    assert_eq!(lines[5], "| \u{1b}[38;2;100;90;0m10\u{1b}[39m   |");
+    assert_eq!(lines[7], "| \u{1b}[38;2;200;0;0m100\u{1b}[39m  |");
+    assert_eq!(lines[9], "| -1   |");
+
+    let table = heat_table()
+        .with(Heatmap::column(0).min(-10.0).max(6.0).foreground())
+        .to_string();
+    let lines = table.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines[3], "| \u{1b}[38;2;125;68;0m0\u{1b}[39m    |");
+    assert_eq!(lines[5], "| \u{1b}[38;2;200;0;0m10\u{1b}[39m   |");
+    assert_eq!(lines[9], "| \u{1b}[38;2;188;11;0m5\u{1b}[39m    |");
+
+    let table = heat_table().with(Heatmap::column(1)).to_string();
+    assert_eq!(table, heat_table().to_string());
+}
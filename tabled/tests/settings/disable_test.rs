@@ -81,3 +81,47 @@ test_table!(
         .with(Disable::column(Columns::new(..))),
     ""
 );
+
+test_table!(
+    disable_last_nth_column,
+    Matrix::new(2, 2)
+        .with(Style::psql())
+        .with(Disable::column(Columns::last_nth(1))),
+    " N | column 1 "
+    "---+----------"
+    " 0 |   0-1    "
+    " 1 |   1-1    "
+);
+
+test_table!(
+    disable_last_nth_column_exceeds_boundaries,
+    Matrix::new(2, 1)
+        .with(Style::psql())
+        .with(Disable::column(Columns::last_nth(3))),
+    " N | column 0 "
+    "---+----------"
+    " 0 |   0-0    "
+    " 1 |   1-0    "
+);
+
+test_table!(
+    disable_rows_except_last,
+    Matrix::new(4, 2)
+        .with(Style::psql())
+        .with(Disable::row(Rows::new(1..).except_last(2))),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 2 |   2-0    |   2-1    "
+    " 3 |   3-0    |   3-1    "
+);
+
+test_table!(
+    disable_last_nth_row,
+    Matrix::new(3, 2)
+        .with(Style::psql())
+        .with(Disable::row(Rows::last_nth(1))),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    |   0-1    "
+    " 2 |   2-0    |   2-1    "
+);
@@ -1,6 +1,7 @@
 #![cfg(feature = "std")]
 
 use tabled::settings::{
+    location::ByContent,
     object::{Cell, Columns, Object, Rows, Segment},
     Alignment, Format, Modify, Padding, Style,
 };
@@ -267,3 +268,55 @@ test_table!(
     "   |          |          | \u{1b}[32m.com\u{1b}[39m     "
     "   |          |          | \u{1b}[32m/en\u{1b}[39m      "
 );
+
+test_table!(
+    format_then_modify_by_content,
+    Matrix::new(3, 3)
+        .with(Modify::new(Rows::new(1..)).with(Format::content(|s| format!("<{s}>"))))
+        .with(Modify::new(ByContent::starts_with("<1-")).with("#"))
+        .with(Modify::new(ByContent::row_where(|text| text == "<2-2>")).with("*")),
+    "+-----+----------+----------+----------+"
+    "|  N  | column 0 | column 1 | column 2 |"
+    "+-----+----------+----------+----------+"
+    "| <0> |  <0-0>   |  <0-1>   |  <0-2>   |"
+    "+-----+----------+----------+----------+"
+    "| <1> |    #     |    #     |    #     |"
+    "+-----+----------+----------+----------+"
+    "|  *  |    *     |    *     |    *     |"
+    "+-----+----------+----------+----------+"
+);
+
+#[test]
+fn format_then_is_equal_to_sequential_formatting() {
+    let trim = |s: &str| s.trim().to_owned();
+    let upper = |s: &str| s.to_uppercase();
+    let wrap = |s: &str| format!("<{s}>");
+    let dash = |s: &str| s.replace('-', "\n");
+
+    let mut sequential = Matrix::table(3, 3);
+    sequential
+        .with(Modify::new(Segment::all()).with(Format::content(trim)))
+        .with(Modify::new(Segment::all()).with(Format::content(dash)))
+        .with(Modify::new(Segment::all()).with(Format::content(wrap)))
+        .with(Modify::new(Segment::all()).with(Format::content(upper)));
+
+    let mut composed = Matrix::table(3, 3);
+    composed.with(
+        Modify::new(Segment::all()).with(Format::content(trim).then(dash).then(wrap).then(upper)),
+    );
+
+    assert_eq!(sequential.to_string(), composed.to_string());
+
+    let mut sequential = Matrix::table(3, 3);
+    sequential
+        .with(Modify::new(Rows::new(1..)).with(Format::content(dash)))
+        .with(Modify::new(Rows::new(1..)).with(Format::content(wrap).multiline()))
+        .with(Modify::new(Rows::new(1..)).with(Format::content(wrap)));
+
+    let mut composed = Matrix::table(3, 3);
+    composed
+        .with(Modify::new(Rows::new(1..)).with(Format::content(dash)))
+        .with(Modify::new(Rows::new(1..)).with(Format::content(wrap).multiline().then(wrap)));
+
+    assert_eq!(sequential.to_string(), composed.to_string());
+}
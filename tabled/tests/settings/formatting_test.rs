@@ -1,6 +1,19 @@
 #![cfg(feature = "std")]
 
-use tabled::settings::{formatting::Justification, object::Columns, Color, Modify};
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    settings::{
+        formatting::{
+            Charset, Justification, KeepTrailingNewline, MultilineAlignment, TrimLines, VAlignment,
+        },
+        object::{Cell, Columns, Rows},
+        style::{BorderSpanCorrection, HorizontalLine, LineText},
+        Border, Color, Height, Modify, Padding, Panel, Style,
+    },
+    Table,
+};
 
 use crate::matrix::Matrix;
 use testing_table::test_table;
@@ -66,3 +79,371 @@ test_table!(
     "| 2 | \u{1b}[44m##\u{1b}[49m2-0\u{1b}[44m###\u{1b}[49m | \u{1b}[41m@@\u{1b}[49m2-1\u{1b}[41m@@@\u{1b}[49m | \u{1b}[47m$$\u{1b}[49m2-2\u{1b}[47m$$$\u{1b}[49m |"
     "+---+----------+----------+----------+"
 );
+
+test_table!(
+    invisible_chars_width,
+    Builder::from_iter([["\u{FEFF}bom", "a\u{200B}b"], ["abc", "soft\u{00AD}"], ["👨\u{200D}👩\u{200D}👧", "ab"]]).build(),
+    "+-----+------+"
+    "| \u{FEFF}bom | a\u{200B}b   |"
+    "+-----+------+"
+    "| abc | soft\u{00AD} |"
+    "+-----+------+"
+    "| 👨\u{200D}👩\u{200D}👧  | ab   |"
+    "+-----+------+"
+);
+
+test_table!(
+    charset_strip_invisible,
+    Builder::from_iter([["\u{FEFF}bom", "a\u{200B}b"], ["abc", "soft\u{00AD}"], ["👨\u{200D}👩\u{200D}👧", "a\u{200D}b"]])
+        .build()
+        .with(Charset::strip_invisible()),
+    "+-----+------+"
+    "| bom | ab   |"
+    "+-----+------+"
+    "| abc | soft |"
+    "+-----+------+"
+    "| 👨\u{200D}👩\u{200D}👧  | ab   |"
+    "+-----+------+"
+);
+
+test_table!(
+    charset_strip_invisible_column,
+    Builder::from_iter([["\u{FEFF}bom", "\u{FEFF}bom"]])
+        .build()
+        .with(Modify::new(Columns::single(1)).with(Charset::strip_invisible())),
+    "+-----+-----+"
+    "| \u{FEFF}bom | bom |"
+    "+-----+-----+"
+);
+
+test_table!(
+    charset_ascii_rounded,
+    Matrix::new(2, 2).with(Style::rounded()).with(Charset::ascii()),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    charset_ascii_extended,
+    Matrix::new(2, 2).with(Style::extended()).with(Charset::ascii()),
+    "+===+==========+==========+"
+    "| N | column 0 | column 1 |"
+    "+===+==========+==========+"
+    "| 0 |   0-0    |   0-1    |"
+    "+===+==========+==========+"
+    "| 1 |   1-0    |   1-1    |"
+    "+===+==========+==========+"
+);
+
+test_table!(
+    charset_ascii_custom_lines_and_cells,
+    Matrix::new(2, 2)
+        .with(Style::modern().horizontals([(1, HorizontalLine::full('━', '╋', '┣', '┫'))]))
+        .modify(Cell::new(1, 1), Border::new().set_top('┅').set_left('┇').set_corner_top_left('╬'))
+        .with(Charset::ascii()),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    charset_ascii_spans,
+    Matrix::new(2, 2)
+        .with(Panel::header("panel"))
+        .with(Style::heavy_light())
+        .with(BorderSpanCorrection)
+        .with(Charset::ascii()),
+    "+-------------------------+"
+    "|          panel          |"
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    charset_ascii_content,
+    Table::new(["Café", "日本"])
+        .with(Style::rounded())
+        .with(Charset::ascii().content(|c| if c == 'é' { Some('e') } else { Some('?') })),
+    "+------+"
+    "| &str |"
+    "+------+"
+    "| Cafe |"
+    "| ??   |"
+    "+------+"
+);
+
+#[test]
+fn charset_ascii_output_is_ascii() {
+    let table = Table::new(["Café — ok", "naïve"])
+        .with(Style::rounded())
+        .with(LineText::new("━label━", Rows::first()).offset(1))
+        .with(Charset::ascii().content(|_| None))
+        .to_string();
+
+    assert!(table.is_ascii(), "{}", table);
+}
+
+test_table!(
+    multiline_alignment_top,
+    Table::new([["Multi\nline\ntext", "a", "b\nc"]]).with(MultilineAlignment(VAlignment::Top)),
+    "+-------+---+---+"
+    "| 0     | 1 | 2 |"
+    "+-------+---+---+"
+    "| Multi | a | b |"
+    "| line  |   | c |"
+    "| text  |   |   |"
+    "+-------+---+---+"
+);
+
+test_table!(
+    multiline_alignment_middle,
+    Table::new([["Multi\nline\ntext", "a", "b\nc"]]).with(MultilineAlignment(VAlignment::Middle)),
+    "+-------+---+---+"
+    "| 0     | 1 | 2 |"
+    "+-------+---+---+"
+    "| Multi |   | b |"
+    "| line  | a | c |"
+    "| text  |   |   |"
+    "+-------+---+---+"
+);
+
+test_table!(
+    multiline_alignment_bottom,
+    Table::new([["Multi\nline\ntext", "a", "b\nc"]]).with(MultilineAlignment(VAlignment::Bottom)),
+    "+-------+---+---+"
+    "| 0     | 1 | 2 |"
+    "+-------+---+---+"
+    "| Multi |   |   |"
+    "| line  |   | b |"
+    "| text  | a | c |"
+    "+-------+---+---+"
+);
+
+test_table!(
+    multiline_alignment_with_row_height,
+    Table::new([["Multi\nline", "a"]])
+        .with(Height::list([3, 4]))
+        .with(MultilineAlignment(VAlignment::Middle)),
+    "+-------+---+"
+    "|       |   |"
+    "| 0     | 1 |"
+    "|       |   |"
+    "+-------+---+"
+    "|       |   |"
+    "| Multi | a |"
+    "| line  |   |"
+    "|       |   |"
+    "+-------+---+"
+);
+
+test_table!(
+    multiline_alignment_column,
+    Table::new([["Multi\nline\ntext", "a", "b"]])
+        .with(Modify::new(Columns::single(1)).with(MultilineAlignment(VAlignment::Bottom))),
+    "+-------+---+---+"
+    "| 0     | 1 | 2 |"
+    "+-------+---+---+"
+    "| Multi |   | b |"
+    "| line  |   |   |"
+    "| text  | a |   |"
+    "+-------+---+---+"
+);
+
+test_table!(
+    trim_lines_top,
+    Builder::from_iter([["message", "level"], ["\n\n\nconnection lost", "error"]])
+        .build()
+        .with(TrimLines::top()),
+    "+-----------------+-------+"
+    "| message         | level |"
+    "+-----------------+-------+"
+    "| connection lost | error |"
+    "+-----------------+-------+"
+);
+
+test_table!(
+    trim_lines_bottom,
+    Builder::from_iter([["message", "level"], ["\n\n\nconnection lost\n \n", "error"]])
+        .build()
+        .with(TrimLines::bottom()),
+    "+-----------------+-------+"
+    "| message         | level |"
+    "+-----------------+-------+"
+    "|                 | error |"
+    "|                 |       |"
+    "|                 |       |"
+    "| connection lost |       |"
+    "+-----------------+-------+"
+);
+
+test_table!(
+    trim_lines_collapse,
+    Builder::from_iter([["message", "level"], ["\n\n\nconnection\n\n\nlost", "error"]])
+        .build()
+        .with(TrimLines::collapse()),
+    "+------------+-------+"
+    "| message    | level |"
+    "+------------+-------+"
+    "|            | error |"
+    "| connection |       |"
+    "|            |       |"
+    "| lost       |       |"
+    "+------------+-------+"
+);
+
+test_table!(
+    trim_lines_column,
+    Builder::from_iter([["message", "level"], ["\n\n\nconnection lost", "\n\n\nerror"]])
+        .build()
+        .with(Modify::new(Columns::first()).with(TrimLines::top())),
+    "+-----------------+-------+"
+    "| message         | level |"
+    "+-----------------+-------+"
+    "| connection lost |       |"
+    "|                 |       |"
+    "|                 |       |"
+    "|                 | error |"
+    "+-----------------+-------+"
+);
+
+test_table!(
+    trim_lines_padding_is_applied_after,
+    Builder::from_iter([["message", "level"], ["\n\n\nconnection lost", "error"]])
+        .build()
+        .with(Padding::new(1, 1, 1, 1))
+        .with(TrimLines::top()),
+    "+-----------------+-------+"
+    "|                 |       |"
+    "| message         | level |"
+    "|                 |       |"
+    "+-----------------+-------+"
+    "|                 |       |"
+    "| connection lost | error |"
+    "|                 |       |"
+    "+-----------------+-------+"
+);
+
+test_table!(
+    trim_lines_blank_cell,
+    Builder::from_iter([["message", "level"], ["\n \n\n", "error"]])
+        .build()
+        .with(TrimLines::top()),
+    "+---------+-------+"
+    "| message | level |"
+    "+---------+-------+"
+    "|         | error |"
+    "+---------+-------+"
+);
+
+test_table!(
+    trailing_newline_is_not_a_line,
+    Builder::from_iter([["message", "level"], ["a\n", "error"]])
+        .build(),
+    "+---------+-------+"
+    "| message | level |"
+    "+---------+-------+"
+    "| a       | error |"
+    "+---------+-------+"
+);
+
+test_table!(
+    trailing_newline_only,
+    Builder::from_iter([["message", "level"], ["\n", "error"]])
+        .build(),
+    "+---------+-------+"
+    "| message | level |"
+    "+---------+-------+"
+    "|         | error |"
+    "+---------+-------+"
+);
+
+test_table!(
+    trailing_newline_double,
+    Builder::from_iter([["message", "level"], ["\n\n", "error"]])
+        .build(),
+    "+---------+-------+"
+    "| message | level |"
+    "+---------+-------+"
+    "|         | error |"
+    "|         |       |"
+    "+---------+-------+"
+);
+
+test_table!(
+    trailing_newline_kept,
+    Builder::from_iter([["message", "level"], ["a\n", "error"]])
+        .build()
+        .with(KeepTrailingNewline),
+    "+---------+-------+"
+    "| message | level |"
+    "+---------+-------+"
+    "| a       | error |"
+    "|         |       |"
+    "+---------+-------+"
+);
+
+test_table!(
+    trailing_newline_kept_only,
+    Builder::from_iter([["message", "level"], ["\n", "error"]])
+        .build()
+        .with(KeepTrailingNewline),
+    "+---------+-------+"
+    "| message | level |"
+    "+---------+-------+"
+    "|         | error |"
+    "|         |       |"
+    "+---------+-------+"
+);
+
+test_table!(
+    trailing_newline_kept_double,
+    Builder::from_iter([["message", "level"], ["\n\n", "error"]])
+        .build()
+        .with(KeepTrailingNewline),
+    "+---------+-------+"
+    "| message | level |"
+    "+---------+-------+"
+    "|         | error |"
+    "|         |       |"
+    "|         |       |"
+    "+---------+-------+"
+);
+
+test_table!(
+    trailing_newline_height_increase,
+    Builder::from_iter([["message", "level"], ["a\n", "error"]])
+        .build()
+        .with(Height::increase(6)),
+    "+---------+-------+"
+    "| message | level |"
+    "|         |       |"
+    "+---------+-------+"
+    "| a       | error |"
+    "+---------+-------+"
+);
+
+test_table!(
+    trailing_newline_height_limit,
+    Builder::from_iter([["message", "level"], ["a\n\nb\n", "error"]])
+        .build()
+        .with(Height::limit(6)),
+    "+---------+-------+"
+    "| message | level |"
+    "+---------+-------+"
+    "| a       | error |"
+    "|         |       |"
+    "+---------+-------+"
+);
@@ -1,8 +1,11 @@
 #![cfg(feature = "std")]
 
-use tabled::settings::{
-    object::{Columns, Segment},
-    Alignment, Format, Height, Modify, Style,
+use tabled::{
+    settings::{
+        object::{Columns, Segment},
+        Alignment, Format, Height, Modify, Padding, Style,
+    },
+    Table,
 };
 
 use crate::matrix::Matrix;
@@ -114,6 +117,7 @@ test_table!(
         .with(Height::limit(10)),
     "| xxxx  | column 0 | column 1 | column 2 |"
     "| Nxxxx |          |          |          |"
+    "| xxxx  |          |          |          |"
     "|-------|----------|----------|----------|"
     "| xxxx  |   0-0    |   0-1    |   0-2    |"
     "| 0xxxx |          |          |          |"
@@ -121,7 +125,6 @@ test_table!(
     "| 1xxxx |          |          |          |"
     "| xxxx  |   2-0    |   2-1    |   2-2    |"
     "| 2xxxx |          |          |          |"
-    "| xxxx  |          |          |          |"
 );
 
 test_table!(
@@ -132,12 +135,12 @@ test_table!(
         .with(Modify::new(Columns::first()).with(Alignment::center_vertical()))
         .with(Height::limit(7)),
     "| xxxx  | column 0 | column 1 | column 2 |"
+    "| Nxxxx |          |          |          |"
     "|-------|----------|----------|----------|"
     "| xxxx  |   0-0    |   0-1    |   0-2    |"
+    "| 0xxxx |          |          |          |"
     "| xxxx  |   1-0    |   1-1    |   1-2    |"
-    "| 1xxxx |          |          |          |"
     "| xxxx  |   2-0    |   2-1    |   2-2    |"
-    "| 2xxxx |          |          |          |"
 );
 
 test_table!(
@@ -182,20 +185,16 @@ test_table!(
         "| xxxx | xxxx | xxxx | xxxx |"
         "| xxxx | xxxx | xxxx | xxxx |"
         "| xxxx | xxxx | xxxx | xxxx |"
-        "|      |      |      |      |"
         "|------|------|------|------|"
         "| xxxx | xxxx | xxxx | xxxx |"
         "| xxxx | xxxx | xxxx | xxxx |"
         "| xxxx | xxxx | xxxx | xxxx |"
-        "|      |      |      |      |"
         "| xxxx | xxxx | xxxx | xxxx |"
         "| xxxx | xxxx | xxxx | xxxx |"
         "| xxxx | xxxx | xxxx | xxxx |"
-        "|      |      |      |      |"
         "| xxxx | xxxx | xxxx | xxxx |"
         "| xxxx | xxxx | xxxx | xxxx |"
         "| xxxx | xxxx | xxxx | xxxx |"
-        "|      |      |      |      |"
 );
 
 #[cfg(feature = "ansi")]
@@ -228,6 +227,7 @@ test_table!(
         .with(Height::limit(10)),
         "| \u{1b}[34;42mxxxx\u{1b}[39m\u{1b}[49m  | column 0 | column 1 | column 2 |"
         "| \u{1b}[34m\u{1b}[42mNxxxx\u{1b}[39m\u{1b}[49m |          |          |          |"
+        "| \u{1b}[34m\u{1b}[42mxxxx\u{1b}[39m\u{1b}[49m  |          |          |          |"
         "|-------|----------|----------|----------|"
         "| \u{1b}[34;42mxxxx\u{1b}[39m\u{1b}[49m  |   0-0    |   0-1    |   0-2    |"
         "| \u{1b}[34m\u{1b}[42m0xxxx\u{1b}[39m\u{1b}[49m |          |          |          |"
@@ -235,7 +235,6 @@ test_table!(
         "| \u{1b}[34m\u{1b}[42m1xxxx\u{1b}[39m\u{1b}[49m |          |          |          |"
         "| \u{1b}[34;42mxxxx\u{1b}[39m\u{1b}[49m  |   2-0    |   2-1    |   2-2    |"
         "| \u{1b}[34m\u{1b}[42m2xxxx\u{1b}[39m\u{1b}[49m |          |          |          |"
-        "| \u{1b}[34m\u{1b}[42mxxxx\u{1b}[39m\u{1b}[49m  |          |          |          |"
 );
 
 #[cfg(feature = "macros")]
@@ -261,3 +260,104 @@ test_table!(
     "| +------+ |"
     "+----------+"
 );
+
+#[test]
+fn table_height_fits_exact_total() {
+    let data = [["a\nb\nc", "1"], ["x", "2\n3"], ["y", "z"]];
+
+    // natural height is 12 lines
+    for height in [6, 8, 9, 12, 15, 20] {
+        let table = Table::new(data).with(Height::increase(height)).to_string();
+        assert_eq!(table.lines().count(), height.max(12), "increase {height}");
+
+        let table = Table::new(data).with(Height::limit(height)).to_string();
+        assert_eq!(table.lines().count(), height.min(12), "limit {height}");
+
+        let table = Table::new(data)
+            .with(Padding::new(1, 1, 1, 1))
+            .with(Height::limit(height))
+            .to_string();
+        assert_eq!(
+            table.lines().count(),
+            height.min(20),
+            "padded limit {height}"
+        );
+    }
+}
+
+test_table!(
+    table_height_limit_shrinks_tallest_rows_first,
+    Table::new([["a\nb\nc", "1"], ["x", "2\n3"], ["y", "z"]]).with(Height::limit(10)),
+    "+---+---+"
+    "| 0 | 1 |"
+    "+---+---+"
+    "| a | 1 |"
+    "| b |   |"
+    "+---+---+"
+    "| x | 2 |"
+    "+---+---+"
+    "| y | z |"
+    "+---+---+"
+);
+
+test_table!(
+    table_height_limit_with_padding,
+    Table::new([["a\nb\nc", "1"], ["x", "2\n3"], ["y", "z"]])
+        .with(Padding::new(1, 1, 1, 1))
+        .with(Height::limit(16)),
+    "+---+---+"
+    "|   |   |"
+    "| 0 | 1 |"
+    "|   |   |"
+    "+---+---+"
+    "|   |   |"
+    "| a | 1 |"
+    "|   |   |"
+    "+---+---+"
+    "|   |   |"
+    "| x | 2 |"
+    "|   |   |"
+    "+---+---+"
+    "|   |   |"
+    "| y | z |"
+    "+---+---+"
+);
+
+test_table!(
+    table_height_limit_cuts_padding_before_content,
+    Table::new([["a\nb\nc", "1"], ["x", "2\n3"], ["y", "z"]])
+        .with(Padding::new(1, 1, 1, 1))
+        .with(Height::limit(11)),
+    "+---+---+"
+    "|   |   |"
+    "| 0 | 1 |"
+    "+---+---+"
+    "|   |   |"
+    "| a | 1 |"
+    "+---+---+"
+    "| x | 2 |"
+    "+---+---+"
+    "| y | z |"
+    "+---+---+"
+);
+
+test_table!(
+    table_height_increase_exact_total,
+    Table::new([["a\nb\nc", "1"], ["x", "2\n3"], ["y", "z"]])
+        .with(Style::modern())
+        .with(Height::increase(14)),
+    "┌───┬───┐"
+    "│ 0 │ 1 │"
+    "│   │   │"
+    "├───┼───┤"
+    "│ a │ 1 │"
+    "│ b │   │"
+    "│ c │   │"
+    "│   │   │"
+    "├───┼───┤"
+    "│ x │ 2 │"
+    "│   │ 3 │"
+    "├───┼───┤"
+    "│ y │ z │"
+    "└───┴───┘"
+);
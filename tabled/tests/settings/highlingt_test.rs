@@ -417,3 +417,31 @@ fn highlingt_complex_figures() {
         ),
     );
 }
+
+test_table!(
+    highlight_last_nth_column_exceeds_boundaries,
+    Matrix::new(2, 1)
+        .with(Style::psql())
+        .with(Highlight::border(Columns::last_nth(3), Border::filled('+'))),
+    " N | column 0 "
+    "---+----------"
+    " 0 |   0-0    "
+    " 1 |   1-0    "
+);
+
+test_table!(
+    highlight_columns_except_last,
+    Matrix::new(2, 2)
+        .with(Style::psql())
+        .with(Highlight::border(
+            Columns::new(..).except_last(2),
+            Border::filled('+')
+        )),
+    "+++++                     "
+    "+ N + column 0 | column 1 "
+    "+---+----------+----------"
+    "+ 0 +   0-0    |   0-1    "
+    "+   +                     "
+    "+ 1 +   1-0    |   1-1    "
+    "+++++                     "
+);
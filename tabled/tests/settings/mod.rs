@@ -20,5 +20,6 @@ mod shadow_test;
 mod span_test;
 mod split_test;
 mod style_test;
+mod summary_test;
 mod theme_test;
 mod width_test;
@@ -2,15 +2,17 @@
 
 use std::{collections::HashMap, iter::FromIterator};
 
+use tabled::builder::Builder;
 use tabled::settings::{
     object::{Cell, Object, Rows, Segment},
+    panel::{Caption, CaptionPosition, ColumnGroups},
     style::{BorderSpanCorrection, HorizontalLine, Style},
     themes::Theme,
     Alignment, Border, Highlight, Modify, Panel, Span, Width,
 };
 
 use crate::matrix::Matrix;
-use testing_table::test_table;
+use testing_table::{static_table, test_table};
 
 test_table!(
     panel_has_no_style_by_default,
@@ -241,9 +243,9 @@ test_table!(
     Matrix::new(3, 3).with(Style::psql()).with(Panel::vertical(0,"Linux Distributions")).with(Modify::new(Cell::new(0, 0)).with(Width::wrap(3))),
     " Lin | N | column 0 | column 1 | column 2 "
     " ux  |   |          |          |          "
-    " Dis |   |          |          |          "
-    " tri +---+----------+----------+----------"
-    " but | 0 |   0-0    |   0-1    |   0-2    "
+    " Dis +---+----------+----------+----------"
+    " tri | 0 |   0-0    |   0-1    |   0-2    "
+    " but |   |          |          |          "
     " ion | 1 |   1-0    |   1-1    |   1-2    "
     " s   | 2 |   2-0    |   2-1    |   2-2    "
 );
@@ -354,9 +356,9 @@ test_table!(
     " n |   |          |          |          "
     " u |   |          |          |          "
     " x |   |          |          |          "
-    "   |   |          |          |          "
-    " D +---+----------+----------+----------"
-    " i | 0 |   0-0    |   0-1    |   0-2    "
+    "   +---+----------+----------+----------"
+    " D | 0 |   0-0    |   0-1    |   0-2    "
+    " i |   |          |          |          "
     " s |   |          |          |          "
     " t |   |          |          |          "
     " r |   |          |          |          "
@@ -369,3 +371,277 @@ test_table!(
     " n |   |          |          |          "
     " s |   |          |          |          "
 );
+
+fn system_stats() -> Builder {
+    Builder::from_iter([
+        ["host", "cpu", "rx", "tx", "drops", "read", "write"],
+        ["alpha", "12%", "1.2M", "0.3M", "0", "40M", "12M"],
+        ["beta", "89%", "9.1M", "7.7M", "3", "2M", "0M"],
+    ])
+}
+
+test_table!(
+    column_groups,
+    system_stats().build().with(ColumnGroups::new([("Network", 2..5), ("Disk", 5..7)])),
+    "+-------+-----+------+------+-------+------+-------+"
+    "|       |     |       Network       |     Disk     |"
+    "+-------+-----+------+------+-------+------+-------+"
+    "| host  | cpu | rx   | tx   | drops | read | write |"
+    "+-------+-----+------+------+-------+------+-------+"
+    "| alpha | 12% | 1.2M | 0.3M | 0     | 40M  | 12M   |"
+    "+-------+-----+------+------+-------+------+-------+"
+    "| beta  | 89% | 9.1M | 7.7M | 3     | 2M   | 0M    |"
+    "+-------+-----+------+------+-------+------+-------+"
+);
+
+test_table!(
+    column_groups_border_correction,
+    system_stats()
+        .build()
+        .with(Style::modern())
+        .with(ColumnGroups::new([("Network", 2..5), ("Disk", 5..7)]))
+        .with(BorderSpanCorrection),
+    "┌───────┬─────┬─────────────────────┬──────────────┐"
+    "│       │     │       Network       │     Disk     │"
+    "├───────┼─────┼──────┬──────┬───────┼──────┬───────┤"
+    "│ host  │ cpu │ rx   │ tx   │ drops │ read │ write │"
+    "├───────┼─────┼──────┼──────┼───────┼──────┼───────┤"
+    "│ alpha │ 12% │ 1.2M │ 0.3M │ 0     │ 40M  │ 12M   │"
+    "├───────┼─────┼──────┼──────┼───────┼──────┼───────┤"
+    "│ beta  │ 89% │ 9.1M │ 7.7M │ 3     │ 2M   │ 0M    │"
+    "└───────┴─────┴──────┴──────┴───────┴──────┴───────┘"
+);
+
+test_table!(
+    column_groups_with_index,
+    system_stats()
+        .index()
+        .build()
+        .with(ColumnGroups::new([("Network", 3..6), ("Disk", 6..8)])),
+    "+---+-------+-----+------+------+-------+------+-------+"
+    "|   |       |     |       Network       |     Disk     |"
+    "+---+-------+-----+------+------+-------+------+-------+"
+    "|   | host  | cpu | rx   | tx   | drops | read | write |"
+    "+---+-------+-----+------+------+-------+------+-------+"
+    "| 0 | alpha | 12% | 1.2M | 0.3M | 0     | 40M  | 12M   |"
+    "+---+-------+-----+------+------+-------+------+-------+"
+    "| 1 | beta  | 89% | 9.1M | 7.7M | 3     | 2M   | 0M    |"
+    "+---+-------+-----+------+------+-------+------+-------+"
+);
+
+#[test]
+fn column_groups_match_builder_column_groups() {
+    let mut builder = system_stats();
+    builder
+        .set_column_groups(vec![
+            (2..5, String::from("Network")),
+            (5..7, String::from("Disk")),
+        ])
+        .unwrap();
+
+    let table = system_stats()
+        .build()
+        .with(ColumnGroups::new([("Network", 2..5), ("Disk", 5..7)]))
+        .to_string();
+
+    assert_eq!(builder.build().to_string(), table);
+}
+
+test_table!(
+    column_groups_invalid_ranges_are_ignored,
+    Matrix::new(1, 3).with(ColumnGroups::new([("a", 1..3), ("b", 2..4), ("c", 2..2), ("d", 0..1)])),
+    "+---+----------+----------+----------+"
+    "| d |          a          |          |"
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    |   0-1    |   0-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    caption_above,
+    Matrix::new(2, 2).with(Caption::new("Table 1. Matrix")),
+    " Table 1. Matrix           "
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    caption_below,
+    Matrix::new(2, 2)
+        .with(Style::modern())
+        .with(Caption::new("Table 1. Matrix").position(CaptionPosition::Below)),
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │"
+    "├───┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │"
+    "└───┴──────────┴──────────┘"
+    " Table 1. Matrix           "
+);
+
+test_table!(
+    caption_alignment,
+    Matrix::new(2, 2)
+        .with(Style::rounded())
+        .with(Caption::new("Above").alignment(Alignment::center()))
+        .with(
+            Caption::new("Below")
+                .position(CaptionPosition::Below)
+                .alignment(Alignment::right()),
+        ),
+    "           Above           "
+    "╭───┬──────────┬──────────╮"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │"
+    "│ 1 │   1-0    │   1-1    │"
+    "╰───┴──────────┴──────────╯"
+    "                     Below "
+);
+
+test_table!(
+    caption_wrapped,
+    Matrix::new(1, 1).with(Style::modern()).with(
+        Caption::new("A caption which is longer than the table").alignment(Alignment::right())
+    ),
+    "      A caption "
+    "       which is "
+    "    longer than "
+    "      the table "
+    "┌───┬──────────┐"
+    "│ N │ column 0 │"
+    "├───┼──────────┤"
+    "│ 0 │   0-0    │"
+    "└───┴──────────┘"
+);
+
+test_table!(
+    caption_top_centered_over_narrow_table,
+    Builder::from_iter([["id"], ["1"]])
+        .build()
+        .with(Style::modern())
+        .with(Caption::top("IDs").alignment(Alignment::center()))
+        .with(Caption::bottom("n=1").alignment(Alignment::right())),
+    " IDs  "
+    "┌────┐"
+    "│ id │"
+    "├────┤"
+    "│ 1  │"
+    "└────┘"
+    "  n=1 "
+);
+
+test_table!(
+    caption_bottom_wrapped_under_wide_table,
+    Matrix::new(1, 3).with(Style::modern()).with(Caption::bottom(
+        "updated 2024-05-01 from the nightly export, rows with empty values are skipped"
+    )),
+    "┌───┬──────────┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │ column 2 │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │   0-2    │"
+    "└───┴──────────┴──────────┴──────────┘"
+    " updated 2024-05-01 from the nightly  "
+    " export, rows with empty values are   "
+    " skipped                              "
+);
+
+#[test]
+fn caption_keeps_column_widths() {
+    let table = Matrix::table(2, 2);
+    let widths = table.column_widths();
+
+    let mut captioned = table.clone();
+    captioned
+        .with(Caption::top(
+            "A caption which is much longer than the table itself",
+        ))
+        .with(Caption::bottom("n=2"));
+
+    assert_eq!(captioned.total_width(), table.total_width());
+    assert_eq!(captioned.column_widths(), widths);
+}
+
+#[test]
+fn caption_is_not_a_part_of_grid() {
+    let mut table = Matrix::table(1, 1);
+    table
+        .with(Caption::top("IDs"))
+        .with(Caption::bottom("n=1"))
+        .with(Style::modern());
+
+    assert_eq!(table.count_rows(), 2);
+    assert_eq!(table.total_height(), 7);
+    assert_eq!(
+        table.to_string_without_header(),
+        static_table!(
+            " IDs            "
+            "┌───┬──────────┐"
+            "│ 0 │   0-0    │"
+            "└───┴──────────┘"
+            " n=1            "
+        )
+    );
+
+    table.modify(Rows::first(), "N");
+    table.with(Width::increase(20));
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            " IDs                "
+            "┌────────┬─────────┐"
+            "│   N    │    N    │"
+            "├────────┼─────────┤"
+            "│   0    │   0-0   │"
+            "└────────┴─────────┘"
+            " n=1                "
+        )
+    );
+}
+
+test_table!(
+    caption_with_horizontal_line,
+    Matrix::new(2, 2)
+        .with(
+            Style::ascii()
+                .remove_horizontal()
+                .horizontals([(1, HorizontalLine::inherit(Style::ascii()))])
+        )
+        .with(Caption::new("Table 1. Matrix")),
+    " Table 1. Matrix           "
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    caption_without_frame,
+    Matrix::new(2, 2)
+        .with(Style::psql())
+        .with(Caption::new("Table 1. Matrix").position(CaptionPosition::Below)),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    |   0-1    "
+    " 1 |   1-0    |   1-1    "
+    "Table 1. Matrix          "
+);
+
+test_table!(
+    caption_empty_table,
+    Builder::default()
+        .build()
+        .with(Caption::new("Table 1. Matrix")),
+    ""
+);
@@ -25,7 +25,6 @@ test_table!(
     "           |          |          |          "
     "    asdasd |          |          |          "
     "           |          |          |          "
-    "           |          |          |          "
     "         2 |      2-0 | https:// |      2-2 "
     "           |          |      www |          "
     "           |          |        . |          "
@@ -49,7 +48,6 @@ test_table!(
     "           |          |          |          "
     "    asdasd |          |          |          "
     "           |          |          |          "
-    "           |          |          |          "
     "         2 |      2-0 | https:// |      2-2 "
     "           |          |      www |          "
     "           |          |        . |          "
@@ -72,9 +70,8 @@ test_table!(
     " 0                 | 0-0      | 0-1      | 0-2      "
     "                   |          |          |          "
     "                   |          |          |          "
-    "                   |          |          |          "
-    " asd               | 1-0      | 1-1      | 1-2      "
-    " 21213123   asdasd |          |          |          "
+    " asd               |          |          |          "
+    " 21213123   asdasd | 1-0      | 1-1      | 1-2      "
     "                   |          |          |          "
     "                   |          |          |          "
     "                   |          |          |          "
@@ -2,9 +2,14 @@
 
 // todo: add method for SPACING between cells.
 
-use tabled::settings::{
-    object::{Cell, Rows},
-    Border, Highlight, Rotate,
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    settings::{
+        object::{Cell, Rows},
+        Border, Highlight, Rotate,
+    },
 };
 
 use crate::matrix::Matrix;
@@ -212,3 +217,70 @@ test_table!(
     "| i32 | i32 | i32 | i32 | i32 | i32 |"
     "+-----+-----+-----+-----+-----+-----+"
 );
+
+test_table!(
+    rotate_cell_vertical_mixed_length_headers,
+    Builder::from_iter([["a", "count", "ok"], ["1", "10", "+"], ["2", "200", "-"]])
+        .build()
+        .modify(Rows::first(), Rotate::cell_vertical()),
+    "+---+-----+---+"
+    "| a | c   | o |"
+    "|   | o   | k |"
+    "|   | u   |   |"
+    "|   | n   |   |"
+    "|   | t   |   |"
+    "+---+-----+---+"
+    "| 1 | 10  | + |"
+    "+---+-----+---+"
+    "| 2 | 200 | - |"
+    "+---+-----+---+"
+);
+
+test_table!(
+    rotate_cell_vertical_multi_word,
+    Builder::from_iter([["total sum", "n"], ["7", "1"]])
+        .build()
+        .modify(Rows::first(), Rotate::cell_vertical()),
+    "+---+---+"
+    "| t | n |"
+    "| o |   |"
+    "| t |   |"
+    "| a |   |"
+    "| l |   |"
+    "|   |   |"
+    "| s |   |"
+    "| u |   |"
+    "| m |   |"
+    "+---+---+"
+    "| 7 | 1 |"
+    "+---+---+"
+);
+
+test_table!(
+    rotate_cell_vertical_per_word,
+    Builder::from_iter([["total sum", "is done"], ["7", "+"]])
+        .build()
+        .modify(Rows::first(), Rotate::cell_vertical().per_word(true)),
+    "+-----+-----+"
+    "| t s | i d |"
+    "| o u | s o |"
+    "| t m |   n |"
+    "| a   |   e |"
+    "| l   |     |"
+    "+-----+-----+"
+    "| 7   | +   |"
+    "+-----+-----+"
+);
+
+test_table!(
+    rotate_cell_vertical_graphemes,
+    Builder::from_iter([["e\u{301}a", "👩\u{200D}🔬x", "宽"], ["1", "2", "3"]])
+        .build()
+        .modify(Rows::first(), Rotate::cell_vertical()),
+    "+---+----+----+"
+    "| é | 👩‍🔬 | 宽 |"
+    "| a | x  |    |"
+    "+---+----+----+"
+    "| 1 | 2  | 3  |"
+    "+---+----+----+"
+);
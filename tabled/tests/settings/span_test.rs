@@ -5,7 +5,7 @@ use std::iter::FromIterator;
 
 use tabled::{
     builder::Builder,
-    grid::config::Position,
+    grid::config::{AlignmentVertical, Position},
     settings::{
         object::{Columns, Segment},
         style::{Border, BorderSpanCorrection, Style},
@@ -1232,3 +1232,71 @@ test_table!(
 fn create_span_list(count_rows: usize, count_cols: usize) -> impl Iterator<Item = Position> {
     (0..count_rows).flat_map(move |r| (0..count_cols).map(move |c| (r, c)))
 }
+
+test_table!(
+    row_span_tall_content_is_distributed_evenly,
+    Builder::from_iter([
+        ["name", "value"],
+        ["1\n2\n3\n4\n5\n6\n7", "a"],
+        ["", "b"],
+        ["", "c"],
+    ])
+    .build()
+    .with(Modify::new((1, 0)).with(Span::row(3))),
+    "+------+-------+"
+    "| name | value |"
+    "+------+-------+"
+    "| 1    | a     |"
+    "| 2    |       |"
+    "+ 3    +-------+"
+    "| 4    | b     |"
+    "| 5    |       |"
+    "+ 6    +-------+"
+    "| 7    | c     |"
+    "+------+-------+"
+);
+
+test_table!(
+    row_span_tall_content_without_borders,
+    Builder::from_iter([
+        ["name", "value"],
+        ["1\n2\n3\n4\n5\n6\n7", "a"],
+        ["", "b"],
+        ["", "c"],
+    ])
+    .build()
+    .with(Style::blank())
+    .with(Modify::new((1, 0)).with(Span::row(3))),
+    " name   value "
+    " 1      a     "
+    " 2            "
+    " 3            "
+    " 4      b     "
+    " 5            "
+    " 6      c     "
+    " 7            "
+);
+
+test_table!(
+    row_span_vertical_alignment,
+    Builder::from_iter([
+        ["name", "value"],
+        ["tabled", "a\nb"],
+        ["", "c\nd"],
+        ["", "e\nf"],
+    ])
+    .build()
+    .with(Modify::new((1, 0)).with(Span::row(3).alignment(AlignmentVertical::Bottom))),
+    "+--------+-------+"
+    "| name   | value |"
+    "+--------+-------+"
+    "|        | a     |"
+    "|        | b     |"
+    "+        +-------+"
+    "|        | c     |"
+    "|        | d     |"
+    "+        +-------+"
+    "|        | e     |"
+    "| tabled | f     |"
+    "+--------+-------+"
+);
@@ -8,11 +8,12 @@ use tabled::{
     settings::{
         object::{Columns, Rows, Segment},
         style::{
-            Border, BorderColor, BorderSpanCorrection, HorizontalLine, LineChar, LineText, Offset,
-            On, Style, VerticalLine,
+            Border, BorderCharWidthError, BorderColor, BorderSpanCorrection,
+            HorizontalBorderRepeat, HorizontalLine, HorizontalSplit, LineChar, LineText, Offset,
+            On, Style, StyleError, VerticalLine, VerticalSplit,
         },
         themes::Theme,
-        Color, Format, Highlight, Modify, Padding, Span,
+        Color, Format, Highlight, Margin, Modify, Padding, Settings, Span,
     },
     Table,
 };
@@ -57,6 +58,60 @@ test_table!(
     "| 2 |   2-0    |   2-1    |   2-2    |"
 );
 
+test_table!(
+    header_separator_only_style,
+    Matrix::new(3, 3).with(Style::header_separator_only()),
+    " N  column 0  column 1  column 2 "
+    "---------------------------------"
+    " 0    0-0       0-1       0-2    "
+    " 1    1-0       1-1       1-2    "
+    " 2    2-0       2-1       2-2    "
+);
+
+test_table!(
+    heavy_style,
+    Matrix::new(3, 3).with(Style::heavy()),
+    "┏━━━┳━━━━━━━━━━┳━━━━━━━━━━┳━━━━━━━━━━┓"
+    "┃ N ┃ column 0 ┃ column 1 ┃ column 2 ┃"
+    "┣━━━╋━━━━━━━━━━╋━━━━━━━━━━╋━━━━━━━━━━┫"
+    "┃ 0 ┃   0-0    ┃   0-1    ┃   0-2    ┃"
+    "┣━━━╋━━━━━━━━━━╋━━━━━━━━━━╋━━━━━━━━━━┫"
+    "┃ 1 ┃   1-0    ┃   1-1    ┃   1-2    ┃"
+    "┣━━━╋━━━━━━━━━━╋━━━━━━━━━━╋━━━━━━━━━━┫"
+    "┃ 2 ┃   2-0    ┃   2-1    ┃   2-2    ┃"
+    "┗━━━┻━━━━━━━━━━┻━━━━━━━━━━┻━━━━━━━━━━┛"
+);
+
+test_table!(
+    heavy_light_style,
+    Matrix::new(3, 3).with(Style::heavy_light()),
+    "┏━━━┯━━━━━━━━━━┯━━━━━━━━━━┯━━━━━━━━━━┓"
+    "┃ N │ column 0 │ column 1 │ column 2 ┃"
+    "┠───┼──────────┼──────────┼──────────┨"
+    "┃ 0 │   0-0    │   0-1    │   0-2    ┃"
+    "┠───┼──────────┼──────────┼──────────┨"
+    "┃ 1 │   1-0    │   1-1    │   1-2    ┃"
+    "┠───┼──────────┼──────────┼──────────┨"
+    "┃ 2 │   2-0    │   2-1    │   2-2    ┃"
+    "┗━━━┷━━━━━━━━━━┷━━━━━━━━━━┷━━━━━━━━━━┛"
+);
+
+test_table!(
+    heavy_light_style_remove_horizontals,
+    Matrix::new(3, 3).with(
+        Style::heavy_light()
+            .remove_horizontal()
+            .horizontals([(1, HorizontalLine::full('─', '┼', '┠', '┨'))])
+    ),
+    "┏━━━┯━━━━━━━━━━┯━━━━━━━━━━┯━━━━━━━━━━┓"
+    "┃ N │ column 0 │ column 1 │ column 2 ┃"
+    "┠───┼──────────┼──────────┼──────────┨"
+    "┃ 0 │   0-0    │   0-1    │   0-2    ┃"
+    "┃ 1 │   1-0    │   1-1    │   1-2    ┃"
+    "┃ 2 │   2-0    │   2-1    │   2-2    ┃"
+    "┗━━━┷━━━━━━━━━━┷━━━━━━━━━━┷━━━━━━━━━━┛"
+);
+
 test_table!(
     modern_style,
     Matrix::new(3, 3).with(Style::modern()),
@@ -2423,7 +2478,6 @@ test_table!(
     "| 0         | 0-0       | 0-1       | 0-2       |"
     "| text      : text      | text      | text      |"
     "| text      | text      | text      | text      |"
-    "|           |           |           |           |"
     "|     1     |    1-0    |    1-1    |    1-2    |"
     "|     2     |    2-0    |    2-1    |    2-2    |"
 );
@@ -2439,12 +2493,11 @@ test_table!(
     "|     N     | column 0  | column 1  | column 2  |"
     "|-----------|-----------|-----------|-----------|"
     "|           |           |           |           |"
-    "| some text | some text | some text | some text |"
-    "| text      : text      | text      | text      |"
+    "| some text : some text | some text | some text |"
+    "| text      | text      | text      | text      |"
     "| 0         | 0-0       | 0-1       | 0-2       |"
     "| text      | text      | text      | text      |"
     "| text      | text      | text      | text      |"
-    "|           |           |           |           |"
     "|     1     |    1-0    |    1-1    |    1-2    |"
     "|     2     |    2-0    |    2-1    |    2-2    |"
 );
@@ -2715,3 +2768,275 @@ test_table!(
     "| |        |        |"
     "+-+--------+--------+"
 );
+
+test_table!(
+    horizontal_split,
+    Matrix::new(3, 2)
+        .with(Style::modern().remove_horizontal())
+        .with(HorizontalSplit::new(2).line(HorizontalLine::inherit(Style::modern()))),
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "│ 0 │   0-0    │   0-1    │"
+    "│ 1 │   1-0    │   1-1    │"
+    "├───┼──────────┼──────────┤"
+    "│ 2 │   2-0    │   2-1    │"
+    "└───┴──────────┴──────────┘"
+);
+
+test_table!(
+    horizontal_split_stack,
+    Matrix::new(3, 2)
+        .with(Style::psql())
+        .with(HorizontalSplit::new(1))
+        .with(HorizontalSplit::new(2).line(HorizontalLine::new('=').intersection('#'))),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    |   0-1    "
+    "---+----------+----------"
+    " 1 |   1-0    |   1-1    "
+    "===#==========#=========="
+    " 2 |   2-0    |   2-1    "
+);
+
+test_table!(
+    horizontal_split_default_chars,
+    Matrix::new(3, 2).with(Style::blank()).with(HorizontalSplit::new(1)),
+    " N   column 0   column 1 "
+    " 0     0-0        0-1    "
+    "---+----------+----------"
+    " 1     1-0        1-1    "
+    " 2     2-0        2-1    "
+);
+
+test_table!(
+    horizontal_split_out_of_bounds,
+    Matrix::new(2, 2)
+        .with(Style::psql())
+        .with(HorizontalSplit::new(2))
+        .with(HorizontalSplit::new(10)),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    |   0-1    "
+    " 1 |   1-0    |   1-1    "
+);
+
+test_table!(
+    vertical_split,
+    Matrix::new(2, 3)
+        .with(Style::ascii())
+        .with(VerticalSplit::new(1)),
+    "+---+----------+----------+----------+"
+    "| N | column 0 ║ column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    ║   0-1    |   0-2    |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    ║   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    vertical_split_stack,
+    Matrix::new(2, 3)
+        .with(Style::modern())
+        .with(VerticalSplit::new(0))
+        .with(VerticalSplit::new(2).line(VerticalLine::inherit(Style::extended()))),
+    "┌───┬──────────┬──────────╦──────────┐"
+    "│ N ║ column 0 │ column 1 ║ column 2 │"
+    "├───┼──────────┼──────────╬──────────┤"
+    "│ 0 ║   0-0    │   0-1    ║   0-2    │"
+    "├───┼──────────┼──────────╬──────────┤"
+    "│ 1 ║   1-0    │   1-1    ║   1-2    │"
+    "└───┴──────────┴──────────╩──────────┘"
+);
+
+test_table!(
+    vertical_split_out_of_bounds,
+    Matrix::new(1, 2)
+        .with(Style::markdown())
+        .with(VerticalSplit::new(2))
+        .with(VerticalSplit::new(10)),
+    "| N | column 0 | column 1 |"
+    "|---|----------|----------|"
+    "| 0 |   0-0    |   0-1    |"
+);
+
+#[test]
+fn style_wide_vertical_char_is_rejected() {
+    let style = Style::ascii().vertical('＃');
+
+    assert_eq!(
+        style.check_chars_width(),
+        Err(BorderCharWidthError { c: '＃', width: 2 })
+    );
+    assert_eq!(
+        BorderCharWidthError { c: '＃', width: 2 }.to_string(),
+        "border character '＃' has a display width of 2, but only characters of width 1 are supported"
+    );
+
+    let style = Style::ascii().verticals([(1, VerticalLine::full('🦀', '+', '+', '+'))]);
+    assert_eq!(
+        style.check_chars_width(),
+        Err(BorderCharWidthError {
+            c: '🦀', width: 2
+        })
+    );
+
+    assert_eq!(Style::modern().check_chars_width(), Ok(()));
+    assert_eq!(Style::extended().check_chars_width(), Ok(()));
+}
+
+test_table!(
+    style_wide_vertical_char_is_replaced_on_apply,
+    Matrix::new(2, 2).with(Style::ascii().vertical('＃')),
+    "+--- ---------- ----------+"
+    "| N   column 0   column 1 |"
+    "+--- ---------- ----------+"
+    "| 0     0-0        0-1    |"
+    "+--- ---------- ----------+"
+    "| 1     1-0        1-1    |"
+    "+--- ---------- ----------+"
+);
+
+#[test]
+fn style_wide_vertical_char_is_replaced_on_apply_compact() {
+    let table = tabled::tables::CompactTable::new([["a", "b"]])
+        .columns(2)
+        .width(3)
+        .with(Style::ascii().vertical('🦀'))
+        .to_string();
+
+    assert_eq!(table, "+--- ---+\n| a   b |\n+--- ---+");
+}
+
+#[test]
+fn style_verify() {
+    assert_eq!(Style::modern().verify(), Ok(()));
+    assert_eq!(Style::psql().verify(), Ok(()));
+    assert_eq!(Style::markdown().verify(), Ok(()));
+    assert_eq!(Style::re_structured_text().verify(), Ok(()));
+
+    let style = Style::ascii().verticals([(
+        1,
+        VerticalLine::<On, On, On>::empty()
+            .vertical('#')
+            .intersection('+')
+            .bottom('+'),
+    )]);
+    let err = style.verify().unwrap_err();
+    assert_eq!(
+        err,
+        StyleError {
+            missing: "top intersection of a custom vertical line",
+            lines: ("custom vertical", "top")
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "missing top intersection of a custom vertical line while custom vertical and top lines are set"
+    );
+
+    let style = Style::ascii().horizontals([(1, HorizontalLine::empty().horizontal('='))]);
+    assert_eq!(
+        style.verify().unwrap_err().to_string(),
+        "missing left intersection of a custom horizontal line while custom horizontal and left lines are set"
+    );
+
+    let style = Style::ascii().verticals([(
+        1,
+        VerticalLine::<On, On, On>::empty()
+            .vertical('#')
+            .top('+')
+            .bottom('+'),
+    )]);
+    assert_eq!(
+        style.verify().unwrap_err().to_string(),
+        "missing intersection of a custom vertical line while custom vertical and horizontal lines are set"
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "missing intersection of a custom vertical line")]
+fn style_verify_panics_on_apply() {
+    let style = Style::ascii().verticals([(
+        1,
+        VerticalLine::<On, On, On>::empty()
+            .vertical('#')
+            .top('+')
+            .bottom('+'),
+    )]);
+
+    let _ = Matrix::new(2, 2).with(style).to_string();
+}
+
+const CUSTOM_STYLE: Style<On, On, On, On, (), On, 0, 0> = Style::modern().remove_horizontal();
+const _: () = assert!(CUSTOM_STYLE.verify().is_ok());
+
+test_table!(
+    horizontal_border_repeat,
+    Matrix::new(2, 2).with(Settings::new(Style::modern(), HorizontalBorderRepeat(2))),
+    "┌───┬──────────┬──────────┐"
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "├───┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │"
+    "├───┼──────────┼──────────┤"
+    "├───┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │"
+    "└───┴──────────┴──────────┘"
+    "└───┴──────────┴──────────┘"
+);
+
+test_table!(
+    horizontal_border_repeat_psql_with_margin,
+    Matrix::new(2, 2)
+        .with(Style::psql())
+        .with(Margin::new(1, 1, 1, 1))
+        .with(HorizontalBorderRepeat(3)),
+    "                           "
+    "  N | column 0 | column 1  "
+    " ---+----------+---------- "
+    " ---+----------+---------- "
+    " ---+----------+---------- "
+    "  0 |   0-0    |   0-1     "
+    "  1 |   1-0    |   1-1     "
+    "                           "
+);
+
+test_table!(
+    horizontal_border_repeat_multiline,
+    Table::from_iter([["long\nheader", "b"], ["1", "2"]])
+        .with(HorizontalBorderRepeat(2)),
+    "+--------+---+"
+    "+--------+---+"
+    "| long   | b |"
+    "| header |   |"
+    "+--------+---+"
+    "+--------+---+"
+    "| 1      | 2 |"
+    "+--------+---+"
+    "+--------+---+"
+);
+
+#[test]
+fn horizontal_border_repeat_default() {
+    let table = Matrix::new(2, 2).with(Style::modern()).to_string();
+
+    for n in [0, 1] {
+        let repeated = Matrix::new(2, 2)
+            .with(Settings::new(Style::modern(), HorizontalBorderRepeat(n)))
+            .to_string();
+        assert_eq!(repeated, table);
+    }
+
+    assert_eq!(HorizontalBorderRepeat::default(), HorizontalBorderRepeat(1));
+}
+
+#[test]
+fn horizontal_border_repeat_total_height() {
+    let mut table = Matrix::new(3, 2).with(Style::extended()).clone();
+    table.with(HorizontalBorderRepeat(2));
+
+    assert_eq!(table.total_height(), table.to_string().lines().count());
+}
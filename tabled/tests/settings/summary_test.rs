@@ -0,0 +1,150 @@
+#![cfg(feature = "std")]
+
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    settings::summary::{Aggregate, Summary},
+    Table,
+};
+
+use testing_table::test_table;
+
+fn data() -> Builder {
+    Builder::from_iter([
+        ["item", "price", "count", "comment"],
+        ["apple", "1.5", "3", "fresh"],
+        ["pear", "", "n/a", "-"],
+        ["plum", "2.25", "1", ""],
+        ["cherry", "abc", "8", "sweet"],
+    ])
+}
+
+test_table!(
+    summary_sum_and_mean,
+    data()
+        .build()
+        .with(
+            Summary::new()
+                .label(0, "Total")
+                .col(1, Aggregate::Sum)
+                .col(2, Aggregate::Mean)
+        ),
+    "+--------+-------+-------+---------+"
+    "| item   | price | count | comment |"
+    "+--------+-------+-------+---------+"
+    "| apple  | 1.5   | 3     | fresh   |"
+    "+--------+-------+-------+---------+"
+    "| pear   |       | n/a   | -       |"
+    "+--------+-------+-------+---------+"
+    "| plum   | 2.25  | 1     |         |"
+    "+--------+-------+-------+---------+"
+    "| cherry | abc   | 8     | sweet   |"
+    "+--------+-------+-------+---------+"
+    "| Total  | 3.75  | 4     |         |"
+    "+--------+-------+-------+---------+"
+);
+
+test_table!(
+    summary_min_max_count,
+    data()
+        .build()
+        .with(
+            Summary::new()
+                .col(1, Aggregate::Min)
+                .col(2, Aggregate::Max)
+                .col(3, Aggregate::Count)
+                .precision(1)
+        ),
+    "+--------+-------+-------+---------+"
+    "| item   | price | count | comment |"
+    "+--------+-------+-------+---------+"
+    "| apple  | 1.5   | 3     | fresh   |"
+    "+--------+-------+-------+---------+"
+    "| pear   |       | n/a   | -       |"
+    "+--------+-------+-------+---------+"
+    "| plum   | 2.25  | 1     |         |"
+    "+--------+-------+-------+---------+"
+    "| cherry | abc   | 8     | sweet   |"
+    "+--------+-------+-------+---------+"
+    "|        | 1.5   | 8.0   | 3       |"
+    "+--------+-------+-------+---------+"
+);
+
+test_table!(
+    summary_not_numeric_column,
+    Table::new([("apple", "red"), ("plum", "blue")])
+        .with(Summary::new().col(0, Aggregate::Sum).col(1, Aggregate::Mean).col(5, Aggregate::Max)),
+    "+-------+------+"
+    "| &str  | &str |"
+    "+-------+------+"
+    "| apple | red  |"
+    "+-------+------+"
+    "| plum  | blue |"
+    "+-------+------+"
+    "|       |      |"
+    "+-------+------+"
+);
+
+test_table!(
+    summary_formatted_numbers,
+    Builder::from_iter([
+        ["item", "price"],
+        ["apple", "1,000"],
+        ["pear", "NaN"],
+        ["plum", "$20.5"],
+        ["cherry", "inf"],
+    ])
+    .build()
+    .with(Summary::new().label(0, "Total").col(1, Aggregate::Sum)),
+    "+--------+--------+"
+    "| item   | price  |"
+    "+--------+--------+"
+    "| apple  | 1,000  |"
+    "+--------+--------+"
+    "| pear   | NaN    |"
+    "+--------+--------+"
+    "| plum   | $20.5  |"
+    "+--------+--------+"
+    "| cherry | inf    |"
+    "+--------+--------+"
+    "| Total  | 1020.5 |"
+    "+--------+--------+"
+);
+
+test_table!(
+    summary_builder,
+    {
+        let mut b = data();
+        b.remove_column(3);
+        b.push_summary(&Summary::new().label(0, "Mean").col(1, Aggregate::Mean).col(2, Aggregate::Mean).precision(2));
+        b.build()
+    },
+    "+--------+-------+-------+"
+    "| item   | price | count |"
+    "+--------+-------+-------+"
+    "| apple  | 1.5   | 3     |"
+    "+--------+-------+-------+"
+    "| pear   |       | n/a   |"
+    "+--------+-------+-------+"
+    "| plum   | 2.25  | 1     |"
+    "+--------+-------+-------+"
+    "| cherry | abc   | 8     |"
+    "+--------+-------+-------+"
+    "| Mean   | 1.88  | 4.00  |"
+    "+--------+-------+-------+"
+);
+
+test_table!(
+    summary_header_only,
+    {
+        let mut b = Builder::from_iter([["price"]]);
+        b.push_summary(&Summary::new().col(0, Aggregate::Sum));
+        b.build()
+    },
+    "+-------+"
+    "| price |"
+    "+-------+"
+    "|       |"
+    "+-------+"
+);
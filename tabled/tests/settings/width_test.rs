@@ -1,12 +1,15 @@
 #![cfg(feature = "std")]
 
+use std::iter::FromIterator;
+
 use tabled::{
+    builder::Builder,
     grid::util::string::string_width_multiline,
     settings::{
         formatting::{TabSize, TrimStrategy},
         object::{Columns, Object, Rows, Segment},
         peaker::{PriorityMax, PriorityMin},
-        width::{Justify, MinWidth, SuffixLimit, Width},
+        width::{Cut, Justify, MinWidth, SuffixLimit, Width},
         Alignment, Margin, Modify, Padding, Panel, Settings, Span, Style,
     },
 };
@@ -2358,7 +2361,6 @@ fn cell_truncate_multiline() {
             "| 1 |    1     |     1-1     |   1-2    |"
             "| 2 |    2     | multi       |   2-2    |"
             "|   |          | line string |          |"
-            "|   |          |             |          |"
         )
     );
 }
@@ -2386,7 +2388,6 @@ fn cell_truncate_multiline_with_suffix() {
             "| 1 |    .     |     1-1     |   1-2    |"
             "| 2 |    .     | multi       |   2-2    |"
             "|   |          | line string |          |"
-            "|   |          |             |          |"
         )
     );
 }
@@ -2411,7 +2412,6 @@ fn table_truncate_multiline() {
             "|  | 1 | 1-1  | 1- |"
             "|  | 2 | mult | 2- |"
             "|  |   | line |    |"
-            "|  |   |      |    |"
         )
     );
 }
@@ -2832,3 +2832,215 @@ mod derived {
         );
     }
 }
+
+fn paths_table() -> tabled::Table {
+    tabled::Table::new([
+        ["/var/logs/today.log", "deadbeef0123456789"],
+        ["/tmp/a", "0123"],
+    ])
+}
+
+test_table!(
+    truncate_cut_left,
+    paths_table().with(Modify::new(Columns::single(0)).with(Width::truncate(12).suffix("…").cut(Cut::Left))),
+    "+--------------+--------------------+"
+    "| 0            | 1                  |"
+    "+--------------+--------------------+"
+    "| …s/today.log | deadbeef0123456789 |"
+    "+--------------+--------------------+"
+    "| /tmp/a       | 0123               |"
+    "+--------------+--------------------+"
+);
+
+test_table!(
+    truncate_cut_middle,
+    paths_table().with(Modify::new(Columns::single(1)).with(Width::truncate(11).suffix("…").cut(Cut::Middle))),
+    "+---------------------+-------------+"
+    "| 0                   | 1           |"
+    "+---------------------+-------------+"
+    "| /var/logs/today.log | deadb…56789 |"
+    "+---------------------+-------------+"
+    "| /tmp/a              | 0123        |"
+    "+---------------------+-------------+"
+);
+
+test_table!(
+    truncate_cut_middle_odd_width,
+    paths_table().with(Modify::new(Columns::single(1)).with(Width::truncate(10).suffix("..").cut(Cut::Middle))),
+    "+---------------------+------------+"
+    "| 0                   | 1          |"
+    "+---------------------+------------+"
+    "| /var/logs/today.log | dead..6789 |"
+    "+---------------------+------------+"
+    "| /tmp/a              | 0123       |"
+    "+---------------------+------------+"
+);
+
+test_table!(
+    truncate_cut_left_without_suffix,
+    paths_table().with(Modify::new(Columns::single(0)).with(Width::truncate(5).cut(Cut::Left))),
+    "+-------+--------------------+"
+    "| 0     | 1                  |"
+    "+-------+--------------------+"
+    "| y.log | deadbeef0123456789 |"
+    "+-------+--------------------+"
+    "| tmp/a | 0123               |"
+    "+-------+--------------------+"
+);
+
+test_table!(
+    truncate_cut_width_smaller_than_suffix,
+    paths_table()
+        .with(Modify::new(Columns::single(0)).with(Width::truncate(2).suffix("...").cut(Cut::Left)))
+        .with(Modify::new(Columns::single(1)).with(Width::truncate(2).suffix("...").cut(Cut::Middle))),
+    "+----+----+"
+    "| 0  | 1  |"
+    "+----+----+"
+    "| .. | .. |"
+    "+----+----+"
+    "| .. | .. |"
+    "+----+----+"
+);
+
+test_table!(
+    truncate_cut_width_smaller_than_suffix_ignored,
+    paths_table().with(
+        Modify::new(Columns::single(1))
+            .with(Width::truncate(2).suffix("...").suffix_limit(SuffixLimit::Ignore).cut(Cut::Middle))
+    ),
+    "+---------------------+----+"
+    "| 0                   | 1  |"
+    "+---------------------+----+"
+    "| /var/logs/today.log | d9 |"
+    "+---------------------+----+"
+    "| /tmp/a              | 03 |"
+    "+---------------------+----+"
+);
+
+test_table!(
+    truncate_cut_width_equal_to_content,
+    paths_table()
+        .with(Modify::new(Columns::single(0)).with(Width::truncate(19).suffix("…").cut(Cut::Left)))
+        .with(Modify::new(Columns::single(1)).with(Width::truncate(18).suffix("…").cut(Cut::Middle))),
+    "+---------------------+--------------------+"
+    "| 0                   | 1                  |"
+    "+---------------------+--------------------+"
+    "| /var/logs/today.log | deadbeef0123456789 |"
+    "+---------------------+--------------------+"
+    "| /tmp/a              | 0123               |"
+    "+---------------------+--------------------+"
+);
+
+test_table!(
+    truncate_cut_wide_chars,
+    tabled::Table::new([["你好世界"], ["ab你好"]])
+        .with(Modify::new(Rows::single(1)).with(Width::truncate(5).cut(Cut::Left)))
+        .with(Modify::new(Rows::single(2)).with(Width::truncate(5).suffix(".").cut(Cut::Middle))),
+    "+-------+"
+    "| 0     |"
+    "+-------+"
+    "| �世界 |"
+    "+-------+"
+    "| ab.好 |"
+    "+-------+"
+);
+
+test_table!(
+    truncate_cut_left_total_width,
+    paths_table().with(Width::truncate(20).suffix("…").cut(Cut::Left)),
+    "+---------+--------+"
+    "| 0       | 1      |"
+    "+---------+--------+"
+    "| …ay.log | …56789 |"
+    "+---------+--------+"
+    "| /tmp/a  | 0123   |"
+    "+---------+--------+"
+);
+
+#[cfg(feature = "ansi")]
+#[test]
+fn truncate_cut_left_ansi() {
+    let text = format!("{}{}", "/var/logs/".red(), "today.log".blue());
+
+    let mut table = tabled::Table::new([[text]]);
+    table.with(Modify::new(Rows::single(1)).with(Width::truncate(12).suffix("…").cut(Cut::Left)));
+
+    assert_eq!(
+        AnsiStr::ansi_strip(&table.to_string()),
+        static_table!(
+            "+--------------+"
+            "| 0            |"
+            "+--------------+"
+            "| …s/today.log |"
+            "+--------------+"
+        )
+    );
+
+    assert!(table.to_string().contains(&"today.log".blue().to_string()));
+}
+
+test_table!(
+    truncate_without_suffix_clips_multibyte,
+    Builder::from_iter([["message"], ["ошибка соединения"], ["naïve café"]])
+        .build()
+        .with(Modify::new(Segment::all()).with(Width::truncate(6))),
+    "+--------+"
+    "| messag |"
+    "+--------+"
+    "| ошибка |"
+    "+--------+"
+    "| naïve  |"
+    "+--------+"
+);
+
+test_table!(
+    truncate_without_suffix_clips_wide_chars,
+    Builder::from_iter([["log"], ["日本語のログ"]])
+        .build()
+        .with(Modify::new(Segment::all()).with(Width::truncate(5))),
+    "+-------+"
+    "| log   |"
+    "+-------+"
+    "| 日本� |"
+    "+-------+"
+);
+
+test_table!(
+    truncate_measures_invisible_chars_as_width,
+    Builder::from_iter([["soft\u{00AD}ware"], ["👨\u{200D}👩\u{200D}👧 family"]])
+        .build()
+        .with(Modify::new(Segment::all()).with(Width::truncate(5))),
+    "+-------+"
+    "| soft\u{00AD}w |"
+    "+-------+"
+    "| 👨\u{200D}👩\u{200D}👧 fa |"
+    "+-------+"
+);
+
+test_table!(
+    wrap_measures_invisible_chars_as_width,
+    Builder::from_iter([["soft\u{00AD}ware"], ["👨\u{200D}👩\u{200D}👧 family"]])
+        .build()
+        .with(Modify::new(Segment::all()).with(Width::wrap(5))),
+    "+-------+"
+    "| soft\u{00AD}w |"
+    "| are   |"
+    "+-------+"
+    "| 👨\u{200D}👩\u{200D}👧 fa |"
+    "| mily  |"
+    "+-------+"
+);
+
+test_table!(
+    wrap_keeping_words_measures_invisible_chars_as_width,
+    Builder::from_iter([["soft\u{00AD}ware"], ["👨\u{200D}👩\u{200D}👧 family"]])
+        .build()
+        .with(Modify::new(Segment::all()).with(Width::wrap(5).keep_words())),
+    "+-------+"
+    "| soft\u{00AD}w |"
+    "| are   |"
+    "+-------+"
+    "| 👨\u{200D}👩\u{200D}👧 fa |"
+    "| mily  |"
+    "+-------+"
+);
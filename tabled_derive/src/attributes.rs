@@ -5,6 +5,7 @@ use crate::{
     error::Error,
     parse::{
         field_attr::{parse_field_attributes, FieldAttr, FieldAttrKind},
+        serde_attr::parse_serde_rename,
         type_attr::{parse_type_attributes, TypeAttr, TypeAttrKind},
     },
 };
@@ -21,12 +22,30 @@ pub struct FieldAttributes {
     pub order: Option<usize>,
     pub format: Option<String>,
     pub format_with_args: Option<Vec<FuncArg>>,
+    pub with: Option<String>,
+    pub serde_rename: Option<String>,
+    pub width: Option<usize>,
+    pub wrap: bool,
+    pub truncate: bool,
 }
 
 impl FieldAttributes {
     pub fn parse(attrs: &[Attribute]) -> Result<Self, Error> {
         let mut attributes = Self::default();
         attributes.fill_attributes(attrs)?;
+        attributes.serde_rename = parse_serde_rename(attrs);
+
+        if attributes.wrap && attributes.truncate {
+            return Err(Error::message(
+                "wrap and truncate attributes can't be used together",
+            ));
+        }
+
+        if (attributes.wrap || attributes.truncate) && attributes.width.is_none() {
+            return Err(Error::message(
+                "wrap and truncate attributes require a width attribute",
+            ));
+        }
 
         Ok(attributes)
     }
@@ -83,6 +102,10 @@ impl FieldAttributes {
                 }
             }
             FieldAttrKind::Order(value) => self.order = Some(lit_int_to_usize(&value)?),
+            FieldAttrKind::With(path) => self.with = Some(path.value()),
+            FieldAttrKind::Width(value) => self.width = Some(lit_int_to_usize(&value)?),
+            FieldAttrKind::Wrap(b) => self.wrap = b.value,
+            FieldAttrKind::Truncate(b) => self.truncate = b.value,
         }
 
         Ok(())
@@ -95,6 +118,8 @@ pub struct TypeAttributes {
     pub inline: bool,
     pub inline_value: Option<String>,
     pub crate_name: Option<String>,
+    pub transparent: bool,
+    pub serde_rename: bool,
 }
 
 impl TypeAttributes {
@@ -136,6 +161,16 @@ impl TypeAttributes {
             TypeAttrKind::RenameAll(lit) => {
                 self.rename_all = Some(CasingStyle::from_lit(&lit)?);
             }
+            TypeAttrKind::Transparent(b) => {
+                if b.value {
+                    self.transparent = true;
+                }
+            }
+            TypeAttrKind::SerdeRename(b) => {
+                if b.value {
+                    self.serde_rename = true;
+                }
+            }
         }
 
         Ok(())
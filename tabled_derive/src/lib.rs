@@ -45,6 +45,19 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
         .unwrap();
     let fields = info.values;
     let headers = info.headers;
+    let hints = info.hints.map(|hints| {
+        let hint_path = create_column_hint_path(&tabled_trait_path);
+        quote! {
+            #[allow(unused_mut, unused_variables, unused_assignments)]
+            fn column_hints() -> Vec<#hint_path> {
+                #hints
+            }
+        }
+    });
+
+    let transparent = attrs
+        .transparent
+        .then(|| quote! { const TRANSPARENT: bool = true; });
 
     let name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
@@ -52,6 +65,7 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
     let expanded = quote! {
         impl #impl_generics #tabled_trait_path for #name #ty_generics #where_clause {
             const LENGTH: usize = #length;
+            #transparent
 
             fn fields(&self) -> Vec<::std::borrow::Cow<'_, str>> {
                 #fields
@@ -60,6 +74,8 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
             fn headers() -> Vec<::std::borrow::Cow<'static, str>> {
                 #headers
             }
+
+            #hints
         }
     };
 
@@ -71,6 +87,10 @@ fn get_tabled_length(
     attrs: &TypeAttributes,
     trait_path: &ExprPath,
 ) -> Result<TokenStream, Error> {
+    if attrs.transparent {
+        return transparent_field(ast).map(|_| quote! { 1 });
+    }
+
     match &ast.data {
         Data::Struct(data) => get_fields_length(&data.fields, trait_path),
         Data::Enum(data) => {
@@ -156,6 +176,10 @@ fn collect_info(
     attrs: &TypeAttributes,
     trait_path: &ExprPath,
 ) -> Result<Impl, Error> {
+    if attrs.transparent {
+        return collect_info_transparent(ast, trait_path);
+    }
+
     match &ast.data {
         Data::Struct(data) => collect_info_struct(data, attrs, trait_path),
         Data::Enum(data) => collect_info_enum(data, attrs, &ast.ident, trait_path),
@@ -163,6 +187,53 @@ fn collect_info(
     }
 }
 
+fn collect_info_transparent(ast: &DeriveInput, trait_path: &ExprPath) -> Result<Impl, Error> {
+    let (fields, field) = transparent_field(ast)?;
+    let attributes = FieldAttributes::parse(&field.attrs)?;
+    if attributes.is_ignored || attributes.inline {
+        return Err(Error::new(
+            "skip and inline attributes can't be used on a field of a transparent type",
+            ast.ident.span(),
+            None,
+        ));
+    }
+
+    let field_name = field_var_name(0, field);
+    let values = get_field_fields(
+        &field_name,
+        &field.ty,
+        &attributes,
+        fields,
+        field_var_name,
+        trait_path,
+    );
+
+    // An empty header is replaced by a field name when the type is inlined, see `TRANSPARENT`.
+    let headers = quote! { vec![::std::borrow::Cow::Borrowed("")] };
+
+    Ok(Impl {
+        headers,
+        values,
+        hints: None,
+    })
+}
+
+fn transparent_field(ast: &DeriveInput) -> Result<(&Fields, &Field), Error> {
+    if let Data::Struct(data) = &ast.data {
+        if data.fields.len() == 1 {
+            if let Some(field) = data.fields.iter().next() {
+                return Ok((&data.fields, field));
+            }
+        }
+    }
+
+    Err(Error::new(
+        "transparent attribute can be used only on a struct with a single field",
+        ast.ident.span(),
+        None,
+    ))
+}
+
 fn collect_info_struct(
     ast: &DataStruct,
     attrs: &TypeAttributes,
@@ -195,6 +266,8 @@ fn info_from_fields(
 
     let mut headers = Vec::new();
     let mut values = Vec::new();
+    let mut hints = Vec::new();
+    let mut has_hints = false;
     let mut reorder = HashMap::new();
 
     let mut skipped = 0;
@@ -218,14 +291,26 @@ fn info_from_fields(
         let header = field_headers(field, i, &attributes, header_prefix, trait_path);
         headers.push(header);
 
+        has_hints |= attributes.width.is_some() || attributes.inline;
+        let hint = field_hints(field, &attributes, trait_path);
+        hints.push(hint);
+
         let field_name_result = field_name(i, field);
-        let value = get_field_fields(&field_name_result, &attributes, fields, &field_name);
+        let value = get_field_fields(
+            &field_name_result,
+            &field.ty,
+            &attributes,
+            fields,
+            &field_name,
+            trait_path,
+        );
         values.push(value);
     }
 
     if !reorder.is_empty() {
         values = reorder_fields(&reorder, &values);
         headers = reorder_fields(&reorder, &headers);
+        hints = reorder_fields(&reorder, &hints);
     }
 
     let headers = quote!({
@@ -240,7 +325,27 @@ fn info_from_fields(
         out
     });
 
-    Ok(Impl { headers, values })
+    // A column of a hint is shifted by a length of the previous fields.
+    //
+    // The hints are not emitted when they're not used,
+    // so the hint type is not required to be available next to the trait.
+    let hint_path = create_column_hint_path(trait_path);
+    let hints = has_hints.then(|| quote!({
+        let mut out = Vec::new();
+        let mut offset = 0;
+        #(
+            let (hints, length) = #hints;
+            out.extend(hints.into_iter().map(|hint| #hint_path { column: hint.column + offset, ..hint }));
+            offset += length;
+        )*
+        out
+    }));
+
+    Ok(Impl {
+        headers,
+        values,
+        hints,
+    })
 }
 
 fn reorder_fields<T: Clone>(order: &HashMap<usize, usize>, elements: &[T]) -> Vec<T> {
@@ -278,15 +383,16 @@ fn field_headers(
     prefix: &str,
     trait_path: &ExprPath,
 ) -> TokenStream {
+    let header_name = field_header_name(field, attributes, index);
+
     if attributes.inline {
         let prefix = attributes
             .inline_prefix
             .as_ref()
             .map_or_else(|| "", |s| s.as_str());
-        return get_type_headers(&field.ty, prefix, "", trait_path);
+        return get_type_headers(&field.ty, prefix, "", &header_name, trait_path);
     }
 
-    let header_name = field_header_name(field, attributes, index);
     if prefix.is_empty() {
         quote!(vec![::std::borrow::Cow::Borrowed(#header_name)])
     } else {
@@ -295,6 +401,24 @@ fn field_headers(
     }
 }
 
+fn field_hints(field: &Field, attributes: &FieldAttributes, trait_path: &ExprPath) -> TokenStream {
+    let field_type = &field.ty;
+    if attributes.inline {
+        return quote! {
+            (<#field_type as #trait_path>::column_hints(), <#field_type as #trait_path>::LENGTH)
+        };
+    }
+
+    let hint_path = create_column_hint_path(trait_path);
+    match attributes.width {
+        Some(width) => {
+            let wrap = !attributes.truncate;
+            quote! { (vec![#hint_path { column: 0, width: #width, wrap: #wrap }], 1) }
+        }
+        None => quote! { (::std::vec::Vec::<#hint_path>::new(), 1) },
+    }
+}
+
 fn collect_info_enum(
     ast: &DataEnum,
     attrs: &TypeAttributes,
@@ -348,7 +472,11 @@ fn _collect_info_enum(
         .concat()
     };
 
-    Ok(Impl { headers, values })
+    Ok(Impl {
+        headers,
+        values,
+        hints: None,
+    })
 }
 
 fn collect_info_enum_inlined(
@@ -380,7 +508,11 @@ fn collect_info_enum_inlined(
         }
     };
 
-    Ok(Impl { headers, values })
+    Ok(Impl {
+        headers,
+        values,
+        hints: None,
+    })
 }
 
 fn info_from_variant(
@@ -397,6 +529,14 @@ fn info_from_variant(
         return info_from_fields(&variant.fields, attrs, variant_var_name, prefix, trait_path);
     }
 
+    if attr.with.is_some() {
+        return Err(Error::new(
+            "with attribute can be used only on fields",
+            variant.ident.span(),
+            None,
+        ));
+    }
+
     let variant_name = variant_name(variant, attr);
     let value = if let Some(func) = &attr.display_with {
         let args = match &attr.display_with_args {
@@ -450,26 +590,44 @@ fn info_from_variant(
     // we need exactly string because of it must be inlined as string
     let values = quote! { vec![#value] };
 
-    Ok(Impl { headers, values })
+    Ok(Impl {
+        headers,
+        values,
+        hints: None,
+    })
 }
 
 struct Impl {
     headers: TokenStream,
     values: TokenStream,
+    hints: Option<TokenStream>,
 }
 
+// An empty header of an inlined transparent type is replaced by a field name.
 fn get_type_headers(
     field_type: &Type,
     inline_prefix: &str,
     prefix: &str,
+    field_name: &str,
     tabled_trait: &ExprPath,
 ) -> TokenStream {
     if prefix.is_empty() && inline_prefix.is_empty() {
-        quote! { <#field_type as #tabled_trait>::headers() }
+        quote! {
+            <#field_type as #tabled_trait>::headers().into_iter()
+                .map(|header| match <#field_type as #tabled_trait>::TRANSPARENT && header.is_empty() {
+                    true => ::std::borrow::Cow::Borrowed(#field_name),
+                    false => header,
+                })
+                .collect::<Vec<_>>()
+        }
     } else {
         quote! {
             <#field_type as #tabled_trait>::headers().into_iter()
                 .map(|header| {
+                    let header = match <#field_type as #tabled_trait>::TRANSPARENT && header.is_empty() {
+                        true => ::std::borrow::Cow::Borrowed(#field_name),
+                        false => header,
+                    };
                     let header = format!("{}{}{}", #prefix, #inline_prefix, header);
                     ::std::borrow::Cow::Owned(header)
                 })
@@ -480,14 +638,31 @@ fn get_type_headers(
 
 fn get_field_fields(
     field: &TokenStream,
+    field_type: &Type,
     attr: &FieldAttributes,
     fields: &Fields,
     field_name: impl Fn(usize, &Field) -> TokenStream,
+    trait_path: &ExprPath,
 ) -> TokenStream {
     if attr.inline {
         return quote! { #field.fields() };
     }
 
+    if let Some(formatter) = &attr.with {
+        let formatter_trait = create_formatter_trait_path(trait_path);
+        let call = match syn::parse_str::<Type>(formatter) {
+            Ok(formatter) => {
+                quote! { <#formatter as #formatter_trait<#field_type>>::format(&#field) }
+            }
+            Err(_) => {
+                let message = format!("unexpected formatter type {formatter:?}");
+                quote! { compile_error!(#message) }
+            }
+        };
+
+        return quote!(vec![::std::borrow::Cow::Owned(#call)]);
+    }
+
     if let Some(func) = &attr.display_with {
         let args = match &attr.display_with_args {
             None => Some(quote!(&#field)),
@@ -709,6 +884,10 @@ fn merge_attributes(attr: &mut FieldAttributes, global_attr: &TypeAttributes) {
     if attr.rename_all.is_none() {
         attr.rename_all = global_attr.rename_all;
     }
+
+    if global_attr.serde_rename && attr.rename.is_none() {
+        attr.rename = attr.serde_rename.take();
+    }
 }
 
 fn fnarg_tokens(
@@ -793,6 +972,26 @@ fn parse_crate_name(name: &str) -> Result<ExprPath, Error> {
     syn::parse_str(name).map_err(|_| Error::message("unexpected crate attribute type"))
 }
 
+// The formatter trait is expected to be placed next to the `Tabled` trait.
+fn create_formatter_trait_path(tabled_trait: &ExprPath) -> ExprPath {
+    create_sibling_path(tabled_trait, "CellFormatter")
+}
+
+// The column hint type is expected to be placed next to the `Tabled` trait.
+fn create_column_hint_path(tabled_trait: &ExprPath) -> ExprPath {
+    create_sibling_path(tabled_trait, "ColumnHint")
+}
+
+fn create_sibling_path(tabled_trait: &ExprPath, name: &str) -> ExprPath {
+    let mut p = tabled_trait.clone();
+    let _ = p.path.segments.pop();
+    p.path.segments.push(PathSegment {
+        ident: Ident::new(name, proc_macro2::Span::call_site()),
+        arguments: syn::PathArguments::None,
+    });
+    p
+}
+
 fn create_tabled_trait_path(mut p: ExprPath) -> ExprPath {
     p.path.segments.push(PathSegment {
         ident: Ident::new("Tabled", proc_macro2::Span::call_site()),
@@ -33,6 +33,10 @@ pub enum FieldAttrKind {
     DisplayWith(LitStr, Option<Token!(,)>, Punctuated<syn::Expr, Token!(,)>),
     Order(LitInt),
     FormatWith(LitStr, Option<Token!(,)>, Punctuated<syn::Expr, Token!(,)>),
+    With(LitStr),
+    Width(LitInt),
+    Wrap(LitBool),
+    Truncate(LitBool),
 }
 
 impl Parse for FieldAttr {
@@ -55,6 +59,7 @@ impl Parse for FieldAttr {
                         return Ok(Self::new(DisplayWith(lit, None, Punctuated::new())))
                     }
                     "format" => return Ok(Self::new(FormatWith(lit, None, Punctuated::new()))),
+                    "with" => return Ok(Self::new(With(lit))),
                     _ => {}
                 }
             }
@@ -65,6 +70,8 @@ impl Parse for FieldAttr {
                 match name_str.as_str() {
                     "skip" => return Ok(Self::new(Skip(lit))),
                     "inline" => return Ok(Self::new(Inline(lit, None))),
+                    "wrap" => return Ok(Self::new(Wrap(lit))),
+                    "truncate" => return Ok(Self::new(Truncate(lit))),
                     _ => {}
                 }
             }
@@ -72,8 +79,10 @@ impl Parse for FieldAttr {
             if input.peek(LitInt) {
                 let lit = input.parse::<LitInt>()?;
 
-                if let "order" = name_str.as_str() {
-                    return Ok(Self::new(Order(lit)));
+                match name_str.as_str() {
+                    "order" => return Ok(Self::new(Order(lit))),
+                    "width" => return Ok(Self::new(Width(lit))),
+                    _ => {}
                 }
             }
 
@@ -131,6 +140,8 @@ impl Parse for FieldAttr {
 
         match name_str.as_str() {
             "skip" => return Ok(Self::new(Skip(LitBool::new(true, Span::call_site())))),
+            "wrap" => return Ok(Self::new(Wrap(LitBool::new(true, Span::call_site())))),
+            "truncate" => return Ok(Self::new(Truncate(LitBool::new(true, Span::call_site())))),
             "inline" => {
                 return Ok(Self::new(Inline(
                     LitBool::new(true, Span::call_site()),
@@ -1,2 +1,3 @@
 pub mod field_attr;
+pub mod serde_attr;
 pub mod type_attr;
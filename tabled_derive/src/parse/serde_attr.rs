@@ -0,0 +1,44 @@
+use syn::{Attribute, Lit, Meta, NestedMeta};
+
+/// Looks for a `#[serde(rename = "...")]` value among attributes.
+///
+/// In case of `#[serde(rename(serialize = "..."))]` the serialize name is used.
+/// Attributes which can't be parsed are ignored, as they are checked by serde itself.
+pub fn parse_serde_rename(attributes: &[Attribute]) -> Option<String> {
+    attributes
+        .iter()
+        .filter(|attr| attr.path.is_ident("serde"))
+        .filter_map(|attr| attr.parse_meta().ok())
+        .filter_map(|meta| match meta {
+            Meta::List(list) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|nested| match nested {
+            NestedMeta::Meta(meta) => find_rename(&meta),
+            NestedMeta::Lit(_) => None,
+        })
+        .last()
+}
+
+fn find_rename(meta: &Meta) -> Option<String> {
+    match meta {
+        Meta::NameValue(value) if value.path.is_ident("rename") => lit_to_string(&value.lit),
+        Meta::List(list) if list.path.is_ident("rename") => {
+            list.nested.iter().find_map(|nested| match nested {
+                NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("serialize") => {
+                    lit_to_string(&value.lit)
+                }
+                _ => None,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn lit_to_string(lit: &Lit) -> Option<String> {
+    match lit {
+        Lit::Str(value) => Some(value.value()),
+        _ => None,
+    }
+}
@@ -28,6 +28,8 @@ pub enum TypeAttrKind {
     Inline(LitBool, Option<LitStr>),
     RenameAll(LitStr),
     Crate(LitStr),
+    Transparent(LitBool),
+    SerdeRename(LitBool),
 }
 
 impl Parse for TypeAttr {
@@ -59,8 +61,11 @@ impl Parse for TypeAttr {
             if input.peek(LitBool) {
                 let lit = input.parse::<LitBool>()?;
 
-                if let "inline" = name_str.as_str() {
-                    return Ok(Self::new(Inline(lit, None)));
+                match name_str.as_str() {
+                    "inline" => return Ok(Self::new(Inline(lit, None))),
+                    "transparent" => return Ok(Self::new(Transparent(lit))),
+                    "serde_rename" => return Ok(Self::new(SerdeRename(lit))),
+                    _ => {}
                 }
             }
 
@@ -91,11 +96,26 @@ impl Parse for TypeAttr {
             ));
         }
 
-        if let "inline" = name_str.as_str() {
-            return Ok(Self::new(Inline(
-                LitBool::new(true, Span::call_site()),
-                None,
-            )));
+        match name_str.as_str() {
+            "inline" => {
+                return Ok(Self::new(Inline(
+                    LitBool::new(true, Span::call_site()),
+                    None,
+                )))
+            }
+            "transparent" => {
+                return Ok(Self::new(Transparent(LitBool::new(
+                    true,
+                    Span::call_site(),
+                ))))
+            }
+            "serde_rename" => {
+                return Ok(Self::new(SerdeRename(LitBool::new(
+                    true,
+                    Span::call_site(),
+                ))))
+            }
+            _ => {}
         }
 
         Err(syn::Error::new(